@@ -0,0 +1,184 @@
+use types::{CoordFloat, Coordinate, Point};
+use algorithm::map_coords::MapCoordsInplace;
+
+/// A 2D affine transformation, mapping `(x, y)` to
+/// `(a * x + b * y + xoff, d * x + e * y + yoff)`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct AffineTransform<T>
+    where T: CoordFloat
+{
+    pub a: T,
+    pub b: T,
+    pub xoff: T,
+    pub d: T,
+    pub e: T,
+    pub yoff: T,
+}
+
+impl<T> AffineTransform<T>
+    where T: CoordFloat
+{
+    /// Creates a transform from its coefficients.
+    pub fn new(a: T, b: T, xoff: T, d: T, e: T, yoff: T) -> AffineTransform<T> {
+        AffineTransform { a, b, xoff, d, e, yoff }
+    }
+
+    /// The transform leaving every coordinate unchanged.
+    pub fn identity() -> AffineTransform<T> {
+        AffineTransform::new(T::one(), T::zero(), T::zero(), T::zero(), T::one(), T::zero())
+    }
+
+    /// A translation by the given offsets.
+    pub fn translate(xoff: T, yoff: T) -> AffineTransform<T> {
+        AffineTransform::new(T::one(), T::zero(), xoff, T::zero(), T::one(), yoff)
+    }
+
+    /// A rotation by an angle in degrees about an origin. Positive angles are
+    /// counter-clockwise.
+    pub fn rotate(angle: T, origin: &Point<T>) -> AffineTransform<T> {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let (x0, y0) = (origin.x(), origin.y());
+        AffineTransform::new(cos, -sin, x0 - x0 * cos + y0 * sin,
+                             sin, cos, y0 - x0 * sin - y0 * cos)
+    }
+
+    /// A scaling by the given factors about an origin.
+    pub fn scale(xfact: T, yfact: T, origin: &Point<T>) -> AffineTransform<T> {
+        let (x0, y0) = (origin.x(), origin.y());
+        AffineTransform::new(xfact, T::zero(), x0 - x0 * xfact,
+                             T::zero(), yfact, y0 - y0 * yfact)
+    }
+
+    /// Returns the transform applying `self` and then `other`.
+    ///
+    /// ```
+    /// use geo::{Coordinate, Point};
+    /// use geo::algorithm::affine_transform::AffineTransform;
+    ///
+    /// let transform = AffineTransform::scale(2., 2., &Point::new(0., 0.))
+    ///     .compose(&AffineTransform::translate(1., 0.));
+    ///
+    /// assert_eq!(transform.apply(&Coordinate { x: 1., y: 1. }), Coordinate { x: 3., y: 2. });
+    /// ```
+    pub fn compose(&self, other: &AffineTransform<T>) -> AffineTransform<T> {
+        AffineTransform::new(other.a * self.a + other.b * self.d,
+                             other.a * self.b + other.b * self.e,
+                             other.a * self.xoff + other.b * self.yoff + other.xoff,
+                             other.d * self.a + other.e * self.d,
+                             other.d * self.b + other.e * self.e,
+                             other.d * self.xoff + other.e * self.yoff + other.yoff)
+    }
+
+    /// Applies the transform to a coordinate.
+    pub fn apply(&self, c: &Coordinate<T>) -> Coordinate<T> {
+        Coordinate {
+            x: self.a * c.x + self.b * c.y + self.xoff,
+            y: self.d * c.x + self.e * c.y + self.yoff,
+        }
+    }
+
+    /// Returns the transform undoing this one, or `None` if it is singular, such as a
+    /// scaling by zero, so that distinct coordinates may map to the same one.
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::affine_transform::AffineTransform;
+    ///
+    /// let transform = AffineTransform::translate(3., -2.);
+    /// assert_eq!(transform.inverse(), Some(AffineTransform::translate(-3., 2.)));
+    ///
+    /// assert_eq!(AffineTransform::scale(0., 1., &Point::new(0., 0.)).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<AffineTransform<T>> {
+        let det = self.a * self.e - self.b * self.d;
+        if det.is_zero() || !det.is_finite() {
+            return None;
+        }
+        let (a, b) = (self.e / det, -self.b / det);
+        let (d, e) = (-self.d / det, self.a / det);
+        Some(AffineTransform::new(a, b, -(a * self.xoff + b * self.yoff),
+                                  d, e, -(d * self.xoff + e * self.yoff)))
+    }
+}
+
+pub trait AffineOps<T: CoordFloat> {
+    /// Returns a geometry with an affine transform applied to each of its coordinates.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::affine_transform::{AffineOps, AffineTransform};
+    ///
+    /// let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 0.)]);
+    /// let transformed = ls.affine_transform(&AffineTransform::translate(1., 1.));
+    ///
+    /// assert_eq!(transformed, LineString(vec![Point::new(1., 1.), Point::new(2., 1.)]));
+    /// ```
+    fn affine_transform(&self, transform: &AffineTransform<T>) -> Self;
+
+    /// Applies an affine transform to each coordinate of a geometry, in place.
+    fn affine_transform_inplace(&mut self, transform: &AffineTransform<T>);
+}
+
+impl<T, G> AffineOps<T> for G
+    where T: CoordFloat,
+          G: MapCoordsInplace<T> + Clone
+{
+    fn affine_transform(&self, transform: &AffineTransform<T>) -> Self {
+        let mut transformed = self.clone();
+        transformed.affine_transform_inplace(transform);
+        transformed
+    }
+
+    fn affine_transform_inplace(&mut self, transform: &AffineTransform<T>) {
+        self.map_coords_inplace(|c| transform.apply(c));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon};
+    use algorithm::rotate::RotatePoint;
+    use super::*;
+
+    #[test]
+    fn matches_rotate_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(5., 5.), Point::new(10., 10.)]);
+        let origin = Point::new(10., 0.);
+        let transformed = ls.affine_transform(&AffineTransform::rotate(-45., &origin));
+        for (p, q) in transformed.0.iter().zip(ls.rotate_around_point(-45., &origin).0.iter()) {
+            assert_relative_eq!(p.x(), q.x(), epsilon = 1e-12);
+            assert_relative_eq!(p.y(), q.y(), epsilon = 1e-12);
+        }
+    }
+    #[test]
+    fn inverse_round_trip_test() {
+        let transform = AffineTransform::rotate(37.5, &Point::new(2.5, -1.25))
+            .compose(&AffineTransform::translate(-13.7, 4.2))
+            .compose(&AffineTransform::scale(1.5, 0.75, &Point::new(0., 3.)));
+        let inverse = transform.inverse().unwrap();
+        let polygon = Polygon::new(LineString(vec![Point::new(0., 0.), Point::new(4.3, 0.1),
+                                                   Point::new(3.9, 5.2), Point::new(-1.1, 2.7),
+                                                   Point::new(0., 0.)]),
+                                   vec![]);
+        let round_trip = polygon.affine_transform(&transform).affine_transform(&inverse);
+        for (p, q) in round_trip.exterior.0.iter().zip(polygon.exterior.0.iter()) {
+            assert_relative_eq!(p.x(), q.x(), epsilon = 1e-12);
+            assert_relative_eq!(p.y(), q.y(), epsilon = 1e-12);
+        }
+        let identity = transform.compose(&inverse);
+        let expected = AffineTransform::<f64>::identity();
+        for &(x, y) in &[(identity.a, expected.a), (identity.b, expected.b), (identity.xoff, expected.xoff),
+                         (identity.d, expected.d), (identity.e, expected.e), (identity.yoff, expected.yoff)] {
+            assert_relative_eq!(x, y, epsilon = 1e-12);
+        }
+    }
+    #[test]
+    fn singular_test() {
+        let origin = Point::new(1., 1.);
+        assert_eq!(AffineTransform::scale(0., 0., &origin).inverse(), None);
+        assert_eq!(AffineTransform::scale(2., 0., &origin).inverse(), None);
+        // a shear collapsing the plane onto a line
+        assert_eq!(AffineTransform::new(1., 2., 0., 2., 4., 0.).inverse(), None);
+        assert!(AffineTransform::<f64>::identity().inverse().is_some());
+    }
+}
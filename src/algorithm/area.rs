@@ -1,9 +1,10 @@
-use num_traits::Float;
-use types::{Line, LineString, Polygon, MultiPolygon, Bbox};
+use types::{CoordFloat, Point, Line, LineString, Polygon, MultiPolygon, Bbox};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 /// Calculation of the area.
 
-pub trait Area<T> where T: Float
+pub trait Area<T> where T: CoordFloat
 {
     /// Area of polygon.
     /// See: https://en.wikipedia.org/wiki/Polygon
@@ -20,7 +21,7 @@ pub trait Area<T> where T: Float
     fn area(&self) -> T;
 }
 
-fn get_linestring_area<T>(linestring: &LineString<T>) -> T where T: Float {
+fn get_linestring_area<T>(linestring: &LineString<T>) -> T where T: CoordFloat {
     if linestring.0.is_empty() || linestring.0.len() == 1 {
         return T::zero();
     }
@@ -32,7 +33,7 @@ fn get_linestring_area<T>(linestring: &LineString<T>) -> T where T: Float {
 }
 
 impl<T> Area<T> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn area(&self) -> T {
         T::zero()
@@ -40,7 +41,7 @@ impl<T> Area<T> for Line<T>
 }
 
 impl<T> Area<T> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn area(&self) -> T {
         self.interiors.iter().fold(get_linestring_area(&self.exterior),
@@ -49,25 +50,111 @@ impl<T> Area<T> for Polygon<T>
 }
 
 impl<T> Area<T> for MultiPolygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn area(&self) -> T {
         self.0.iter().fold(T::zero(), |total, next| total + next.area())
     }
 }
 
+#[cfg(feature = "rayon")]
+impl<T> MultiPolygon<T>
+    where T: CoordFloat + Send + Sync
+{
+    /// Area of a multipolygon, computing the area of each polygon in parallel.
+    ///
+    /// The areas are summed in order, so the result is identical to `area()`.
+    /// Requires the `rayon` feature.
+    pub fn par_area(&self) -> T {
+        let areas: Vec<T> = self.0.par_iter().map(|poly| poly.area()).collect();
+        areas.iter().fold(T::zero(), |total, &next| total + next)
+    }
+}
+
 impl<T> Area<T> for Bbox<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn area(&self) -> T {
         (self.xmax - self.xmin) * (self.ymax - self.ymin)
     }
 }
 
+// The total unsigned area of the triangles fanning out from the first point of a ring.
+fn fan_area<T>(ring: &LineString<T>) -> T where T: CoordFloat {
+    let first = match ring.0.first() {
+        Some(&p) => p,
+        None => return T::zero(),
+    };
+    let triangle = |b: &Point<T>, c: &Point<T>| {
+        ((b.x() - first.x()) * (c.y() - first.y()) - (c.x() - first.x()) * (b.y() - first.y())).abs()
+    };
+    let twice = ring.0[1..].windows(2).fold(T::zero(), |total, ps| total + triangle(&ps[0], &ps[1]));
+    twice / (T::one() + T::one())
+}
+
+impl<T> Polygon<T>
+    where T: CoordFloat
+{
+    /// Area of the polygon computed by fan triangulation, as a cross-check on `area` when
+    /// debugging.
+    ///
+    /// Each ring is split into triangles fanning out from its first point, and the
+    /// unsigned areas of the triangles are summed, minus those of the interior rings.
+    /// When every ring is simple and star-shaped from its first point, as convex rings
+    /// are, this agrees with the magnitude of `area`. A disagreement flags a ring whose
+    /// triangles fold back, and so cancel in `area` but not here: one which intersects
+    /// itself, like the bowtie below, or a concave ring which can't all be seen from its
+    /// first point. `IsSimple` tells the two apart.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    /// use geo::algorithm::area::Area;
+    ///
+    /// let p = |x, y| Point::new(x, y);
+    /// let bowtie = Polygon::new(LineString(vec![p(0., 0.), p(2., 2.), p(2., 0.), p(0., 2.), p(0., 0.)]),
+    ///                           vec![]);
+    ///
+    /// assert_eq!(bowtie.area(), 0.);
+    /// assert_eq!(bowtie.area_by_triangulation(), 4.);
+    /// ```
+    pub fn area_by_triangulation(&self) -> T {
+        self.interiors
+            .iter()
+            .fold(fan_area(&self.exterior), |total, ring| total - fan_area(ring))
+    }
+
+    /// Unsigned area of the polygon, whichever way each of its rings is wound.
+    ///
+    /// This is the magnitude of the exterior ring's area, minus the magnitudes of the
+    /// interior rings' areas. `area` subtracts the signed area of each interior ring, so it
+    /// is only correct when the interior rings are wound the same way as the exterior.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    /// use geo::algorithm::area::Area;
+    ///
+    /// let p = |x, y| Point::new(x, y);
+    /// // a counter-clockwise exterior, with a clockwise hole
+    /// let exterior = LineString(vec![p(0., 0.), p(4., 0.), p(4., 4.), p(0., 4.), p(0., 0.)]);
+    /// let hole = LineString(vec![p(1., 1.), p(1., 2.), p(2., 2.), p(2., 1.), p(1., 1.)]);
+    /// let poly = Polygon::new(exterior, vec![hole]);
+    ///
+    /// assert_eq!(poly.area_by_ring_magnitude(), 15.);
+    /// assert_eq!(poly.area(), 17.);
+    /// ```
+    pub fn area_by_ring_magnitude(&self) -> T {
+        self.interiors
+            .iter()
+            .fold(get_linestring_area(&self.exterior).abs(),
+                  |total, ring| total - get_linestring_area(ring).abs())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use types::{Coordinate, Point, Line, LineString, Polygon, MultiPolygon, Bbox};
     use algorithm::area::Area;
+    use algorithm::is_simple::IsSimple;
 
     // Area of the polygon
     #[test]
@@ -124,4 +211,61 @@ mod test {
         let line1 = Line::new(p(0.0, 0.0), p(1.0, 1.0));
         assert_eq!(line1.area(), 0.);
     }
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_area_multipolygon_test() {
+        let polys = (0..1000)
+            .map(|i| {
+                let (x, size) = (i as f64 * 2., 1. + (i % 7) as f64 * 0.1);
+                Bbox { xmin: x, xmax: x + size, ymin: 0., ymax: size }.to_polygon()
+            })
+            .collect();
+        let mpoly = MultiPolygon(polys);
+        assert_eq!(mpoly.par_area(), mpoly.area());
+    }
+    #[test]
+    fn area_by_triangulation_test() {
+        let p = |x, y| Point(Coordinate { x: x, y: y });
+        // a convex hexagon, wound clockwise
+        let hexagon = Polygon::new(LineString(vec![p(0f64, 0.), p(-1., 2.), p(0., 4.), p(3., 4.), p(4., 2.),
+                                                   p(3., 0.), p(0., 0.)]),
+                                   vec![]);
+        assert_relative_eq!(hexagon.area_by_triangulation(), hexagon.area().abs());
+        assert_relative_eq!(hexagon.area_by_triangulation(), 16.);
+
+        let exterior = LineString(vec![p(0., 0.), p(6., 0.), p(6., 6.), p(0., 6.), p(0., 0.)]);
+        let hole = LineString(vec![p(1., 1.), p(1., 3.), p(3., 3.), p(3., 1.), p(1., 1.)]);
+        assert_relative_eq!(Polygon::new(exterior, vec![hole]).area_by_triangulation(), 32.);
+
+        // the two lobes of a bowtie wind in opposite directions, and cancel in the shoelace area
+        let bowtie = Polygon::new(LineString(vec![p(0., 0.), p(4., 4.), p(4., 0.), p(0., 4.), p(0., 0.)]),
+                                  vec![]);
+        assert_relative_eq!(bowtie.area(), 0.);
+        assert_relative_eq!(bowtie.area_by_triangulation(), 16.);
+        assert!(!bowtie.exterior.is_simple());
+
+        // a simple U shape, part of which can't be seen from its first point, also disagrees
+        let u = Polygon::new(LineString(vec![p(0., 0.), p(3., 0.), p(3., 3.), p(2., 3.), p(2., 1.), p(1., 1.),
+                                             p(1., 3.), p(0., 3.), p(0., 0.)]),
+                             vec![]);
+        assert_relative_eq!(u.area(), 7.);
+        assert_relative_eq!(u.area_by_triangulation(), 11.);
+        assert!(u.exterior.is_simple());
+    }
+    #[test]
+    fn area_by_ring_magnitude_test() {
+        let p = |x, y| Point::new(x, y);
+        let ccw = |min: f64, max: f64| LineString(vec![p(min, min), p(max, min), p(max, max), p(min, max), p(min, min)]);
+        let cw = |min: f64, max: f64| LineString(ccw(min, max).0.into_iter().rev().collect());
+        let windings = [(ccw(0., 10.), ccw(2., 4.)), (ccw(0., 10.), cw(2., 4.)),
+                        (cw(0., 10.), ccw(2., 4.)), (cw(0., 10.), cw(2., 4.))];
+        for (exterior, hole) in &windings {
+            let poly = Polygon::new(exterior.clone(), vec![hole.clone(), ccw(6., 7.)]);
+            assert_relative_eq!(poly.area_by_ring_magnitude(), 100. - 4. - 1.);
+        }
+        // area only subtracts holes wound like the exterior
+        assert_relative_eq!(Polygon::new(ccw(0., 10.), vec![ccw(2., 4.)]).area(), 96.);
+        assert_relative_eq!(Polygon::new(ccw(0., 10.), vec![cw(2., 4.)]).area(), 104.);
+        assert_relative_eq!(Polygon::new(LineString(vec![]), vec![]).area_by_ring_magnitude(), 0.);
+    }
 }
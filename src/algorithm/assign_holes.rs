@@ -0,0 +1,113 @@
+use types::{CoordFloat, LineString, Polygon, MultiPolygon};
+use algorithm::contains::Contains;
+
+// does the exterior of `outer` enclose every point of `inner`, allowing them to touch?
+fn encloses<T>(outer: &LineString<T>, inner: &LineString<T>) -> bool
+    where T: CoordFloat
+{
+    let filled = Polygon::new(outer.clone(), vec![]);
+    !inner.0.is_empty() && inner.0.iter().all(|p| outer.contains(p) || filled.contains(p))
+}
+
+/// Nests polygons that lie inside others as interior rings, for data storing holes as
+/// separate polygons.
+///
+/// A polygon inside an odd number of the others is a hole, and becomes an interior ring of
+/// the smallest polygon containing it; one inside an even number, such as an island in a
+/// lake, stays a polygon. Holes keep their winding, and any interior rings they had are
+/// dropped. The polygons that remain keep their order. Of two polygons with the same
+/// exterior, the later one is taken to lie inside the earlier.
+///
+/// ```
+/// use geo::Bbox;
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::assign_holes::assign_holes;
+///
+/// let field = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. }.to_polygon();
+/// let pond = Bbox { xmin: 2., xmax: 4., ymin: 2., ymax: 4. }.to_polygon();
+///
+/// let nested = assign_holes(vec![pond, field]);
+/// assert_eq!(nested.0.len(), 1);
+/// assert_eq!(nested.0[0].interiors.len(), 1);
+/// assert_eq!(nested.area(), 96.);
+/// ```
+pub fn assign_holes<T>(polygons: Vec<Polygon<T>>) -> MultiPolygon<T>
+    where T: CoordFloat
+{
+    let n = polygons.len();
+    let enclosing: Vec<Vec<bool>> = (0..n)
+        .map(|i| (0..n).map(|j| j != i && encloses(&polygons[j].exterior, &polygons[i].exterior)).collect())
+        .collect();
+    // polygons with the same exterior enclose each other, so the earlier one counts as
+    // containing the later
+    let containers: Vec<Vec<usize>> = (0..n)
+        .map(|i| (0..n).filter(|&j| enclosing[i][j] && (j < i || !enclosing[j][i])).collect())
+        .collect();
+    // the direct parent of a polygon is the container nested most deeply itself
+    let parent = |i: usize| containers[i].iter().cloned().max_by_key(|&j| containers[j].len());
+    let mut holes: Vec<Vec<LineString<T>>> = vec![vec![]; n];
+    let mut is_hole = vec![false; n];
+    for i in 0..n {
+        if containers[i].len() % 2 == 1 {
+            if let Some(j) = parent(i) {
+                holes[j].push(polygons[i].exterior.clone());
+                is_hole[i] = true;
+            }
+        }
+    }
+    MultiPolygon(polygons
+        .into_iter()
+        .zip(holes)
+        .zip(is_hole)
+        .filter(|&(_, hole)| !hole)
+        .map(|((mut poly, new_holes), _)| {
+            poly.interiors.extend(new_holes);
+            poly
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Bbox, Polygon};
+    use algorithm::area::Area;
+    use super::*;
+
+    fn square(min: f64, max: f64) -> Polygon<f64> {
+        Bbox { xmin: min, xmax: max, ymin: min, ymax: max }.to_polygon()
+    }
+
+    #[test]
+    fn one_hole_test() {
+        let nested = assign_holes(vec![square(0., 10.), square(3., 5.)]);
+        assert_eq!(nested.0.len(), 1);
+        assert_eq!(nested.0[0].exterior, square(0., 10.).exterior);
+        assert_eq!(nested.0[0].interiors.len(), 1);
+        assert_eq!(Polygon::new(nested.0[0].interiors[0].clone(), vec![]).area(), 4.);
+    }
+    #[test]
+    fn island_test() {
+        // an island inside a lake inside a field, and a separate field
+        let nested = assign_holes(vec![square(2., 8.), square(0., 10.), square(4., 6.), square(20., 21.)]);
+        assert_eq!(nested.0.len(), 3);
+        assert_eq!(nested.0[0].exterior, square(0., 10.).exterior);
+        assert_eq!(nested.0[0].interiors.len(), 1);
+        assert_eq!(nested.0[1], square(4., 6.));
+        assert_eq!(nested.0[2], square(20., 21.));
+    }
+    #[test]
+    fn disjoint_test() {
+        let nested = assign_holes(vec![square(0., 1.), square(2., 3.)]);
+        assert_eq!(nested.0, vec![square(0., 1.), square(2., 3.)]);
+        assert!(assign_holes::<f64>(vec![]).0.is_empty());
+    }
+    #[test]
+    fn duplicate_polygon_test() {
+        let nested = assign_holes(vec![square(0., 10.), square(0., 10.)]);
+        assert_eq!(nested.0.len(), 1);
+        assert_eq!(nested.0[0].exterior, square(0., 10.).exterior);
+        assert_eq!(nested.0[0].interiors, vec![square(0., 10.).exterior]);
+        // a third copy is an island in the hole
+        assert_eq!(assign_holes(vec![square(0., 10.), square(0., 10.), square(0., 10.)]).0.len(), 2);
+    }
+}
@@ -0,0 +1,91 @@
+use types::{CoordFloat, Point};
+use algorithm::cross_track_distance::initial_bearing;
+use algorithm::rhumb::RhumbBearing;
+
+/// The kind of path a bearing is measured along.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum BearingMethod {
+    /// The shortest path on the sphere, whose bearing changes along the way; the initial
+    /// bearing is used
+    GreatCircle,
+    /// The path of constant bearing, which is a straight line in the Mercator projection
+    Rhumb,
+}
+
+// bring a bearing in degrees into [0, 360). Adding a full turn to a tiny negative bearing
+// rounds to exactly 360, which is taken as 0.
+pub(crate) fn normalize_bearing<T>(degrees: T) -> T
+    where T: CoordFloat
+{
+    let full_turn = T::from(360.).unwrap();
+    let bearing = (degrees + full_turn) % full_turn;
+    if bearing >= full_turn { T::zero() } else { bearing }
+}
+
+pub trait Bearing<T: CoordFloat> {
+    /// Returns the bearing from the existing Point to `other`, in degrees clockwise from
+    /// north, in the range `[0, 360)`, following the path given by `method`.
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::bearing::{Bearing, BearingMethod};
+    ///
+    /// let (london, new_york) = (Point::<f64>::new(-0.13, 51.51), Point::new(-74.01, 40.71));
+    ///
+    /// // the great circle sets off north of west, while the rhumb line heads south of west
+    /// assert!(london.bearing(&new_york, BearingMethod::GreatCircle) > 270.);
+    /// assert!(london.bearing(&new_york, BearingMethod::Rhumb) < 270.);
+    /// ```
+    fn bearing(&self, other: &Point<T>, method: BearingMethod) -> T;
+}
+
+impl<T> Bearing<T> for Point<T>
+    where T: CoordFloat
+{
+    fn bearing(&self, other: &Point<T>, method: BearingMethod) -> T {
+        match method {
+            BearingMethod::GreatCircle => normalize_bearing(initial_bearing(self, other).to_degrees()),
+            BearingMethod::Rhumb => self.rhumb_bearing(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::Point;
+    use super::*;
+
+    #[test]
+    fn east_test() {
+        // along a parallel, the rhumb line is due east but the great circle sets off poleward
+        let (a, b) = (Point::<f64>::new(0., 50.), Point::new(60., 50.));
+        assert_relative_eq!(a.bearing(&b, BearingMethod::Rhumb), 90.);
+        let great_circle = a.bearing(&b, BearingMethod::GreatCircle);
+        assert!(great_circle < 80.);
+    }
+    #[test]
+    fn north_test() {
+        let (a, b) = (Point::<f64>::new(12., -30.), Point::new(12., 45.));
+        assert_relative_eq!(a.bearing(&b, BearingMethod::GreatCircle), 0.);
+        assert_relative_eq!(a.bearing(&b, BearingMethod::Rhumb), 0.);
+        assert_relative_eq!(b.bearing(&a, BearingMethod::GreatCircle), 180.);
+        assert_relative_eq!(b.bearing(&a, BearingMethod::Rhumb), 180.);
+    }
+    #[test]
+    fn range_test() {
+        let (a, b) = (Point::<f64>::new(10., 10.), Point::new(5., 10.5));
+        for &method in &[BearingMethod::GreatCircle, BearingMethod::Rhumb] {
+            let bearing = a.bearing(&b, method);
+            assert!(bearing > 270. && bearing < 360.);
+        }
+    }    #[test]
+    fn just_west_of_north_test() {
+        assert_eq!(normalize_bearing(-1e-15f64), 0.);
+        assert_eq!(normalize_bearing(-90f64), 270.);
+        let (a, b) = (Point::<f64>::new(0., 0.), Point::new(-1e-17, 10.));
+        for &method in &[BearingMethod::GreatCircle, BearingMethod::Rhumb] {
+            let bearing = a.bearing(&b, method);
+            assert!((0. ..360.).contains(&bearing));
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use types::{CoordFloat, Point, MultiPoint, LineString, Polygon};
+use algorithm::distance::Distance;
+
+// a circle, as its center and radius
+type Circle<T> = (Point<T>, T);
+
+fn in_circle<T>(circle: &Circle<T>, p: &Point<T>) -> bool
+    where T: CoordFloat
+{
+    // allow for rounding error in the computed center and radius, which grows with the
+    // size of the circle
+    let tolerance = T::from(1e-10).unwrap() * (T::one() + circle.1);
+    circle.0.distance(p) <= circle.1 + tolerance
+}
+
+fn diameter_circle<T>(a: &Point<T>, b: &Point<T>) -> Circle<T>
+    where T: CoordFloat
+{
+    let two = T::one() + T::one();
+    let center = Point::new((a.x() + b.x()) / two, (a.y() + b.y()) / two);
+    (center, center.distance(a).max(center.distance(b)))
+}
+
+fn circumcircle<T>(a: &Point<T>, b: &Point<T>, c: &Point<T>) -> Circle<T>
+    where T: CoordFloat
+{
+    let two = T::one() + T::one();
+    let (bx, by) = (b.x() - a.x(), b.y() - a.y());
+    let (cx, cy) = (c.x() - a.x(), c.y() - a.y());
+    let d = two * (bx * cy - by * cx);
+    if d == T::zero() {
+        // collinear, so the circle through the two furthest points encloses the third
+        let candidates = [diameter_circle(a, b), diameter_circle(a, c), diameter_circle(b, c)];
+        return candidates.iter()
+            .fold(candidates[0], |max, &circle| if circle.1 > max.1 { circle } else { max });
+    }
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    let ux = (cy * b2 - by * c2) / d;
+    let uy = (bx * c2 - cx * b2) / d;
+    let center = Point::new(a.x() + ux, a.y() + uy);
+    (center, center.distance(a).max(center.distance(b)).max(center.distance(c)))
+}
+
+// shuffle the points, so that Welzl's algorithm runs in expected linear time whatever the
+// input order; a fixed xorshift seed keeps the result deterministic
+fn shuffle<T>(points: &mut [Point<T>])
+    where T: CoordFloat
+{
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for i in (1..points.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        points.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+// the iterative form of Welzl's algorithm
+fn welzl<T>(points: &[Point<T>]) -> Option<Circle<T>>
+    where T: CoordFloat
+{
+    let mut points = points.to_vec();
+    shuffle(&mut points);
+    let mut circle = (*points.first()?, T::zero());
+    for i in 1..points.len() {
+        if in_circle(&circle, &points[i]) {
+            continue;
+        }
+        circle = (points[i], T::zero());
+        for j in 0..i {
+            if in_circle(&circle, &points[j]) {
+                continue;
+            }
+            circle = diameter_circle(&points[i], &points[j]);
+            for k in 0..j {
+                if !in_circle(&circle, &points[k]) {
+                    circle = circumcircle(&points[i], &points[j], &points[k]);
+                }
+            }
+        }
+    }
+    Some(circle)
+}
+
+pub trait BoundingCircle<T> {
+    /// Returns the smallest circle enclosing every point of a geometry, as its center and
+    /// radius, or `None` if the geometry has no points.
+    ///
+    /// Uses [Welzl's algorithm](https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm),
+    /// which runs in expected linear time.
+    ///
+    /// ```
+    /// use geo::{Point, MultiPoint};
+    /// use geo::algorithm::bounding_circle::BoundingCircle;
+    ///
+    /// let points = MultiPoint(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(2., 1.)]);
+    /// let (center, radius) = points.bounding_circle().unwrap();
+    ///
+    /// assert_eq!(center, Point::new(2., 0.));
+    /// assert_eq!(radius, 2.);
+    /// ```
+    fn bounding_circle(&self) -> Option<(Point<T>, T)> where T: CoordFloat;
+}
+
+impl<T> BoundingCircle<T> for MultiPoint<T>
+    where T: CoordFloat
+{
+    fn bounding_circle(&self) -> Option<(Point<T>, T)> {
+        welzl(&self.0)
+    }
+}
+
+impl<T> BoundingCircle<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn bounding_circle(&self) -> Option<(Point<T>, T)> {
+        welzl(&self.0)
+    }
+}
+
+impl<T> BoundingCircle<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn bounding_circle(&self) -> Option<(Point<T>, T)> {
+        // the interior rings lie inside the exterior
+        welzl(&self.exterior.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, MultiPoint, LineString, Bbox};
+    use algorithm::distance::Distance;
+    use super::*;
+
+    #[test]
+    fn diameter_test() {
+        let p = |x, y| Point::new(x, y);
+        // (-3, 0) and (3, 0) are opposite ends of a diameter; the rest lie inside
+        let points = MultiPoint(vec![p(1., 1.), p(-3., 0.), p(0., -2.), p(2., 0.5), p(3., 0.),
+                                     p(-1., 2.), p(0., 0.)]);
+        let (center, radius) = points.bounding_circle().unwrap();
+        assert_relative_eq!(center.x(), 0.);
+        assert_relative_eq!(center.y(), 0.);
+        assert_relative_eq!(radius, 3.);
+        for point in &points.0 {
+            assert!(center.distance(point) <= radius + 1e-9);
+        }
+    }
+    #[test]
+    fn triangle_test() {
+        // an acute triangle is enclosed by its circumcircle
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(2., 3.)]);
+        let (center, radius) = ls.bounding_circle().unwrap();
+        assert_relative_eq!(center.x(), 2.);
+        assert_relative_eq!(center.y(), 5. / 6.);
+        assert_relative_eq!(radius, 13. / 6.);
+    }
+    #[test]
+    fn many_points_test() {
+        let points: Vec<Point<f64>> = (0..500)
+            .map(|i| {
+                let angle = i as f64 * 0.37;
+                let r = 10. * ((i * 7919) % 1000) as f64 / 1000.;
+                Point::new(5. + r * angle.cos(), -2. + r * angle.sin())
+            })
+            .collect();
+        let (center, radius) = MultiPoint(points.clone()).bounding_circle().unwrap();
+        assert!(radius <= 10.);
+        for point in &points {
+            assert!(center.distance(point) <= radius + 1e-9);
+        }
+        // the circle is as small as possible, so some point lies on it
+        assert!(points.iter().any(|p| (center.distance(p) - radius).abs() < 1e-9));
+    }
+    #[test]
+    fn degenerate_test() {
+        assert!(MultiPoint::<f64>(vec![]).bounding_circle().is_none());
+        let single = MultiPoint(vec![Point::new(1., 2.)]);
+        assert_eq!(single.bounding_circle(), Some((Point::new(1., 2.), 0.)));
+        let collinear = MultiPoint(vec![Point::new(0., 0.), Point::new(2., 2.), Point::new(1., 1.)]);
+        let (center, radius) = collinear.bounding_circle().unwrap();
+        assert_eq!(center, Point::new(1., 1.));
+        assert_relative_eq!(radius, 2f64.sqrt());
+        let square = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 2. }.to_polygon();
+        let (center, radius) = square.bounding_circle().unwrap();
+        assert_eq!(center, Point::new(1., 1.));
+        assert_relative_eq!(radius, 2f64.sqrt());
+    }
+}
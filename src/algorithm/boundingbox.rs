@@ -1,10 +1,8 @@
-use num_traits::Float;
-
-use types::{Bbox, Point, MultiPoint, Line, LineString, MultiLineString, Polygon, MultiPolygon};
+use types::{CoordFloat, Bbox, Point, MultiPoint, Line, LineString, MultiLineString, Polygon, MultiPolygon};
 
 /// Calculation of the bounding box of a geometry.
 
-pub trait BoundingBox<T: Float> {
+pub trait BoundingBox<T: CoordFloat> {
     /// Return the Bounding Box of a geometry
     ///
     /// ```
@@ -29,13 +27,13 @@ pub trait BoundingBox<T: Float> {
 
 
 fn get_min_max<T>(p: T, min: T, max: T) -> (T, T)
-    where T: Float
+    where T: CoordFloat
 {
     if p > max {(min, p)} else if p < min {(p, max)} else {(min, max)}
 }
 
 fn get_bbox<'a, I, T>(collection: I) -> Option<Bbox<T>>
-    where T: 'a + Float,
+    where T: 'a + CoordFloat,
           I: 'a + IntoIterator<Item = &'a Point<T>>
 {
     let mut iter  = collection.into_iter();
@@ -55,7 +53,7 @@ fn get_bbox<'a, I, T>(collection: I) -> Option<Bbox<T>>
 
 
 impl<T> BoundingBox<T> for MultiPoint<T>
-    where T: Float
+    where T: CoordFloat
 {
     ///
     /// Return the BoundingBox for a MultiPoint
@@ -66,7 +64,7 @@ impl<T> BoundingBox<T> for MultiPoint<T>
 }
 
 impl<T> BoundingBox<T> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn bbox(&self) -> Option<Bbox<T>> {
         let a = self.start;
@@ -79,7 +77,7 @@ impl<T> BoundingBox<T> for Line<T>
 }
 
 impl<T> BoundingBox<T> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     ///
     /// Return the BoundingBox for a LineString
@@ -90,7 +88,7 @@ impl<T> BoundingBox<T> for LineString<T>
 }
 
 impl<T> BoundingBox<T> for MultiLineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     ///
     /// Return the BoundingBox for a MultiLineString
@@ -101,7 +99,7 @@ impl<T> BoundingBox<T> for MultiLineString<T>
 }
 
 impl<T> BoundingBox<T> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     ///
     /// Return the BoundingBox for a Polygon
@@ -113,7 +111,7 @@ impl<T> BoundingBox<T> for Polygon<T>
 }
 
 impl<T> BoundingBox<T> for MultiPolygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     ///
     /// Return the BoundingBox for a MultiPolygon
@@ -189,6 +187,15 @@ mod test {
         assert_eq!(bbox, mpoly.bbox().unwrap());
     }
     #[test]
+    fn multipolygon_with_empty_polygon_test(){
+        let p = |x, y| Point::new(x, y);
+        let poly = Polygon::new(LineString(vec![p(0., 0.), p(5., 0.), p(0., 8.), p(0., 0.)]), Vec::new());
+        let empty = Polygon::new(LineString(Vec::new()), Vec::new());
+        let mpoly = MultiPolygon(vec![empty.clone(), poly.clone(), empty.clone()]);
+        assert_eq!(mpoly.bbox(), poly.bbox());
+        assert_eq!(MultiPolygon(vec![empty]).bbox(), None);
+    }
+    #[test]
     fn line_test() {
         let p = |x, y| Point(Coordinate { x: x, y: y });
         let line1 = Line::new(p(0., 1.), p(2., 3.));
@@ -1,11 +1,10 @@
-use num_traits::{Float, FromPrimitive};
-
-use types::{Point, Line, LineString, Polygon, MultiPolygon, Bbox};
+use types::{CoordFloat, Point, Line, LineString, Polygon, MultiPoint, MultiPolygon, Bbox};
 use algorithm::area::Area;
 use algorithm::distance::Distance;
+use algorithm::length::Length;
 
 /// Calculation of the centroid.
-pub trait Centroid<T: Float> {
+pub trait Centroid<T: CoordFloat> {
     /// See: https://en.wikipedia.org/wiki/Centroid
     ///
     /// ```
@@ -23,9 +22,27 @@ pub trait Centroid<T: Float> {
     fn centroid(&self) -> Option<Point<T>>;
 }
 
+/// Calculation of the centroid, falling back to the centroid of the boundary for degenerate
+/// geometries.
+pub trait CentroidOrBoundary<T: CoordFloat> {
+    /// Returns the area-weighted centroid, or the length-weighted centroid of the boundary
+    /// if the area is zero, such as for a `Polygon` whose points are all collinear.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    /// use geo::algorithm::centroid::CentroidOrBoundary;
+    ///
+    /// let p = |x, y| Point::new(x, y);
+    /// let flat = Polygon::new(LineString(vec![p(0., 0.), p(4., 0.), p(0., 0.)]), vec![]);
+    ///
+    /// assert_eq!(flat.centroid_or_boundary(), Some(p(2., 0.)));
+    /// ```
+    fn centroid_or_boundary(&self) -> Option<Point<T>>;
+}
+
 // Calculation of simple (no interior holes) Polygon area
 fn simple_polygon_area<T>(linestring: &LineString<T>) -> T
-    where T: Float
+    where T: CoordFloat
 {
     if linestring.0.is_empty() || linestring.0.len() == 1 {
         return T::zero();
@@ -39,7 +56,7 @@ fn simple_polygon_area<T>(linestring: &LineString<T>) -> T
 
 // Calculation of a Polygon centroid without interior rings
 fn simple_polygon_centroid<T>(poly_ext: &LineString<T>) -> Option<Point<T>>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
     let vect = &poly_ext.0;
     let area = simple_polygon_area(poly_ext);
@@ -55,7 +72,7 @@ fn simple_polygon_centroid<T>(poly_ext: &LineString<T>) -> Option<Point<T>>
 }
 
 impl<T> Centroid<T> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn centroid(&self) -> Option<Point<T>> {
         let two = T::one() + T::one();
@@ -66,7 +83,7 @@ impl<T> Centroid<T> for Line<T>
 }
 
 impl<T> Centroid<T> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     // The Centroid of a LineString is the mean of the middle of the segment
     // weighted by the length of the segments.
@@ -94,7 +111,7 @@ impl<T> Centroid<T> for LineString<T>
 }
 
 impl<T> Centroid<T> for Polygon<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
     // Calculate the centroid of a Polygon.
     // We distinguish between a simple polygon, which has no interior holes,
@@ -138,19 +155,47 @@ impl<T> Centroid<T> for Polygon<T>
     }
 }
 
+impl<T> CentroidOrBoundary<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn centroid_or_boundary(&self) -> Option<Point<T>> {
+        let first = *self.exterior.0.first()?;
+        let area = self.interiors
+            .iter()
+            .fold(simple_polygon_area(&self.exterior).abs(),
+                  |total, ring| total - simple_polygon_area(ring).abs());
+        if area != T::zero() {
+            return self.centroid();
+        }
+        // weight the centroid of each ring by its length
+        let (mut sum_x, mut sum_y, mut total_length) = (T::zero(), T::zero(), T::zero());
+        for ring in ::std::iter::once(&self.exterior).chain(self.interiors.iter()) {
+            let length = ring.length();
+            if length > T::zero() {
+                let c = ring.centroid().unwrap();
+                sum_x = sum_x + c.x() * length;
+                sum_y = sum_y + c.y() * length;
+                total_length = total_length + length;
+            }
+        }
+        if total_length == T::zero() {
+            // every point is the same
+            return Some(first);
+        }
+        Some(Point::new(sum_x / total_length, sum_y / total_length))
+    }
+}
+
 impl<T> Centroid<T> for MultiPolygon<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
     fn centroid(&self) -> Option<Point<T>> {
         let mut sum_x = T::zero();
         let mut sum_y = T::zero();
         let mut total_area = T::zero();
-        let vect = &self.0;
-        if vect.is_empty() {
-            return None;
-        }
-        for poly in &self.0 {
-            // the area is signed
+        // polygons without any points don't contribute to the centroid
+        for poly in self.0.iter().filter(|poly| !poly.exterior.0.is_empty()) {
+            // the area is signed, so weight by its magnitude whatever the winding
             let area = poly.area().abs();
             total_area = total_area + area;
             if let Some(p) = poly.centroid() {
@@ -158,12 +203,15 @@ impl<T> Centroid<T> for MultiPolygon<T>
                 sum_y = sum_y + area * p.y();
             }
         }
+        if total_area == T::zero() {
+            return None;
+        }
         Some(Point::new(sum_x / total_area, sum_y / total_area))
     }
 }
 
 impl<T> Centroid<T> for Bbox<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn centroid(&self) -> Option<Point<T>> {
         let two = T::one() + T::one();
@@ -172,17 +220,67 @@ impl<T> Centroid<T> for Bbox<T>
 }
 
 impl<T> Centroid<T> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn centroid(&self) -> Option<Point<T>> {
         Some(Point::new(self.x(), self.y()))
     }
 }
 
+impl<T> Centroid<T> for MultiPoint<T>
+    where T: CoordFloat
+{
+    // The Centroid of a MultiPoint is the arithmetic mean of its points.
+    fn centroid(&self) -> Option<Point<T>> {
+        if self.0.is_empty() {
+            return None;
+        }
+        let (sum_x, sum_y) = self.0
+            .iter()
+            .fold((T::zero(), T::zero()), |acc, p| (acc.0 + p.x(), acc.1 + p.y()));
+        let count = T::from(self.0.len()).unwrap();
+        Some(Point::new(sum_x / count, sum_y / count))
+    }
+}
+
+impl<T> MultiPoint<T>
+    where T: CoordFloat
+{
+    /// Centroid of a multipoint, with each point weighted by the matching entry of `weights`.
+    ///
+    /// Returns `None` if the number of weights doesn't match the number of points,
+    /// or if the weights sum to zero.
+    ///
+    /// ```
+    /// use geo::{Point, MultiPoint};
+    ///
+    /// let mp = MultiPoint(vec![Point::new(0., 0.), Point::new(4., 0.)]);
+    ///
+    /// assert_eq!(mp.weighted_centroid(&[1., 3.]), Some(Point::new(3., 0.)));
+    /// assert_eq!(mp.weighted_centroid(&[1.]), None);
+    /// ```
+    pub fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>> {
+        if weights.len() != self.0.len() {
+            return None;
+        }
+        let (sum_x, sum_y, total_weight) =
+            self.0
+                .iter()
+                .zip(weights)
+                .fold((T::zero(), T::zero(), T::zero()),
+                      |acc, (p, &w)| (acc.0 + w * p.x(), acc.1 + w * p.y(), acc.2 + w));
+        if total_weight == T::zero() {
+            return None;
+        }
+        Some(Point::new(sum_x / total_weight, sum_y / total_weight))
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use types::{COORD_PRECISION, Coordinate, Point, Line, LineString, Polygon, MultiPolygon, Bbox};
-    use algorithm::centroid::Centroid;
+    use types::{COORD_PRECISION, Coordinate, Point, Line, LineString, Polygon, MultiPoint, MultiPolygon,
+                Bbox};
+    use algorithm::centroid::{Centroid, CentroidOrBoundary};
     use algorithm::distance::Distance;
     // Tests: Centroid of LineString
     #[test]
@@ -263,6 +361,16 @@ mod test {
     #[test]
     fn empty_multipolygon_polygon_test() {
         assert!(MultiPolygon::<f64>(Vec::new()).centroid().is_none());
+        let empty = Polygon::new(LineString::<f64>(Vec::new()), Vec::new());
+        assert!(MultiPolygon(vec![empty.clone(), empty]).centroid().is_none());
+    }
+    #[test]
+    fn multipolygon_with_empty_polygon_test() {
+        let p = |x, y| Point::new(x, y);
+        let linestring = LineString(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.), p(0., 0.)]);
+        let poly = Polygon::new(linestring, Vec::new());
+        let empty = Polygon::new(LineString(Vec::new()), Vec::new());
+        assert_eq!(MultiPolygon(vec![empty.clone(), poly, empty]).centroid(), Some(p(1., 1.)));
     }
     #[test]
     fn multipolygon_one_polygon_test() {
@@ -294,6 +402,41 @@ mod test {
         assert_eq!(MultiPolygon(vec![poly1, poly2]).centroid(), Some(p(0., 1.)));
     }
     #[test]
+    fn multipolygon_unequal_squares_test() {
+        let p = |x, y| Point::new(x, y);
+        let square = |x: f64, side: f64| {
+            Polygon::new(LineString(vec![p(x, 0.), p(x + side, 0.), p(x + side, side), p(x, side), p(x, 0.)]),
+                         Vec::new())
+        };
+        // areas of 16 and 1, centered at (2, 2) and (10.5, 0.5)
+        let centroid = MultiPolygon(vec![square(0., 4.), square(10., 1.)]).centroid().unwrap();
+        assert_relative_eq!(centroid.x(), (16. * 2. + 10.5) / 17.);
+        assert_relative_eq!(centroid.y(), (16. * 2. + 0.5) / 17.);
+        // nearer the larger square than the midpoint of the centers is
+        assert!(centroid.x() < (2. + 10.5) / 2.);
+    }
+    #[test]
+    fn multipolygon_zero_area_test() {
+        let p = |x, y| Point::new(x, y);
+        let flat = Polygon::new(LineString(vec![p(0., 0.), p(2., 0.), p(1., 0.), p(0., 0.)]), Vec::new());
+        assert!(MultiPolygon(vec![flat.clone(), flat]).centroid().is_none());
+    }
+    #[test]
+    fn polygon_centroid_or_boundary_test() {
+        let p = |x, y| Point::new(x, y);
+        // collinear points spanning (1, 1) to (5, 3)
+        let flat = Polygon::new(LineString(vec![p(1., 1.), p(5., 3.), p(3., 2.), p(1., 1.)]), vec![]);
+        assert_eq!(flat.centroid_or_boundary(), Some(p(3., 2.)));
+        let point = Polygon::new(LineString(vec![p(1., 1.), p(1., 1.)]), vec![]);
+        assert_eq!(point.centroid_or_boundary(), Some(p(1., 1.)));
+        let empty = Polygon::new(LineString::<f64>(vec![]), vec![]);
+        assert_eq!(empty.centroid_or_boundary(), None);
+        // polygons with area are unchanged
+        let square = Polygon::new(LineString(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.), p(0., 0.)]),
+                                  vec![]);
+        assert_eq!(square.centroid_or_boundary(), square.centroid());
+    }
+    #[test]
     fn bbox_test() {
         let bbox = Bbox {
             xmax: 4.,
@@ -310,4 +453,22 @@ mod test {
         let line1 = Line::new(p(0., 1.), p(1., 3.));
         assert_eq!(line1.centroid(), Some(p(0.5, 2.)));
     }
+    #[test]
+    fn multipoint_test() {
+        let p = |x, y| Point::new(x, y);
+        let mp = MultiPoint(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.)]);
+        assert_eq!(mp.centroid(), Some(p(1., 1.)));
+        assert!(MultiPoint::<f64>(vec![]).centroid().is_none());
+    }
+    #[test]
+    fn multipoint_weighted_test() {
+        let p = |x, y| Point::new(x, y);
+        let mp = MultiPoint(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.)]);
+        assert_eq!(mp.weighted_centroid(&[1., 1., 1., 1.]), mp.centroid());
+        // the right-hand corners are three times as heavy
+        let centroid = mp.weighted_centroid(&[1., 3., 3., 1.]).unwrap();
+        assert_eq!(centroid, p(1.5, 1.));
+        assert!(mp.weighted_centroid(&[1., 1., 1.]).is_none());
+        assert!(mp.weighted_centroid(&[0., 0., 0., 0.]).is_none());
+    }
 }
@@ -0,0 +1,116 @@
+use std::f64::consts::PI;
+use types::{CoordFloat, Point, LineString, Polygon};
+
+/// Returns a regular polygon with `sides` vertices on the circle of `radius` around `center`,
+/// approximating the circle more closely as `sides` grows.
+///
+/// The first vertex lies directly in the positive x direction from the center, and the
+/// exterior ring is closed and wound counter-clockwise. With fewer than 3 sides, the
+/// Polygon is empty.
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::circle::regular_polygon;
+///
+/// let square = regular_polygon(&Point::new(0., 0.), 2f64.sqrt(), 4);
+///
+/// assert_eq!(square.exterior.0.len(), 5);
+/// assert!((square.area() - 4.).abs() < 1e-12);
+/// ```
+pub fn regular_polygon<T>(center: &Point<T>, radius: T, sides: usize) -> Polygon<T>
+    where T: CoordFloat
+{
+    ellipse(center, radius, radius, T::zero(), sides)
+}
+
+/// Returns a polygon with `sides` vertices on an ellipse around `center`, with semi-axes `a`
+/// and `b` along the x and y axes before it is rotated counter-clockwise by `rotation` degrees.
+///
+/// The vertices are evenly spaced in angle around the center of the unrotated ellipse, the
+/// first lying at the end of the `a` semi-axis. The exterior ring is closed, and wound
+/// counter-clockwise if `a` and `b` have the same sign. With fewer than 3 sides, the Polygon
+/// is empty.
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::circle::ellipse;
+///
+/// let diamond = ellipse(&Point::new(1., 1.), 2f64, 1., 90., 4);
+/// let vertex = diamond.exterior.0[0];
+///
+/// assert!((vertex.x() - 1.).abs() < 1e-12);
+/// assert!((vertex.y() - 3.).abs() < 1e-12);
+/// ```
+pub fn ellipse<T>(center: &Point<T>, a: T, b: T, rotation: T, sides: usize) -> Polygon<T>
+    where T: CoordFloat
+{
+    if sides < 3 {
+        return Polygon::new(LineString(vec![]), vec![]);
+    }
+    let (sin_rotation, cos_rotation) = rotation.to_radians().sin_cos();
+    let step = T::from(2. * PI).unwrap() / T::from(sides).unwrap();
+    let mut points: Vec<Point<T>> = (0..sides)
+        .map(|i| {
+            let (sin, cos) = (step * T::from(i).unwrap()).sin_cos();
+            let (x, y) = (a * cos, b * sin);
+            Point::new(center.x() + x * cos_rotation - y * sin_rotation,
+                       center.y() + x * sin_rotation + y * cos_rotation)
+        })
+        .collect();
+    let first = points[0];
+    points.push(first);
+    Polygon::new(LineString(points), vec![])
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::PI;
+    use types::Point;
+    use algorithm::area::Area;
+    use algorithm::distance::Distance;
+    use super::*;
+
+    #[test]
+    fn diamond_test() {
+        let diamond = regular_polygon(&Point::new(0., 0.), 1., 4);
+        let expected = [(1., 0.), (0., 1.), (-1., 0.), (0., -1.), (1., 0.)];
+        assert_eq!(diamond.exterior.0.len(), expected.len());
+        for (p, &(x, y)) in diamond.exterior.0.iter().zip(&expected) {
+            assert_relative_eq!(p.x(), x, epsilon = 1e-12);
+            assert_relative_eq!(p.y(), y, epsilon = 1e-12);
+        }
+        assert!(diamond.interiors.is_empty());
+        assert_relative_eq!(diamond.area(), 2., epsilon = 1e-12);
+    }
+    #[test]
+    fn circle_area_test() {
+        let center = Point::new(3., -2.);
+        let circle = regular_polygon(&center, 5., 360);
+        assert_eq!(circle.exterior.0.len(), 361);
+        assert_eq!(circle.exterior.0.first(), circle.exterior.0.last());
+        assert_relative_eq!(circle.area(), PI * 25., max_relative = 1e-4);
+        for p in &circle.exterior.0 {
+            assert_relative_eq!(p.distance(&center), 5., epsilon = 1e-12);
+        }
+    }
+    #[test]
+    fn ellipse_test() {
+        let center = Point::new(1., 1.);
+        let upright = ellipse(&center, 3., 1., 0., 720);
+        assert_relative_eq!(upright.area(), PI * 3., max_relative = 1e-4);
+        assert_relative_eq!(upright.exterior.0[0].x(), 4., epsilon = 1e-12);
+        assert_relative_eq!(upright.exterior.0[180].y(), 2., epsilon = 1e-12);
+        // a quarter turn swaps the axes, without changing the area
+        let turned = ellipse(&center, 3., 1., 90., 720);
+        assert_relative_eq!(turned.area(), upright.area(), epsilon = 1e-9);
+        assert_relative_eq!(turned.exterior.0[0].x(), 1., epsilon = 1e-12);
+        assert_relative_eq!(turned.exterior.0[0].y(), 4., epsilon = 1e-12);
+        assert_relative_eq!(turned.exterior.0[180].x(), 0., epsilon = 1e-12);
+    }
+    #[test]
+    fn too_few_sides_test() {
+        assert!(regular_polygon(&Point::new(0., 0.), 1., 2).exterior.0.is_empty());
+        assert!(ellipse(&Point::new(0., 0.), 1., 2., 0., 0).exterior.0.is_empty());
+    }
+}
@@ -0,0 +1,281 @@
+use types::{CoordFloat, Point, LineString, MultiLineString, Polygon, Bbox};
+use algorithm::boundingbox::BoundingBox;
+
+// Clip the segment (start, end) to the bbox using the Liang–Barsky algorithm.
+// Returns the parameters (t0, t1) along the segment of the clipped portion,
+// or None if the segment lies entirely outside the bbox.
+fn liang_barsky<T>(start: &Point<T>, end: &Point<T>, bbox: &Bbox<T>) -> Option<(T, T)>
+    where T: CoordFloat
+{
+    let dx = end.x() - start.x();
+    let dy = end.y() - start.y();
+    let checks = [(-dx, start.x() - bbox.xmin),
+                  (dx, bbox.xmax - start.x()),
+                  (-dy, start.y() - bbox.ymin),
+                  (dy, bbox.ymax - start.y())];
+    let mut t0 = T::zero();
+    let mut t1 = T::one();
+    for &(p, q) in &checks {
+        if p == T::zero() {
+            // parallel to this edge, and outside of it
+            if q < T::zero() {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < T::zero() {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+    if t0 > t1 {
+        None
+    } else {
+        Some((t0, t1))
+    }
+}
+
+// The point at parameter t along the segment (start, end)
+fn point_at<T>(start: &Point<T>, end: &Point<T>, t: T) -> Point<T>
+    where T: CoordFloat
+{
+    if t == T::one() {
+        return *end;
+    }
+    Point::new(start.x() + t * (end.x() - start.x()),
+               start.y() + t * (end.y() - start.y()))
+}
+
+/// Clips a `LineString` to a bounding box, using the Liang–Barsky algorithm.
+///
+/// Returns the portions of the `LineString` lying inside the bounding box. A line that
+/// leaves and re-enters the box produces one `LineString` per visit.
+///
+/// ```
+/// use geo::{Point, LineString, Bbox};
+/// use geo::algorithm::clip::clip_linestring_to_bbox;
+///
+/// let ls = LineString(vec![Point::new(-1., -1.), Point::new(3., 3.)]);
+/// let bbox = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 2. };
+/// let clipped = clip_linestring_to_bbox(&ls, &bbox);
+///
+/// assert_eq!(clipped.0, vec![LineString(vec![Point::new(0., 0.), Point::new(2., 2.)])]);
+/// ```
+pub fn clip_linestring_to_bbox<T: CoordFloat>(ls: &LineString<T>, bbox: &Bbox<T>) -> MultiLineString<T> {
+    let mut result = vec![];
+    let mut current: Vec<Point<T>> = vec![];
+    for segment in ls.0.windows(2) {
+        let (start, end) = (&segment[0], &segment[1]);
+        match liang_barsky(start, end, bbox) {
+            // ignore segments that only touch the bbox, unless they continue a visit
+            Some((t0, t1)) if t0 < t1 || !current.is_empty() => {
+                if current.is_empty() {
+                    current.push(point_at(start, end, t0));
+                }
+                // a segment leaving the box just as it starts adds nothing to the visit
+                let point = point_at(start, end, t1);
+                if current.last() != Some(&point) {
+                    current.push(point);
+                }
+                if t1 < T::one() {
+                    result.push(LineString(current.split_off(0)));
+                }
+            }
+            _ => {
+                if current.len() > 1 {
+                    result.push(LineString(current.split_off(0)));
+                }
+                current.clear();
+            }
+        }
+    }
+    if current.len() > 1 {
+        result.push(LineString(current));
+    }
+    MultiLineString(result)
+}
+
+// The four half-planes bounding a Bbox
+#[derive(Clone, Copy)]
+enum Edge {
+    Left,
+    Right,
+    Bottom,
+    Top,
+}
+
+fn inside<T>(point: &Point<T>, edge: Edge, bbox: &Bbox<T>) -> bool
+    where T: CoordFloat
+{
+    match edge {
+        Edge::Left => point.x() >= bbox.xmin,
+        Edge::Right => point.x() <= bbox.xmax,
+        Edge::Bottom => point.y() >= bbox.ymin,
+        Edge::Top => point.y() <= bbox.ymax,
+    }
+}
+
+// Intersection of the segment (start, end) with the line bounding the half-plane.
+// Only called when start and end lie on opposite sides of it.
+fn edge_intersection<T>(start: &Point<T>, end: &Point<T>, edge: Edge, bbox: &Bbox<T>) -> Point<T>
+    where T: CoordFloat
+{
+    let t = match edge {
+        Edge::Left => (bbox.xmin - start.x()) / (end.x() - start.x()),
+        Edge::Right => (bbox.xmax - start.x()) / (end.x() - start.x()),
+        Edge::Bottom => (bbox.ymin - start.y()) / (end.y() - start.y()),
+        Edge::Top => (bbox.ymax - start.y()) / (end.y() - start.y()),
+    };
+    point_at(start, end, t)
+}
+
+// Clip a closed ring to the bbox using the Sutherland–Hodgman algorithm.
+// Returns None if fewer than three points remain.
+fn clip_ring<T>(ring: &LineString<T>, bbox: &Bbox<T>) -> Option<LineString<T>>
+    where T: CoordFloat
+{
+    let mut points = ring.0.clone();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    for &edge in &[Edge::Left, Edge::Right, Edge::Bottom, Edge::Top] {
+        let input = points.split_off(0);
+        let mut prev = *input.last()?;
+        for cur in input {
+            if inside(&cur, edge, bbox) {
+                if !inside(&prev, edge, bbox) {
+                    points.push(edge_intersection(&prev, &cur, edge, bbox));
+                }
+                points.push(cur);
+            } else if inside(&prev, edge, bbox) {
+                points.push(edge_intersection(&prev, &cur, edge, bbox));
+            }
+            prev = cur;
+        }
+    }
+    points.dedup();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    if points.len() < 3 {
+        return None;
+    }
+    let first = points[0];
+    points.push(first);
+    Some(LineString(points))
+}
+
+/// Clips a `Polygon` to a bounding box, using the Sutherland–Hodgman algorithm.
+///
+/// A `Polygon` lying entirely inside the bounding box is returned unchanged, and one
+/// lying entirely outside it returns `None`. Interior rings are clipped in the same way,
+/// and dropped if nothing of them remains inside the bounding box.
+///
+/// ```
+/// use geo::{Point, LineString, Polygon, Bbox};
+/// use geo::algorithm::clip::clip_polygon_to_bbox;
+///
+/// let square = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 2. }.to_polygon();
+/// let window = Bbox { xmin: 1., xmax: 3., ymin: 1., ymax: 3. };
+/// let clipped = clip_polygon_to_bbox(&square, &window).unwrap();
+///
+/// assert_eq!(clipped.exterior, LineString(vec![Point::new(1., 1.), Point::new(2., 1.),
+///                                              Point::new(2., 2.), Point::new(1., 2.),
+///                                              Point::new(1., 1.)]));
+/// ```
+pub fn clip_polygon_to_bbox<T: CoordFloat>(poly: &Polygon<T>, bbox: &Bbox<T>) -> Option<Polygon<T>> {
+    let poly_bbox = poly.bbox()?;
+    if poly_bbox.xmin >= bbox.xmin && poly_bbox.xmax <= bbox.xmax &&
+       poly_bbox.ymin >= bbox.ymin && poly_bbox.ymax <= bbox.ymax {
+        return Some(poly.clone());
+    }
+    clip_ring(&poly.exterior, bbox).map(|exterior| {
+        let interiors = poly.interiors
+            .iter()
+            .filter_map(|ring| clip_ring(ring, bbox))
+            .collect();
+        Polygon::new(exterior, interiors)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, Bbox};
+    use super::*;
+
+    fn bbox() -> Bbox<f64> {
+        Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 2. }
+    }
+
+    #[test]
+    fn clip_diagonal_linestring_test() {
+        let ls = LineString(vec![Point::new(-1., 0.), Point::new(3., 2.)]);
+        let clipped = clip_linestring_to_bbox(&ls, &bbox());
+        assert_eq!(clipped.0, vec![LineString(vec![Point::new(0., 0.5), Point::new(2., 1.5)])]);
+    }
+    #[test]
+    fn clip_linestring_inside_test() {
+        let ls = LineString(vec![Point::new(0.5, 0.5), Point::new(1., 1.5), Point::new(1.5, 0.5)]);
+        let clipped = clip_linestring_to_bbox(&ls, &bbox());
+        assert_eq!(clipped.0, vec![ls]);
+    }
+    #[test]
+    fn clip_linestring_outside_test() {
+        let ls = LineString(vec![Point::new(3., 3.), Point::new(4., 3.), Point::new(4., -1.)]);
+        let clipped = clip_linestring_to_bbox(&ls, &bbox());
+        assert!(clipped.0.is_empty());
+    }
+    #[test]
+    fn clip_linestring_reentering_test() {
+        let ls = LineString(vec![Point::new(1., -1.),
+                                 Point::new(1., 1.),
+                                 Point::new(3., 1.),
+                                 Point::new(3., 0.5),
+                                 Point::new(1.5, 0.5)]);
+        let clipped = clip_linestring_to_bbox(&ls, &bbox());
+        assert_eq!(clipped.0,
+                   vec![LineString(vec![Point::new(1., 0.), Point::new(1., 1.), Point::new(2., 1.)]),
+                        LineString(vec![Point::new(2., 0.5), Point::new(1.5, 0.5)])]);
+    }
+    #[test]
+    fn clip_linestring_leaving_at_vertex_test() {
+        let ls = LineString(vec![Point::new(1., 1.), Point::new(2., 1.), Point::new(3., 1.)]);
+        let clipped = clip_linestring_to_bbox(&ls, &bbox());
+        assert_eq!(clipped.0, vec![LineString(vec![Point::new(1., 1.), Point::new(2., 1.)])]);
+    }
+    #[test]
+    fn clip_polygon_inside_test() {
+        let unit_square = Bbox { xmin: 0., xmax: 1., ymin: 0., ymax: 1. }.to_polygon();
+        assert_eq!(clip_polygon_to_bbox(&unit_square, &bbox()), Some(unit_square));
+    }
+    #[test]
+    fn clip_polygon_outside_test() {
+        let unit_square = Bbox { xmin: 0., xmax: 1., ymin: 0., ymax: 1. }.to_polygon();
+        let window = Bbox { xmin: 2., xmax: 3., ymin: -1., ymax: 3. };
+        assert_eq!(clip_polygon_to_bbox(&unit_square, &window), None);
+    }
+    #[test]
+    fn clip_polygon_overlapping_test() {
+        let unit_square = Bbox { xmin: 0., xmax: 1., ymin: 0., ymax: 1. }.to_polygon();
+        let window = Bbox { xmin: 0.5, xmax: 2., ymin: -1., ymax: 0.5 };
+        let correct = Polygon::new(LineString(vec![Point::new(0.5, 0.5),
+                                                   Point::new(0.5, 0.),
+                                                   Point::new(1., 0.),
+                                                   Point::new(1., 0.5),
+                                                   Point::new(0.5, 0.5)]),
+                                   vec![]);
+        assert_eq!(clip_polygon_to_bbox(&unit_square, &window), Some(correct));
+    }
+    #[test]
+    fn clip_polygon_with_hole_test() {
+        let exterior = Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 4. }.to_line_string();
+        let hole_kept = Bbox { xmin: 1., xmax: 2., ymin: 1., ymax: 2. }.to_line_string();
+        let hole_dropped = Bbox { xmin: 3., xmax: 3.5, ymin: 3., ymax: 3.5 }.to_line_string();
+        let poly = Polygon::new(exterior, vec![hole_kept.clone(), hole_dropped]);
+        let window = Bbox { xmin: -1., xmax: 2.5, ymin: -1., ymax: 2.5 };
+        let clipped = clip_polygon_to_bbox(&poly, &window).unwrap();
+        assert_eq!(clipped.interiors, vec![hole_kept]);
+    }
+}
@@ -0,0 +1,154 @@
+use types::{CoordFloat, Point, MultiPoint, total_cmp};
+use algorithm::distance::Distance;
+
+// the closest pair so far, as indices into the original points and their distance
+type Pair<T> = (usize, usize, T);
+
+fn closer<T>(a: Option<Pair<T>>, b: Option<Pair<T>>) -> Option<Pair<T>>
+    where T: CoordFloat
+{
+    match (a, b) {
+        (Some(a), Some(b)) => if b.2 < a.2 { Some(b) } else { Some(a) },
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+// `by_x` holds indices sorted by x, `by_y` the same indices sorted by y
+// `in_left` is scratch space, one flag per point, all false between uses
+fn closest_in<T>(points: &[Point<T>], by_x: &[usize], by_y: &[usize], in_left: &mut [bool]) -> Option<Pair<T>>
+    where T: CoordFloat
+{
+    let n = by_x.len();
+    if n <= 3 {
+        let mut best = None;
+        for (k, &i) in by_x.iter().enumerate() {
+            for &j in &by_x[k + 1..] {
+                best = closer(best, Some((i, j, points[i].distance(&points[j]))));
+            }
+        }
+        return best;
+    }
+    let mid = n / 2;
+    let (left, right) = by_x.split_at(mid);
+    // split the y order to match, by membership so that ties in x are handled
+    for &i in left {
+        in_left[i] = true;
+    }
+    let (left_y, right_y): (Vec<usize>, Vec<usize>) = by_y.iter().partition(|&&i| in_left[i]);
+    for &i in left {
+        in_left[i] = false;
+    }
+    let best = closer(closest_in(points, left, &left_y, in_left),
+                      closest_in(points, right, &right_y, in_left));
+    let mut delta = best.map_or(T::infinity(), |b| b.2);
+
+    // only points within delta of the dividing line can form a closer pair, and each
+    // of those only needs comparing with the few after it in y order
+    let split_x = points[right[0]].x();
+    let strip: Vec<usize> = by_y.iter().cloned().filter(|&i| (points[i].x() - split_x).abs() < delta).collect();
+    let mut best = best;
+    for (k, &i) in strip.iter().enumerate() {
+        for &j in &strip[k + 1..] {
+            if points[j].y() - points[i].y() >= delta {
+                break;
+            }
+            let d = points[i].distance(&points[j]);
+            if d < delta {
+                delta = d;
+                best = Some((i, j, d));
+            }
+        }
+    }
+    best
+}
+
+/// Finds the two closest points in a `MultiPoint`, returning their indices and distance.
+///
+/// This uses the `O(n log n)` divide-and-conquer algorithm. The indices are returned in
+/// ascending order. `None` is returned if there are fewer than two points.
+///
+/// ```
+/// use geo::{Point, MultiPoint};
+/// use geo::algorithm::closest_pair::closest_pair;
+///
+/// let points = MultiPoint(vec![Point::new(0., 0.), Point::new(5., 5.), Point::new(10., 0.),
+///                              Point::new(5., 6.)]);
+///
+/// assert_eq!(closest_pair(&points), Some((1, 3, 1.)));
+/// ```
+pub fn closest_pair<T>(points: &MultiPoint<T>) -> Option<(usize, usize, T)>
+    where T: CoordFloat
+{
+    let points = &points.0;
+    if points.len() < 2 {
+        return None;
+    }
+    let mut by_x: Vec<usize> = (0..points.len()).collect();
+    by_x.sort_by(|&a, &b| points[a].0.cmp_lexicographic(&points[b].0));
+    let mut by_y = by_x.clone();
+    by_y.sort_by(|&a, &b| total_cmp(points[a].y(), points[b].y()));
+    closest_in(points, &by_x, &by_y, &mut vec![false; points.len()]).map(|(i, j, d)| if i < j { (i, j, d) } else { (j, i, d) })
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, MultiPoint};
+    use algorithm::distance::Distance;
+    use super::*;
+
+    fn brute_force(points: &MultiPoint<f64>) -> f64 {
+        let mut best = f64::INFINITY;
+        for i in 0..points.0.len() {
+            for j in (i + 1)..points.0.len() {
+                best = best.min(points.0[i].distance(&points.0[j]));
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn obvious_pair_test() {
+        // a coarse grid, with one extra point just beside (30, 40)
+        let mut points = Vec::new();
+        for i in 0..10 {
+            for j in 0..10 {
+                points.push(Point::new(i as f64 * 10., j as f64 * 10.));
+            }
+        }
+        points.push(Point::new(30.5, 40.));
+        let points = MultiPoint(points);
+        assert_eq!(closest_pair(&points), Some((34, 100, 0.5)));
+        assert_eq!(brute_force(&points), 0.5);
+    }
+    #[test]
+    fn matches_brute_force_test() {
+        // a deterministic scatter of points
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 8) as f64 / (1 << 24) as f64 * 100.
+        };
+        for n in 2..60 {
+            let points = MultiPoint((0..n).map(|_| Point::new(next(), next())).collect());
+            let (i, j, d) = closest_pair(&points).unwrap();
+            assert!(i < j);
+            assert_eq!(points.0[i].distance(&points.0[j]), d);
+            assert_eq!(d, brute_force(&points));
+        }
+    }
+    #[test]
+    fn too_few_test() {
+        assert_eq!(closest_pair::<f64>(&MultiPoint(vec![])), None);
+        assert_eq!(closest_pair(&MultiPoint(vec![Point::new(1., 1.)])), None);
+        // duplicates are at distance zero
+        let points = MultiPoint(vec![Point::new(1., 1.), Point::new(3., 3.), Point::new(1., 1.)]);
+        assert_eq!(closest_pair(&points), Some((0, 2, 0.)));
+    }    #[test]
+    fn nan_test() {
+        // NaN coordinates sort after every number, so they can't split the real pair
+        let points = MultiPoint(vec![Point::new(0., 0.), Point::new(f64::NAN, 1.), Point::new(5., 5.),
+                                     Point::new(f64::NAN, f64::NAN), Point::new(5., 6.), Point::new(9., 0.)]);
+        assert_eq!(closest_pair(&points), Some((2, 4, 1.)));
+    }
+}
@@ -0,0 +1,65 @@
+use types::{CoordFloat, Polygon};
+use algorithm::area::Area;
+
+pub trait Compactness<T> {
+    /// Returns the Polsby-Popper compactness of a geometry, `4π · area / perimeter²`.
+    ///
+    /// This is `1` for a circle, and lower the less compact the shape is. The perimeter
+    /// includes any interior rings. A geometry with no perimeter scores `0`.
+    ///
+    /// ```
+    /// use geo::Bbox;
+    /// use geo::algorithm::compactness::Compactness;
+    ///
+    /// let square = Bbox { xmin: 0., xmax: 1., ymin: 0., ymax: 1. }.to_polygon();
+    /// let strip = Bbox { xmin: 0., xmax: 100., ymin: 0., ymax: 1. }.to_polygon();
+    ///
+    /// assert!(strip.polsby_popper() < square.polsby_popper());
+    /// ```
+    fn polsby_popper(&self) -> T where T: CoordFloat;
+}
+
+impl<T> Compactness<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn polsby_popper(&self) -> T {
+        let perimeter = self.perimeter();
+        if perimeter.is_zero() {
+            return T::zero();
+        }
+        let four_pi = T::from(4. * ::std::f64::consts::PI).unwrap();
+        four_pi * self.area().abs() / (perimeter * perimeter)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::PI;
+    use types::{Point, LineString, Polygon, Bbox};
+    use super::*;
+
+    #[test]
+    fn square_test() {
+        let square = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 2. }.to_polygon();
+        assert_relative_eq!(square.polsby_popper(), PI / 4.);
+        assert_relative_eq!(square.polsby_popper(), 0.785, epsilon = 1e-3);
+    }
+    #[test]
+    fn thin_rectangle_test() {
+        let strip = Bbox { xmin: 0., xmax: 100., ymin: 0., ymax: 1. }.to_polygon();
+        assert!(strip.polsby_popper() < 0.05);
+    }
+    #[test]
+    fn circle_test() {
+        let ring = LineString((0..=360).map(|i| {
+            let angle = (i as f64).to_radians();
+            Point::new(angle.cos(), angle.sin())
+        }).collect());
+        assert_relative_eq!(Polygon::new(ring, vec![]).polsby_popper(), 1., epsilon = 1e-3);
+    }
+    #[test]
+    fn degenerate_test() {
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.polsby_popper(), 0.);
+    }
+}
@@ -1,7 +1,6 @@
-use num_traits::{Float, ToPrimitive};
-
-use types::{COORD_PRECISION, Point, Line, LineString, Polygon, MultiPolygon, Bbox};
-use algorithm::intersects::Intersects;
+use types::{CoordFloat, Point, Line, LineString, Polygon, MultiPolygon, Bbox};
+use constants::DEFAULT_EPSILON;
+use algorithm::intersects::{Intersects, IntersectsWithEpsilon};
 use algorithm::distance::Distance;
 
 ///  Checks if the geometry A is completely inside the B geometry.
@@ -32,16 +31,93 @@ pub trait Contains<Rhs = Self> {
     fn contains(&self, rhs: &Rhs) -> bool;
 }
 
+/// Checks if a point lies on the boundary of a geometry, to within a tolerance.
+pub trait OnBoundary<T, Rhs> {
+    /// Checks if a point lies on the boundary of a geometry: for a `Polygon`, on an
+    /// edge of its exterior or of one of its interior rings.
+    ///
+    /// Points within `DEFAULT_EPSILON` of the boundary are considered to lie on it.
+    /// Together with `contains`, which is `false` for points on the boundary, this
+    /// classifies a point as inside, on the boundary of, or outside a `Polygon`.
+    ///
+    /// ```
+    /// use geo::{Coordinate, Point, LineString, Polygon};
+    /// use geo::algorithm::contains::{Contains, OnBoundary};
+    ///
+    /// let p = |x, y| Point(Coordinate { x: x, y: y });
+    /// let linestring = LineString(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.), p(0., 0.)]);
+    /// let poly = Polygon::new(linestring, Vec::new());
+    ///
+    /// assert!(poly.on_boundary(&p(1., 0.)));
+    /// assert!(!poly.contains(&p(1., 0.)));
+    /// assert!(!poly.on_boundary(&p(1., 1.)));
+    /// ```
+    fn on_boundary(&self, rhs: &Rhs) -> bool;
+
+    /// Checks if a point lies within `epsilon` of the boundary of a geometry.
+    ///
+    /// ```
+    /// use geo::{Point, Bbox};
+    /// use geo::algorithm::contains::OnBoundary;
+    ///
+    /// let poly = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 2. }.to_polygon();
+    ///
+    /// assert!(poly.on_boundary_with_epsilon(&Point::new(1., 0.01), 0.1));
+    /// assert!(!poly.on_boundary_with_epsilon(&Point::new(1., 0.01), 0.001));
+    /// ```
+    fn on_boundary_with_epsilon(&self, rhs: &Rhs, epsilon: T) -> bool;
+}
+
+impl<T> OnBoundary<T, Point<T>> for Polygon<T>
+    where T: CoordFloat
+{
+    fn on_boundary(&self, p: &Point<T>) -> bool {
+        self.on_boundary_with_epsilon(p, T::from(DEFAULT_EPSILON).unwrap())
+    }
+
+    fn on_boundary_with_epsilon(&self, p: &Point<T>, epsilon: T) -> bool {
+        ::std::iter::once(&self.exterior)
+            .chain(self.interiors.iter())
+            .any(|ring| ring.0.len() > 1 && p.distance(ring) <= epsilon)
+    }
+}
+
+/// Checks if a point lies inside a geometry, to within a tolerance.
+pub trait ContainsWithEpsilon<T, Rhs> {
+    /// Checks if the geometry B lies within `epsilon` of the geometry A.
+    ///
+    /// `contains` uses `DEFAULT_EPSILON` for the same check.
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::contains::{Contains, ContainsWithEpsilon};
+    ///
+    /// let p = Point::new(1., 1.);
+    ///
+    /// assert!(p.contains_with_epsilon(&Point::new(1., 1.05), 0.1));
+    /// assert!(!p.contains(&Point::new(1., 1.05)));
+    /// ```
+    fn contains_with_epsilon(&self, rhs: &Rhs, epsilon: T) -> bool;
+}
+
 impl<T> Contains<Point<T>> for Point<T>
-    where T: Float + ToPrimitive
+    where T: CoordFloat
 {
     fn contains(&self, p: &Point<T>) -> bool {
-        self.distance(p).to_f32().unwrap() < COORD_PRECISION
+        self.contains_with_epsilon(p, T::from(DEFAULT_EPSILON).unwrap())
+    }
+}
+
+impl<T> ContainsWithEpsilon<T, Point<T>> for Point<T>
+    where T: CoordFloat
+{
+    fn contains_with_epsilon(&self, p: &Point<T>, epsilon: T) -> bool {
+        self.distance(p) <= epsilon
     }
 }
 
 impl<T> Contains<Point<T>> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, p: &Point<T>) -> bool {
         let vect = &self.0;
@@ -72,15 +148,23 @@ impl<T> Contains<Point<T>> for LineString<T>
 }
 
 impl<T> Contains<Point<T>> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, p: &Point<T>) -> bool {
         self.intersects(p)
     }
 }
 
+impl<T> ContainsWithEpsilon<T, Point<T>> for Line<T>
+    where T: CoordFloat
+{
+    fn contains_with_epsilon(&self, p: &Point<T>, epsilon: T) -> bool {
+        self.intersects_with_epsilon(p, epsilon)
+    }
+}
+
 impl<T> Contains<Line<T>> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, line: &Line<T>) -> bool {
         self.contains(&line.start) & self.contains(&line.end)
@@ -88,7 +172,7 @@ impl<T> Contains<Line<T>> for Line<T>
 }
 
 impl<T> Contains<LineString<T>> for Line<T> 
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, linestring: &LineString<T>) -> bool {
         linestring.0.iter().all(|pt| self.contains(pt))
@@ -96,7 +180,7 @@ impl<T> Contains<LineString<T>> for Line<T>
 }
 
 impl<T> Contains<Line<T>> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, line: &Line<T>) -> bool {
         let (p0, p1) = (line.start, line.end);
@@ -137,7 +221,7 @@ enum PositionPoint {
 }
 
 fn get_position<T>(p: &Point<T>, linestring: &LineString<T>) -> PositionPoint
-    where T: Float
+    where T: CoordFloat
 {
     // See: http://www.ecse.rpi.edu/Homepages/wrf/Research/Short_Notes/pnpoly.html
     //      http://geospatialpython.com/search
@@ -154,20 +238,17 @@ fn get_position<T>(p: &Point<T>, linestring: &LineString<T>) -> PositionPoint
         return PositionPoint::OnBoundary;
     }
 
-    let mut xints = T::zero();
+    // Count the edges crossed by a ray running from the point towards +x. Each edge
+    // counts only if the point's y lies in [min_y, max_y), so a ray through a shared
+    // vertex is counted once rather than twice (or not at all at a spike), and
+    // horizontal edges are never counted.
     let mut crossings = 0;
     for ps in vect.windows(2) {
-        if p.y() > ps[0].y().min(ps[1].y()) {
-            if p.y() <= ps[0].y().max(ps[1].y()) {
-                if p.x() <= ps[0].x().max(ps[1].x()) {
-                    if ps[0].y() != ps[1].y() {
-                        xints = (p.y() - ps[0].y()) * (ps[1].x() - ps[0].x()) /
-                                (ps[1].y() - ps[0].y()) + ps[0].x();
-                    }
-                    if (ps[0].x() == ps[1].x()) || (p.x() <= xints) {
-                        crossings += 1;
-                    }
-                }
+        if (ps[0].y() <= p.y()) != (ps[1].y() <= p.y()) {
+            let xint = (p.y() - ps[0].y()) * (ps[1].x() - ps[0].x()) /
+                       (ps[1].y() - ps[0].y()) + ps[0].x();
+            if p.x() < xint {
+                crossings += 1;
             }
         }
     }
@@ -179,7 +260,7 @@ fn get_position<T>(p: &Point<T>, linestring: &LineString<T>) -> PositionPoint
 }
 
 impl<T> Contains<Point<T>> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, p: &Point<T>) -> bool {
         match get_position(p, &self.exterior) {
@@ -191,7 +272,7 @@ impl<T> Contains<Point<T>> for Polygon<T>
 }
 
 impl<T> Contains<Point<T>> for MultiPolygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, p: &Point<T>) -> bool {
         self.0.iter().any(|poly| poly.contains(p))
@@ -199,7 +280,7 @@ impl<T> Contains<Point<T>> for MultiPolygon<T>
 }
 
 impl<T> Contains<Line<T>> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, line: &Line<T>) -> bool {
         // both endpoints are contained in the polygon and the line
@@ -212,7 +293,7 @@ impl<T> Contains<Line<T>> for Polygon<T>
 }
 
 impl<T> Contains<LineString<T>> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, linestring: &LineString<T>) -> bool {
         // All points of LineString must be in the polygon ?
@@ -225,7 +306,7 @@ impl<T> Contains<LineString<T>> for Polygon<T>
 }
 
 impl<T> Contains<Point<T>> for Bbox<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, p: &Point<T>) -> bool {
         p.x() >= self.xmin && p.x() <= self.xmax && p.y() >= self.ymin && p.y() <= self.ymax
@@ -233,7 +314,7 @@ impl<T> Contains<Point<T>> for Bbox<T>
 }
 
 impl<T> Contains<Bbox<T>> for Bbox<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn contains(&self, bbox: &Bbox<T>) -> bool {
         // All points of LineString must be in the polygon ?
@@ -245,7 +326,7 @@ impl<T> Contains<Bbox<T>> for Bbox<T>
 #[cfg(test)]
 mod test {
     use types::{Coordinate, Point, Line, LineString, Polygon, MultiPolygon, Bbox};
-    use algorithm::contains::Contains;
+    use algorithm::contains::{Contains, ContainsWithEpsilon, OnBoundary};
     /// Tests: Point in LineString
     #[test]
     fn empty_linestring_test() {
@@ -324,6 +405,69 @@ mod test {
         assert!(!poly.contains(&p(1.5, 1.5)));
         assert!(!poly.contains(&p(1.5, 1.)));
     }
+    #[test]
+    fn point_polygon_winding_test() {
+        let p = |x, y| Point(Coordinate { x: x, y: y });
+        let exterior = LineString(vec![p(0., 0.), p(4., 0.), p(4., 4.), p(0., 4.), p(0., 0.)]);
+        let hole = LineString(vec![p(1., 1.), p(2., 1.), p(2., 2.), p(1., 2.), p(1., 1.)]);
+        let reversed = |ls: &LineString<f64>| LineString(ls.0.iter().rev().cloned().collect());
+        let polys = vec![Polygon::new(exterior.clone(), vec![hole.clone()]),
+                         Polygon::new(reversed(&exterior), vec![hole.clone()]),
+                         Polygon::new(exterior.clone(), vec![reversed(&hole)]),
+                         Polygon::new(reversed(&exterior), vec![reversed(&hole)])];
+        for poly in &polys {
+            assert!(poly.contains(&p(3., 3.)));
+            assert!(poly.contains(&p(0.5, 1.5)));
+            assert!(!poly.contains(&p(1.5, 1.5)));
+            assert!(!poly.contains(&p(5., 1.5)));
+            assert!(!poly.contains(&p(-1., -1.)));
+        }
+    }
+    #[test]
+    fn point_polygon_ray_through_vertex_test() {
+        let p = |x, y| Point::new(x, y);
+        // a diamond: the ray from each test point runs through the vertex at (4, 2)
+        let diamond = Polygon::new(LineString(vec![p(2., 0.), p(4., 2.), p(2., 4.), p(0., 2.), p(2., 0.)]),
+                                   vec![]);
+        assert!(diamond.contains(&p(1., 2.)));
+        assert!(diamond.contains(&p(3., 2.)));
+        assert!(!diamond.contains(&p(-1., 2.)));
+        assert!(!diamond.contains(&p(5., 2.)));
+        // a downward spike at (2, 2), the ray touching its tip without crossing
+        let spiked = Polygon::new(LineString(vec![p(0., 0.), p(1., 0.), p(2., 2.), p(3., 0.), p(4., 0.),
+                                                  p(4., 4.), p(0., 4.), p(0., 0.)]),
+                                  vec![]);
+        assert!(spiked.contains(&p(1., 2.)));
+        assert!(spiked.contains(&p(3., 2.)));
+        assert!(!spiked.contains(&p(-1., 2.)));
+        // a notch whose tip the ray touches from below
+        let notched = Polygon::new(LineString(vec![p(0., 0.), p(4., 0.), p(4., 4.), p(3., 4.), p(2., 2.),
+                                                   p(1., 4.), p(0., 4.), p(0., 0.)]),
+                                   vec![]);
+        assert!(notched.contains(&p(1., 2.)));
+        assert!(notched.contains(&p(3., 2.)));
+        assert!(!notched.contains(&p(2., 3.)));
+        assert!(!notched.contains(&p(-1., 2.)));
+    }
+    #[test]
+    fn point_polygon_ray_along_edge_test() {
+        let p = |x, y| Point::new(x, y);
+        // a step shape whose edge from (2, 2) to (4, 2) lies along the rays at y = 2
+        let step = Polygon::new(LineString(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(4., 2.), p(4., 4.),
+                                                p(0., 4.), p(0., 0.)]),
+                                vec![]);
+        assert!(step.contains(&p(1., 2.)));
+        assert!(!step.contains(&p(-1., 2.)));
+        assert!(!step.contains(&p(5., 2.)));
+        assert!(step.contains(&p(3., 3.)));
+        assert!(!step.contains(&p(3., 1.)));
+        // the ray along the bottom edge of a hole
+        let hole = LineString(vec![p(1., 3.), p(2., 3.), p(2., 3.5), p(1., 3.5), p(1., 3.)]);
+        let holed = Polygon::new(step.exterior.clone(), vec![hole]);
+        assert!(holed.contains(&p(0.5, 3.)));
+        assert!(holed.contains(&p(3., 3.)));
+        assert!(!holed.contains(&p(1.5, 3.25)));
+    }
     /// Tests: Point in MultiPolygon
     #[test]
     fn empty_multipolygon_test() {
@@ -466,4 +610,49 @@ mod test {
         assert!(linestring1.contains(&line0));
         assert!(!linestring2.contains(&line0));
     }
+    // Tests: Point on the boundary of a Polygon
+    #[test]
+    fn point_on_polygon_edge_test() {
+        let p = |x, y| Point::new(x, y);
+        let poly = Polygon::new(LineString(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 0.)]), vec![]);
+        assert!(poly.on_boundary(&p(2., 1.)));
+        // on the diagonal edge
+        assert!(poly.on_boundary(&p(1.5, 1.5)));
+    }
+    #[test]
+    fn point_on_polygon_vertex_test() {
+        let p = |x, y| Point::new(x, y);
+        let poly = Polygon::new(LineString(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.), p(0., 0.)]),
+                                vec![]);
+        assert!(poly.on_boundary(&p(2., 2.)));
+    }
+    #[test]
+    fn point_not_on_polygon_boundary_test() {
+        let p = |x, y| Point::new(x, y);
+        let poly = Polygon::new(LineString(vec![p(0., 0.), p(4., 0.), p(4., 4.), p(0., 4.), p(0., 0.)]),
+                                vec![LineString(vec![p(1., 1.), p(2., 1.), p(2., 2.), p(1., 2.), p(1., 1.)])]);
+        assert!(!poly.on_boundary(&p(3., 3.)));
+        assert!(!poly.on_boundary(&p(5., 3.)));
+        // on the edge of the hole
+        assert!(poly.on_boundary(&p(1.5, 2.)));
+    }
+    #[test]
+    fn point_near_polygon_boundary_test() {
+        let p = |x, y| Point::new(x, y);
+        let poly = Polygon::new(LineString(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.), p(0., 0.)]),
+                                vec![]);
+        let near = p(1., 1e-12);
+        assert!(poly.on_boundary(&near));
+        assert!(!poly.on_boundary_with_epsilon(&near, 1e-15));
+    }    #[test]
+    fn point_contains_near_point_test() {
+        let p = Point::new(1., 1.);
+        assert!(p.contains(&Point::new(1., 1. + 1e-12)));
+        assert!(!p.contains_with_epsilon(&Point::new(1., 1. + 1e-12), 1e-15));
+        assert!(!p.contains(&Point::new(1., 1.01)));
+        assert!(p.contains_with_epsilon(&Point::new(1., 1.01), 0.1));
+        let line = Line::new(Point::new(0., 0.), Point::new(2., 0.));
+        assert!(line.contains_with_epsilon(&Point::new(1., 0.01), 0.1));
+        assert!(!line.contains(&Point::new(1., 0.01)));
+    }
 }
@@ -1,5 +1,7 @@
-use num_traits::Float;
-use types::{Point, Polygon, MultiPolygon, LineString, MultiPoint, MultiLineString};
+use types::{CoordFloat, Point, Polygon, MultiPolygon, LineString, MultiPoint, MultiLineString, Geometry,
+            GeometryCollection};
+use algorithm::util::{orient2d, Orientation};
+use std::cmp::Ordering;
 use std::mem;
 
 fn swap_remove_to_first<'a, T>(slice: &mut &'a mut [T], idx: usize) -> &'a mut T {
@@ -35,33 +37,17 @@ fn partition<T, F: FnMut(&T) -> bool>(mut slice: &mut [T], mut pred: F) -> usize
     i
 }
 
-// Determine whether a point lies on one side of a line segment, or the other.
-// The cross product v x w of two vectors v and w is a vector whose length is
-// |v||w|sin φ, (where |v| is the length of v and φ is the angle between the vectors),
-// and which is orthogonal (perpendicular) to both v and w.  Since there are two
-// such possible vectors, the definition arbitrarily selects the one that matches
-// the direction in which a screw would move if rotated from v to w
-
-// Mathematically, if the coordinates of vectors v and w are (vx, vy) and (wx, wy)
-// respectively, the cross product will be (vxwy - vywx). If a segment is
-// defined by points A B and, we wish to check on which side of AB a third point C falls,
-// we can compute the cross product AB x AC and check its sign:
-// If it's negative, it will be on the "right" side of AB
-// (when standing on A and looking towards B). If positive, it will be on the left side
-fn cross_prod<T>(p_a: &Point<T>, p_b: &Point<T>, p_c: &Point<T>) -> T
-    where T: Float
-{
-    (p_b.x() - p_a.x()) * (p_c.y() - p_a.y()) - (p_b.y() - p_a.y()) * (p_c.x() - p_a.x())
-}
+// Determine whether a point lies strictly to the left of a line segment, looking from
+// p_a towards p_b. Points that are collinear within floating-point error are not.
 fn point_location<T>(p_a: &Point<T>, p_b: &Point<T>, p_c: &Point<T>) -> bool
-    where T: Float
+    where T: CoordFloat
 {
-    cross_prod(p_a, p_b, p_c) > T::zero()
+    orient2d(p_a, p_b, p_c) == Orientation::CounterClockwise
 }
 
 // Fast distance between line segment (p_a, p_b), and point p_c
 fn pseudo_distance<T>(p_a: &Point<T>, p_b: &Point<T>, p_c: &Point<T>) -> T
-    where T: Float
+    where T: CoordFloat
 {
     let abx = p_b.x() - p_a.x();
     let aby = p_b.y() - p_a.y();
@@ -69,13 +55,53 @@ fn pseudo_distance<T>(p_a: &Point<T>, p_b: &Point<T>, p_c: &Point<T>) -> T
     if dist < T::zero() { -dist } else { dist }
 }
 
+// Collect up to four distinct points, stopping as soon as a fourth is found.
+// Inputs with fewer than four distinct points don't need a full QuickHull pass.
+fn distinct_points<T>(points: &[Point<T>]) -> Vec<Point<T>>
+    where T: CoordFloat
+{
+    let mut distinct: Vec<Point<T>> = Vec::with_capacity(4);
+    for point in points {
+        if !distinct.contains(point) {
+            distinct.push(*point);
+            if distinct.len() > 3 {
+                break;
+            }
+        }
+    }
+    distinct
+}
+
+// Hull of at most three distinct points. A single point or a segment is
+// returned as-is (degenerate hull), three collinear points collapse to their
+// extreme segment, and a proper triangle is returned as a closed ccw ring.
+fn small_hull<T>(mut distinct: Vec<Point<T>>) -> Vec<Point<T>>
+    where T: CoordFloat
+{
+    if distinct.len() < 3 {
+        return distinct;
+    }
+    let orientation = orient2d(&distinct[0], &distinct[1], &distinct[2]);
+    if orientation == Orientation::Collinear {
+        distinct.sort_by(|a, b| a.0.cmp_lexicographic(&b.0));
+        return vec![distinct[0], distinct[2]];
+    }
+    if orientation == Orientation::Clockwise {
+        distinct.swap(1, 2);
+    }
+    let first = distinct[0];
+    distinct.push(first);
+    distinct
+}
+
 // Adapted from http://www.ahristov.com/tutorial/geometry-games/convex-hull.html
 fn quick_hull<T>(mut points: &mut [Point<T>]) -> Vec<Point<T>>
-    where T: Float
+    where T: CoordFloat
 {
-    // can't build a hull from fewer than four points
-    if points.len() < 4 {
-        return points.to_vec();
+    // can't build a hull from fewer than four distinct points
+    let distinct = distinct_points(points);
+    if distinct.len() < 4 {
+        return small_hull(distinct);
     }
     let mut hull = vec![];
     let mut min = swap_remove_to_first(&mut points, 0);
@@ -105,7 +131,7 @@ fn quick_hull<T>(mut points: &mut [Point<T>]) -> Vec<Point<T>>
 
 // recursively calculate the convex hull of a subset of points
 fn hull_set<T>(p_a: &Point<T>, p_b: &Point<T>, mut set: &mut [Point<T>], hull: &mut Vec<Point<T>>)
-    where T: Float
+    where T: CoordFloat
 {
     if set.is_empty() {
         return;
@@ -114,7 +140,7 @@ fn hull_set<T>(p_a: &Point<T>, p_b: &Point<T>, mut set: &mut [Point<T>], hull: &
         hull.push(set[0]);
         return;
     }
-    let mut furthest_distance = Float::min_value();
+    let mut furthest_distance = T::min_value();
     let mut furthest_idx = 0;
     for (idx, point) in set.iter().enumerate() {
         let current_distance = pseudo_distance(p_a, p_b, point);
@@ -156,11 +182,11 @@ pub trait ConvexHull<T> {
     /// let res = poly.convex_hull();
     /// assert_eq!(res.exterior, correct_hull);
     /// ```
-    fn convex_hull(&self) -> Polygon<T> where T: Float;
+    fn convex_hull(&self) -> Polygon<T> where T: CoordFloat;
 }
 
 impl<T> ConvexHull<T> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn convex_hull(&self) -> Polygon<T> {
         Polygon::new(LineString(quick_hull(&mut self.exterior.0.clone())), vec![])
@@ -168,7 +194,7 @@ impl<T> ConvexHull<T> for Polygon<T>
 }
 
 impl<T> ConvexHull<T> for MultiPolygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn convex_hull(&self) -> Polygon<T> {
         let mut aggregated: Vec<Point<T>> = self.0.iter()
@@ -179,7 +205,7 @@ impl<T> ConvexHull<T> for MultiPolygon<T>
 }
 
 impl<T> ConvexHull<T> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn convex_hull(&self) -> Polygon<T> {
         Polygon::new(LineString(quick_hull(&mut self.0.clone())), vec![])
@@ -187,7 +213,7 @@ impl<T> ConvexHull<T> for LineString<T>
 }
 
 impl<T> ConvexHull<T> for MultiLineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn convex_hull(&self) -> Polygon<T> {
         let mut aggregated: Vec<Point<T>> = self.0.iter()
@@ -198,13 +224,64 @@ impl<T> ConvexHull<T> for MultiLineString<T>
 }
 
 impl<T> ConvexHull<T> for MultiPoint<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn convex_hull(&self) -> Polygon<T> {
         Polygon::new(LineString(quick_hull(&mut self.0.clone())), vec![])
     }
 }
 
+impl<T> MultiPoint<T>
+    where T: CoordFloat
+{
+    /// Returns the convex hull of a MultiPoint as the simplest Geometry covering it.
+    ///
+    /// Unlike `convex_hull`, which always returns a Polygon, this returns a `Point` if there
+    /// is only one distinct point, a `LineString` between the two extreme points if all of
+    /// them are collinear, and a counter-clockwise `Polygon` otherwise. An empty MultiPoint
+    /// has an empty `GeometryCollection` as its hull.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, MultiPoint, Geometry};
+    ///
+    /// let collinear = MultiPoint(vec![Point::new(1., 1.), Point::new(3., 3.), Point::new(0., 0.)]);
+    /// match collinear.convex_hull_geometry() {
+    ///     Geometry::LineString(ls) => assert_eq!(ls, LineString(vec![Point::new(0., 0.), Point::new(3., 3.)])),
+    ///     _ => panic!("expected a LineString"),
+    /// }
+    /// ```
+    pub fn convex_hull_geometry(&self) -> Geometry<T> {
+        let mut distinct = self.0.iter().fold(Vec::new(), |mut distinct: Vec<Point<T>>, p| {
+            if distinct.len() < 2 && !distinct.contains(p) {
+                distinct.push(*p);
+            }
+            distinct
+        });
+        match distinct.len() {
+            0 => return Geometry::GeometryCollection(GeometryCollection(vec![])),
+            1 => return Geometry::Point(distinct[0]),
+            _ => (),
+        }
+        let (a, b) = (distinct[0], distinct[1]);
+        if self.0.iter().any(|p| orient2d(&a, &b, p) != Orientation::Collinear) {
+            return Geometry::Polygon(self.convex_hull());
+        }
+        // the extremes of the line, in lexicographic order
+        for p in &self.0 {
+            if p.0.cmp_lexicographic(&distinct[0].0) == Ordering::Less {
+                distinct[0] = *p;
+            }
+            if p.0.cmp_lexicographic(&distinct[1].0) == Ordering::Greater {
+                distinct[1] = *p;
+            }
+        }
+        if distinct[0].0.cmp_lexicographic(&distinct[1].0) == Ordering::Greater {
+            distinct.swap(0, 1);
+        }
+        Geometry::LineString(LineString(distinct))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use types::Point;
@@ -269,6 +346,47 @@ mod test {
         assert_eq!(res, v_correct);
     }
     #[test]
+    fn quick_hull_one_point_test() {
+        let mut v = vec![Point::new(1.0, 2.0)];
+        let res = quick_hull(&mut v);
+        assert_eq!(res, vec![Point::new(1.0, 2.0)]);
+    }
+    #[test]
+    fn quick_hull_two_points_test() {
+        let mut v = vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0), Point::new(1.0, 2.0)];
+        let res = quick_hull(&mut v);
+        assert_eq!(res, vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0)]);
+    }
+    #[test]
+    fn quick_hull_three_points_test() {
+        // clockwise input is returned as a closed, ccw triangle
+        let mut v = vec![Point::new(0.0, 0.0), Point::new(0.0, 1.0), Point::new(1.0, 0.0)];
+        let correct = vec![Point::new(0.0, 0.0),
+                           Point::new(1.0, 0.0),
+                           Point::new(0.0, 1.0),
+                           Point::new(0.0, 0.0)];
+        let res = quick_hull(&mut v);
+        assert_eq!(res, correct);
+    }
+    #[test]
+    fn quick_hull_closed_triangle_test() {
+        let mut v = vec![Point::new(0.0, 0.0),
+                         Point::new(1.0, 0.0),
+                         Point::new(0.0, 1.0),
+                         Point::new(0.0, 0.0)];
+        let correct = v.clone();
+        let res = quick_hull(&mut v);
+        assert_eq!(res, correct);
+    }
+    #[test]
+    fn quick_hull_three_collinear_points_test() {
+        let mut v = vec![Point::new(1.0, 1.0), Point::new(2.0, 2.0), Point::new(0.0, 0.0)];
+        let res = quick_hull(&mut v);
+        assert_eq!(res, vec![Point::new(0.0, 0.0), Point::new(2.0, 2.0)]);
+    }
+    #[test]
+    // the fixtures end in a semicolon, which the include ignores
+    #[allow(incomplete_include)]
     fn quick_hull_test_complex() {
         let coords = include!("test_fixtures/poly1.rs");
         let mut v: Vec<_> = coords.iter().map(|e| Point::new(e.0, e.1)).collect();
@@ -278,6 +396,8 @@ mod test {
         assert_eq!(res, v_correct);
     }
     #[test]
+    // the fixtures end in a semicolon, which the include ignores
+    #[allow(incomplete_include)]
     fn quick_hull_test_complex_2() {
         let coords = include!("test_fixtures/poly2.rs");
         let mut v: Vec<_> = coords.iter().map(|e| Point::new(e.0, e.1)).collect();
@@ -359,4 +479,37 @@ mod test {
         let res = mp.convex_hull();
         assert_eq!(res.exterior.0, correct);
     }
+    #[test]
+    fn convex_hull_geometry_test() {
+        let p = |x, y| Point::new(x, y);
+        match MultiPoint::<f64>(vec![]).convex_hull_geometry() {
+            Geometry::GeometryCollection(gc) => assert!(gc.0.is_empty()),
+            other => panic!("expected an empty GeometryCollection, got {:?}", other),
+        }
+        match MultiPoint(vec![p(1., 2.), p(1., 2.), p(1., 2.)]).convex_hull_geometry() {
+            Geometry::Point(point) => assert_eq!(point, p(1., 2.)),
+            other => panic!("expected a Point, got {:?}", other),
+        }
+        match MultiPoint(vec![p(3., 4.), p(1., 2.)]).convex_hull_geometry() {
+            Geometry::LineString(ls) => assert_eq!(ls.0, vec![p(1., 2.), p(3., 4.)]),
+            other => panic!("expected a LineString, got {:?}", other),
+        }
+        // many collinear points, including vertical ones and repeats
+        match MultiPoint(vec![p(2., 1.), p(2., 5.), p(2., -3.), p(2., 0.), p(2., 5.)]).convex_hull_geometry() {
+            Geometry::LineString(ls) => assert_eq!(ls.0, vec![p(2., -3.), p(2., 5.)]),
+            other => panic!("expected a LineString, got {:?}", other),
+        }
+        let square = MultiPoint(vec![p(0., 0.), p(1., 1.), p(0., 1.), p(1., 0.), p(0.5, 0.5)]);
+        match square.convex_hull_geometry() {
+            Geometry::Polygon(poly) => assert_eq!(poly, square.convex_hull()),
+            other => panic!("expected a Polygon, got {:?}", other),
+        }
+    }    #[test]
+    fn small_hull_nan_test() {
+        // a NaN coordinate makes the points look collinear; sorting them must not panic
+        let mp = MultiPoint(vec![Point::new(0., 0.), Point::new(f64::NAN, 1.), Point::new(2., 2.)]);
+        let hull = mp.convex_hull();
+        assert_eq!(hull.exterior.0.len(), 2);
+        assert_eq!(hull.exterior.0[0], Point::new(0., 0.));
+    }
 }
@@ -0,0 +1,146 @@
+use std::iter;
+use types::{CoordFloat, Coordinate, Point, Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon,
+            Geometry, GeometryCollection};
+
+pub trait CoordsIter<T: CoordFloat> {
+    /// Iterates over every `Coordinate` of a geometry, in order.
+    ///
+    /// The rings of a `Polygon` are visited exterior first, then each interior ring in turn;
+    /// multi-geometries and collections visit each of their members in turn.
+    ///
+    /// ```
+    /// use geo::{Coordinate, Point, LineString, Polygon};
+    /// use geo::algorithm::coords_iter::CoordsIter;
+    ///
+    /// let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 1.), Point::new(1., 0.)]);
+    /// let poly = Polygon::new(ls, vec![]);
+    ///
+    /// let xs: Vec<f64> = poly.coords_iter().map(|c| c.x).collect();
+    /// assert_eq!(xs, vec![0., 1., 1.]);
+    /// ```
+    fn coords_iter<'a>(&'a self) -> impl Iterator<Item = Coordinate<T>> + 'a;
+}
+
+impl<T> CoordsIter<T> for Point<T>
+    where T: CoordFloat
+{
+    fn coords_iter<'a>(&'a self) -> impl Iterator<Item = Coordinate<T>> + 'a {
+        iter::once(self.0)
+    }
+}
+
+impl<T> CoordsIter<T> for Line<T>
+    where T: CoordFloat
+{
+    fn coords_iter<'a>(&'a self) -> impl Iterator<Item = Coordinate<T>> + 'a {
+        iter::once(self.start.0).chain(iter::once(self.end.0))
+    }
+}
+
+impl<T> CoordsIter<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn coords_iter<'a>(&'a self) -> impl Iterator<Item = Coordinate<T>> + 'a {
+        self.0.iter().map(|p| p.0)
+    }
+}
+
+impl<T> CoordsIter<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn coords_iter<'a>(&'a self) -> impl Iterator<Item = Coordinate<T>> + 'a {
+        self.exterior
+            .coords_iter()
+            .chain(self.interiors.iter().flat_map(|ring| ring.coords_iter()))
+    }
+}
+
+impl<T> CoordsIter<T> for MultiPoint<T>
+    where T: CoordFloat
+{
+    fn coords_iter<'a>(&'a self) -> impl Iterator<Item = Coordinate<T>> + 'a {
+        self.0.iter().map(|p| p.0)
+    }
+}
+
+impl<T> CoordsIter<T> for MultiLineString<T>
+    where T: CoordFloat
+{
+    fn coords_iter<'a>(&'a self) -> impl Iterator<Item = Coordinate<T>> + 'a {
+        self.0.iter().flat_map(|ls| ls.coords_iter())
+    }
+}
+
+impl<T> CoordsIter<T> for MultiPolygon<T>
+    where T: CoordFloat
+{
+    fn coords_iter<'a>(&'a self) -> impl Iterator<Item = Coordinate<T>> + 'a {
+        self.0.iter().flat_map(|poly| poly.coords_iter())
+    }
+}
+
+impl<T> CoordsIter<T> for Geometry<T>
+    where T: CoordFloat
+{
+    fn coords_iter<'a>(&'a self) -> impl Iterator<Item = Coordinate<T>> + 'a {
+        let inner: Box<dyn Iterator<Item = Coordinate<T>> + 'a> = match *self {
+            Geometry::Point(ref g) => Box::new(g.coords_iter()),
+            Geometry::LineString(ref g) => Box::new(g.coords_iter()),
+            Geometry::Polygon(ref g) => Box::new(g.coords_iter()),
+            Geometry::MultiPoint(ref g) => Box::new(g.coords_iter()),
+            Geometry::MultiLineString(ref g) => Box::new(g.coords_iter()),
+            Geometry::MultiPolygon(ref g) => Box::new(g.coords_iter()),
+            Geometry::GeometryCollection(ref g) => Box::new(g.coords_iter()),
+        };
+        inner
+    }
+}
+
+impl<T> CoordsIter<T> for GeometryCollection<T>
+    where T: CoordFloat
+{
+    fn coords_iter<'a>(&'a self) -> impl Iterator<Item = Coordinate<T>> + 'a {
+        // boxed to break the recursion between Geometry and GeometryCollection
+        let inner: Box<dyn Iterator<Item = Coordinate<T>> + 'a> =
+            Box::new(self.0.iter().flat_map(|g| g.coords_iter()));
+        inner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Coordinate, Point, Line, LineString, Polygon, MultiPolygon, Geometry,
+                GeometryCollection};
+    use super::*;
+
+    #[test]
+    fn polygon_with_hole_test() {
+        let p = |x, y| Point::new(x, y);
+        let exterior = LineString(vec![p(0., 0.), p(4., 0.), p(4., 4.), p(0., 0.)]);
+        let interior = LineString(vec![p(1., 1.), p(2., 1.), p(2., 2.), p(1., 1.)]);
+        let poly = Polygon::new(exterior.clone(), vec![interior.clone()]);
+        let coords: Vec<Coordinate<f64>> = poly.coords_iter().collect();
+        let correct: Vec<Coordinate<f64>> = exterior.0
+            .iter()
+            .chain(interior.0.iter())
+            .map(|p| p.0)
+            .collect();
+        assert_eq!(coords, correct);
+    }
+    #[test]
+    fn line_test() {
+        let line = Line::new(Point::new(0., 1.), Point::new(2., 3.));
+        let coords: Vec<_> = line.coords_iter().collect();
+        assert_eq!(coords, vec![Coordinate { x: 0., y: 1. }, Coordinate { x: 2., y: 3. }]);
+    }
+    #[test]
+    fn geometry_collection_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 1.), Point::new(0., 0.)]);
+        let mp = MultiPolygon(vec![Polygon::new(ls.clone(), vec![])]);
+        let gc = GeometryCollection(vec![Geometry::Point(Point::new(5., 5.)),
+                                         Geometry::LineString(ls),
+                                         Geometry::MultiPolygon(mp)]);
+        assert_eq!(gc.coords_iter().count(), 7);
+        assert_eq!(gc.coords_iter().next(), Some(Coordinate { x: 5., y: 5. }));
+    }
+}
@@ -0,0 +1,127 @@
+use types::{CoordFloat, Point};
+use constants::MEAN_EARTH_RADIUS;
+use algorithm::haversine_distance::HaversineDistance;
+
+pub trait CrossTrackDistance<T: CoordFloat> {
+    /// Returns the cross-track distance of a point from the great-circle path
+    /// running from `start` through `end`: the distance, in meters, between the
+    /// point and the nearest point on that great circle.
+    ///
+    /// The result is signed: it is positive if the point lies to the right of the
+    /// path (looking from `start` towards `end`) and negative if it lies to the left.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::Point;
+    /// use geo::algorithm::cross_track_distance::CrossTrackDistance;
+    ///
+    /// # fn main() {
+    /// let p = Point::new(5., -0.01);
+    /// let dist = p.cross_track_distance(&Point::new(0., 0.), &Point::new(10., 0.));
+    /// assert_relative_eq!(dist, 1111.9492664455, epsilon = 1.0e-6);
+    /// # }
+    /// ```
+    fn cross_track_distance(&self, start: &Point<T>, end: &Point<T>) -> T;
+
+    /// Returns the along-track distance of a point on the great-circle path running
+    /// from `start` through `end`: the distance, in meters, from `start` to the point
+    /// on the path nearest to this point.
+    ///
+    /// The result is negative if the nearest point on the path lies behind `start`.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::Point;
+    /// use geo::algorithm::cross_track_distance::CrossTrackDistance;
+    /// use geo::algorithm::haversine_distance::HaversineDistance;
+    ///
+    /// # fn main() {
+    /// let (start, end) = (Point::new(0., 0.), Point::new(10., 0.));
+    /// let dist = Point::new(5., -0.01).along_track_distance(&start, &end);
+    /// assert_relative_eq!(dist, start.haversine_distance(&Point::new(5., 0.)), epsilon = 1.0e-6);
+    /// # }
+    /// ```
+    fn along_track_distance(&self, start: &Point<T>, end: &Point<T>) -> T;
+}
+
+// initial great-circle bearing from a to b, in radians
+pub(crate) fn initial_bearing<T>(a: &Point<T>, b: &Point<T>) -> T
+    where T: CoordFloat
+{
+    let (lat1, lat2) = (a.y().to_radians(), b.y().to_radians());
+    let delta_lambda = (b.x() - a.x()).to_radians();
+    let y = delta_lambda.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lambda.cos();
+    y.atan2(x)
+}
+
+// angular distance from start to p, and the angle between the path and the
+// great circle from start to p
+fn angles<T>(p: &Point<T>, start: &Point<T>, end: &Point<T>) -> (T, T)
+    where T: CoordFloat
+{
+    let delta_13 = start.haversine_distance(p) / T::from(MEAN_EARTH_RADIUS).unwrap();
+    let theta = initial_bearing(start, p) - initial_bearing(start, end);
+    (delta_13, theta)
+}
+
+impl<T> CrossTrackDistance<T> for Point<T>
+    where T: CoordFloat
+{
+    fn cross_track_distance(&self, start: &Point<T>, end: &Point<T>) -> T {
+        let (delta_13, theta) = angles(self, start, end);
+        (delta_13.sin() * theta.sin()).asin() * T::from(MEAN_EARTH_RADIUS).unwrap()
+    }
+
+    fn along_track_distance(&self, start: &Point<T>, end: &Point<T>) -> T {
+        let (delta_13, theta) = angles(self, start, end);
+        let delta_xt = (delta_13.sin() * theta.sin()).asin();
+        // clamp rounding error before taking acos
+        let delta_at = (delta_13.cos() / delta_xt.cos()).max(-T::one()).min(T::one()).acos();
+        let sign = if theta.cos() < T::zero() { -T::one() } else { T::one() };
+        sign * delta_at * T::from(MEAN_EARTH_RADIUS).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::Point;
+    use algorithm::haversine_distance::HaversineDistance;
+    use algorithm::haversine_destination::HaversineDestination;
+    use super::*;
+
+    #[test]
+    fn cross_track_test() {
+        let (start, end) = (Point::new(-1.0_f64, 50.0), Point::new(1.0, 51.0));
+        let p = Point::new(0.1, 50.6);
+        let xtd = p.cross_track_distance(&start, &end);
+        // p lies to the left of the path, heading north-east
+        assert!(xtd < 0.);
+        // the foot of the perpendicular from p to the path
+        let atd = p.along_track_distance(&start, &end);
+        let foot = start.haversine_destination(initial_bearing(&start, &end).to_degrees(), atd);
+        assert_relative_eq!(xtd.abs(), p.haversine_distance(&foot), epsilon = 1.0e-3);
+        assert_relative_eq!(start.haversine_distance(&foot) + foot.haversine_distance(&end),
+                            start.haversine_distance(&end),
+                            epsilon = 1.0e-3);
+    }
+    #[test]
+    fn on_track_test() {
+        let (start, end) = (Point::new(0.0_f64, 0.0), Point::new(0.0, 10.0));
+        let p = Point::new(0.0, 4.0);
+        assert_relative_eq!(p.cross_track_distance(&start, &end), 0.0, epsilon = 1.0e-6);
+        assert_relative_eq!(p.along_track_distance(&start, &end),
+                            start.haversine_distance(&p),
+                            epsilon = 1.0e-6);
+    }
+    #[test]
+    fn behind_start_test() {
+        let (start, end) = (Point::new(0.0_f64, 0.0), Point::new(10.0, 0.0));
+        let p = Point::new(-2.0, 0.001);
+        assert!(p.along_track_distance(&start, &end) < 0.);
+    }
+}
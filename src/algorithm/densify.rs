@@ -0,0 +1,214 @@
+use types::{CoordFloat, Point, LineString, MultiLineString, Polygon, MultiPolygon};
+use algorithm::distance::Distance;
+
+/// The most pieces that densifying splits a single segment into. A segment which would need
+/// more, for a limit that is tiny next to its length, is left as it is rather than filling
+/// memory with points.
+pub const MAX_PIECES_PER_SEGMENT: usize = 1 << 20;
+
+// densify a slice of points, splitting each segment into the given number of equal pieces
+fn densify_points<T, F>(points: &[Point<T>], pieces: F) -> Vec<Point<T>>
+    where T: CoordFloat,
+          F: Fn(&Point<T>, &Point<T>) -> T
+{
+    let mut densified = Vec::with_capacity(points.len());
+    for segment in points.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        densified.push(start);
+        let pieces = pieces(&start, &end);
+        // counting in integers, so that the loop ends however many pieces there are; a
+        // segment that can't be split into a whole number of pieces, or would need too
+        // many, is left as it is
+        let count = pieces.to_usize().unwrap_or(0);
+        if count > MAX_PIECES_PER_SEGMENT {
+            continue;
+        }
+        for i in 1..count {
+            let t = T::from(i).unwrap() / pieces;
+            densified.push(Point::new(start.x() + t * (end.x() - start.x()),
+                                      start.y() + t * (end.y() - start.y())));
+        }
+    }
+    if let Some(&last) = points.last() {
+        densified.push(last);
+    }
+    densified
+}
+
+pub trait Densify<T> {
+    /// Returns a geometry with points added along its segments, so that no segment
+    /// is longer than `max_distance`. The added points are evenly spaced, and the
+    /// original points are all kept.
+    ///
+    /// If `max_distance` isn't positive and finite, the geometry is returned unchanged.
+    /// Segments which would need more than `MAX_PIECES_PER_SEGMENT` pieces are kept whole.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::densify::Densify;
+    ///
+    /// let ls = LineString(vec![Point::new(0.0, 0.0), Point::new(3.0, 0.0)]);
+    /// let densified = ls.densify(1.0);
+    /// let correct = LineString(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0),
+    ///                               Point::new(2.0, 0.0), Point::new(3.0, 0.0)]);
+    /// assert_eq!(densified, correct);
+    /// ```
+    fn densify(&self, max_distance: T) -> Self where T: CoordFloat;
+
+    /// Returns a geometry with `points_per_segment` evenly spaced points added inside each
+    /// of its segments, whatever their length. The original points are all kept.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::densify::Densify;
+    ///
+    /// let ls = LineString(vec![Point::new(0.0, 0.0), Point::new(3.0, 0.0)]);
+    /// let densified = ls.densify_by_count(2);
+    /// let correct = LineString(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0),
+    ///                               Point::new(2.0, 0.0), Point::new(3.0, 0.0)]);
+    /// assert_eq!(densified, correct);
+    /// ```
+    fn densify_by_count(&self, points_per_segment: usize) -> Self where T: CoordFloat;
+}
+
+impl<T> Densify<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn densify(&self, max_distance: T) -> Self {
+        if !(max_distance > T::zero() && max_distance.is_finite()) {
+            return self.clone();
+        }
+        LineString(densify_points(&self.0, |start, end| (start.distance(end) / max_distance).ceil()))
+    }
+
+    fn densify_by_count(&self, points_per_segment: usize) -> Self {
+        let pieces = T::from(points_per_segment + 1).unwrap();
+        LineString(densify_points(&self.0, |_, _| pieces))
+    }
+}
+
+impl<T> Densify<T> for MultiLineString<T>
+    where T: CoordFloat
+{
+    fn densify(&self, max_distance: T) -> Self {
+        MultiLineString(self.0.iter().map(|ls| ls.densify(max_distance)).collect())
+    }
+
+    fn densify_by_count(&self, points_per_segment: usize) -> Self {
+        MultiLineString(self.0.iter().map(|ls| ls.densify_by_count(points_per_segment)).collect())
+    }
+}
+
+impl<T> Densify<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn densify(&self, max_distance: T) -> Self {
+        Polygon::new(self.exterior.densify(max_distance),
+                     self.interiors.iter().map(|ring| ring.densify(max_distance)).collect())
+    }
+
+    fn densify_by_count(&self, points_per_segment: usize) -> Self {
+        Polygon::new(self.exterior.densify_by_count(points_per_segment),
+                     self.interiors.iter().map(|ring| ring.densify_by_count(points_per_segment)).collect())
+    }
+}
+
+impl<T> Densify<T> for MultiPolygon<T>
+    where T: CoordFloat
+{
+    fn densify(&self, max_distance: T) -> Self {
+        MultiPolygon(self.0.iter().map(|poly| poly.densify(max_distance)).collect())
+    }
+
+    fn densify_by_count(&self, points_per_segment: usize) -> Self {
+        MultiPolygon(self.0.iter().map(|poly| poly.densify_by_count(points_per_segment)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, MultiLineString, Polygon, MultiPolygon};
+    use super::*;
+
+    #[test]
+    fn densify_linestring_test() {
+        let ls = LineString(vec![Point::new(0.0, 0.0), Point::new(0.0, 3.0), Point::new(0.5, 3.0)]);
+        let densified = ls.densify(1.2);
+        assert_eq!(densified.0,
+                   vec![Point::new(0.0, 0.0),
+                        Point::new(0.0, 1.0),
+                        Point::new(0.0, 2.0),
+                        Point::new(0.0, 3.0),
+                        Point::new(0.5, 3.0)]);
+    }
+    #[test]
+    fn densify_empty_linestring_test() {
+        let ls = LineString::<f64>(vec![]);
+        assert!(ls.densify(1.0).0.is_empty());
+    }
+    #[test]
+    fn densify_multilinestring_test() {
+        let ls1 = LineString(vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0)]);
+        let ls2 = LineString(vec![Point::new(0.0, 1.0), Point::new(2.0, 1.0), Point::new(2.0, 1.5)]);
+        let densified = MultiLineString(vec![ls1, ls2]).densify(1.0);
+        assert_eq!(densified.0[0].0.len(), 5);
+        assert_eq!(densified.0[1].0.len(), 4);
+    }
+    #[test]
+    fn densify_multipolygon_test() {
+        let ring = |size: f64| {
+            LineString(vec![Point::new(0.0, 0.0),
+                            Point::new(size, 0.0),
+                            Point::new(size, size),
+                            Point::new(0.0, size),
+                            Point::new(0.0, 0.0)])
+        };
+        let mp = MultiPolygon(vec![Polygon::new(ring(2.0), vec![]), Polygon::new(ring(0.5), vec![])]);
+        let densified = mp.densify(1.0);
+        assert_eq!(densified.0[0].exterior.0.len(), 9);
+        assert_eq!(densified.0[0].exterior.0.first(), densified.0[0].exterior.0.last());
+        assert_eq!(densified.0[1], mp.0[1]);
+    }
+    #[test]
+    fn densify_by_count_linestring_test() {
+        let ls = LineString(vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 2.0)]);
+        let densified = ls.densify_by_count(3);
+        assert_eq!(densified.0,
+                   vec![Point::new(0.0, 0.0),
+                        Point::new(1.0, 0.0),
+                        Point::new(2.0, 0.0),
+                        Point::new(3.0, 0.0),
+                        Point::new(4.0, 0.0),
+                        Point::new(4.0, 0.5),
+                        Point::new(4.0, 1.0),
+                        Point::new(4.0, 1.5),
+                        Point::new(4.0, 2.0)]);
+        assert_eq!(ls.densify_by_count(0), ls);
+    }
+    #[test]
+    fn densify_by_count_polygon_test() {
+        let ring = LineString(vec![Point::new(0.0, 0.0),
+                                   Point::new(1.0, 0.0),
+                                   Point::new(1.0, 1.0),
+                                   Point::new(0.0, 0.0)]);
+        let densified = Polygon::new(ring, vec![]).densify_by_count(1);
+        assert_eq!(densified.exterior.0.len(), 7);
+        assert_eq!(densified.exterior.0[5], Point::new(0.5, 0.5));
+    }
+    #[test]
+    fn densify_invalid_distance_test() {
+        let ls = LineString(vec![Point::new(0.0, 0.0), Point::new(3.0, 0.0), Point::new(3.0, 1.0)]);
+        assert_eq!(ls.densify(0.0), ls);
+        assert_eq!(ls.densify(-1.0), ls);
+        assert_eq!(ls.densify(f64::NAN), ls);
+        assert_eq!(ls.densify(f64::INFINITY), ls);
+        // a segment too long to be split into a countable number of pieces
+        let huge = LineString(vec![Point::new(0.0, 0.0), Point::new(1e300, 0.0)]);
+        assert_eq!(huge.densify(1e-300), huge);
+        // a limit so small that the segment would need more than the most pieces allowed
+        assert_eq!(ls.densify(1e-9), ls);
+        let segment = LineString(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0)]);
+        let most = segment.densify(1.0 / MAX_PIECES_PER_SEGMENT as f64);
+        assert_eq!(most.0.len(), MAX_PIECES_PER_SEGMENT + 1);
+    }
+}
@@ -0,0 +1,83 @@
+use types::{CoordFloat, Point, MultiPoint, Bbox};
+use algorithm::distance::Distance;
+
+/// Estimates the density of `points` at the centers of an `nx` by `ny` grid of cells covering
+/// `bbox`, using a Gaussian kernel with standard deviation `bandwidth`.
+///
+/// The result is indexed by row, then column: `grid[j][i]` is the density at the center of the
+/// `i`th cell from the left (`xmin`) in the `j`th row from the bottom (`ymin`). Each point
+/// contributes a kernel which integrates to one, so the densities multiplied by the cell area
+/// sum to roughly the number of points lying well inside the bbox.
+///
+/// ```
+/// use geo::{Point, MultiPoint, Bbox};
+/// use geo::algorithm::density::kernel_density;
+///
+/// let points = MultiPoint(vec![Point::new(0.5, 0.5), Point::new(3.5, 2.5)]);
+/// let bbox = Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 3. };
+/// let grid = kernel_density(&points, &bbox, 4, 3, 0.5);
+///
+/// assert_eq!(grid.len(), 3);
+/// assert_eq!(grid[0].len(), 4);
+/// assert!(grid[0][0] > grid[1][1]);
+/// assert!(grid[2][3] > grid[1][2]);
+/// ```
+pub fn kernel_density<T: CoordFloat>(points: &MultiPoint<T>, bbox: &Bbox<T>, nx: usize, ny: usize, bandwidth: T) -> Vec<Vec<T>> {
+    let two = T::one() + T::one();
+    let pi = T::from(::std::f64::consts::PI).unwrap();
+    let cell_width = (bbox.xmax - bbox.xmin) / T::from(nx).unwrap();
+    let cell_height = (bbox.ymax - bbox.ymin) / T::from(ny).unwrap();
+    let variance = bandwidth * bandwidth;
+    let normalization = T::one() / (two * pi * variance);
+    (0..ny)
+        .map(|j| {
+            let y = bbox.ymin + cell_height * (T::from(j).unwrap() + T::from(0.5).unwrap());
+            (0..nx)
+                .map(|i| {
+                    let x = bbox.xmin + cell_width * (T::from(i).unwrap() + T::from(0.5).unwrap());
+                    let center = Point::new(x, y);
+                    points.0.iter().fold(T::zero(), |density, p| {
+                        let distance = center.distance(p);
+                        density + normalization * (-(distance * distance) / (two * variance)).exp()
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, MultiPoint, Bbox};
+    use super::*;
+
+    #[test]
+    fn single_point_test() {
+        let points = MultiPoint(vec![Point::new(5.5, 3.5)]);
+        let bbox = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. };
+        let grid = kernel_density(&points, &bbox, 10, 10, 1.);
+        assert_eq!(grid.len(), 10);
+        assert!(grid.iter().all(|row| row.len() == 10));
+
+        // the peak is in the cell containing the point
+        let peak = grid[3][5];
+        assert!(grid.iter().flat_map(|row| row.iter()).all(|&d| d <= peak));
+        // and decays outward in every direction
+        for (i, &d) in grid[3].iter().enumerate().skip(6) {
+            assert!(d < grid[3][i - 1]);
+        }
+        for (i, &d) in grid[3].iter().enumerate().take(5) {
+            assert!(d < grid[3][i + 1]);
+        }
+        assert!(grid[0][5] < grid[1][5] && grid[1][5] < grid[2][5] && grid[2][5] < peak);
+        // each cell has an area of one, so the densities sum to about one point
+        let total: f64 = grid.iter().flat_map(|row| row.iter()).sum();
+        assert_relative_eq!(total, 1., epsilon = 1e-3);
+    }
+    #[test]
+    fn no_points_test() {
+        let bbox = Bbox { xmin: 0., xmax: 1., ymin: 0., ymax: 1. };
+        let grid = kernel_density(&MultiPoint(vec![]), &bbox, 2, 3, 1.);
+        assert_eq!(grid, vec![vec![0., 0.]; 3]);
+    }
+}
@@ -1,8 +1,9 @@
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
-use num_traits::{Float, ToPrimitive};
-use types::{Point, Line, MultiPoint, LineString, MultiLineString, Polygon, MultiPolygon};
+use types::{CoordFloat, Point, Line, MultiPoint, LineString, MultiLineString, Polygon, MultiPolygon, Bbox};
 use algorithm::contains::Contains;
+use algorithm::intersects::Intersects;
+use algorithm::boundingbox::BoundingBox;
 use num_traits::pow::pow;
 
 /// Returns the distance between two geometries.
@@ -78,12 +79,12 @@ pub trait Distance<T, Rhs = Self> {
 // falls on the line past one end or the other of the segment. In that case the
 // distance to the segment will be the distance to the nearer end
 fn line_segment_distance<T>(point: &Point<T>, start: &Point<T>, end: &Point<T>) -> T
-    where T: Float + ToPrimitive
+    where T: CoordFloat
 {
     let dist_squared = pow(start.distance(end), 2);
     // Implies that start == end
     if dist_squared.is_zero() {
-        return pow(point.distance(start), 2);
+        return point.distance(start);
     }
     // Consider the line extending the segment, parameterized as start + t (end - start)
     // We find the projection of the point onto the line
@@ -97,31 +98,31 @@ fn line_segment_distance<T>(point: &Point<T>, start: &Point<T>, end: &Point<T>)
 
 #[derive(PartialEq, Debug)]
 struct Mindist<T>
-    where T: Float
+    where T: CoordFloat
 {
     distance: T,
 }
 
 // These impls give us a min-heap when used with BinaryHeap
 impl<T> Ord for Mindist<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn cmp(&self, other: &Mindist<T>) -> Ordering {
         other.distance.partial_cmp(&self.distance).unwrap()
     }
 }
 impl<T> PartialOrd for Mindist<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn partial_cmp(&self, other: &Mindist<T>) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T> Eq for Mindist<T> where T: Float {}
+impl<T> Eq for Mindist<T> where T: CoordFloat {}
 
 impl<T> Distance<T, Point<T>> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance between two Points
     fn distance(&self, p: &Point<T>) -> T {
@@ -131,7 +132,7 @@ impl<T> Distance<T, Point<T>> for Point<T>
 }
 
 impl<T> Distance<T, MultiPoint<T>> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a Point to a MultiPoint
     fn distance(&self, points: &MultiPoint<T>) -> T {
@@ -145,7 +146,7 @@ impl<T> Distance<T, MultiPoint<T>> for Point<T>
 }
 
 impl<T> Distance<T, Point<T>> for MultiPoint<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a MultiPoint to a Point
     fn distance(&self, point: &Point<T>) -> T {
@@ -154,7 +155,7 @@ impl<T> Distance<T, Point<T>> for MultiPoint<T>
 }
 
 impl<T> Distance<T, Polygon<T>> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a Point to a Polygon
     fn distance(&self, polygon: &Polygon<T>) -> T {
@@ -181,7 +182,7 @@ impl<T> Distance<T, Polygon<T>> for Point<T>
 }
 
 impl<T> Distance<T, Point<T>> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a Polygon to a Point
     fn distance(&self, point: &Point<T>) -> T {
@@ -190,7 +191,7 @@ impl<T> Distance<T, Point<T>> for Polygon<T>
 }
 
 impl<T> Distance<T, MultiPolygon<T>> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a Point to a MultiPolygon
     fn distance(&self, mpolygon: &MultiPolygon<T>) -> T {
@@ -203,7 +204,7 @@ impl<T> Distance<T, MultiPolygon<T>> for Point<T>
 }
 
 impl<T> Distance<T, Point<T>> for MultiPolygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a MultiPolygon to a Point
     fn distance(&self, point: &Point<T>) -> T {
@@ -212,7 +213,7 @@ impl<T> Distance<T, Point<T>> for MultiPolygon<T>
 }
 
 impl<T> Distance<T, MultiLineString<T>> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a Point to a MultiLineString
     fn distance(&self, mls: &MultiLineString<T>) -> T {
@@ -225,7 +226,7 @@ impl<T> Distance<T, MultiLineString<T>> for Point<T>
 }
 
 impl<T> Distance<T, Point<T>> for MultiLineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a MultiLineString to a Point
     fn distance(&self, point: &Point<T>) -> T {
@@ -234,7 +235,7 @@ impl<T> Distance<T, Point<T>> for MultiLineString<T>
 }
 
 impl<T> Distance<T, LineString<T>> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a Point to a LineString
     fn distance(&self, linestring: &LineString<T>) -> T {
@@ -254,8 +255,37 @@ impl<T> Distance<T, LineString<T>> for Point<T>
     }
 }
 
+impl<T> Point<T>
+    where T: CoordFloat
+{
+    /// Minimum distance from a Point to a LineString, with the index of the segment nearest
+    /// the Point, where segment `i` runs from point `i` to point `i + 1`.
+    ///
+    /// If several segments are equally near, the first of them is used. Returns `None` if
+    /// the LineString has fewer than two points.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let ls = LineString(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(4., 4.)]);
+    ///
+    /// assert_eq!(Point::new(6., 1.).euclidean_distance_and_segment(&ls), Some((2., 1)));
+    /// ```
+    pub fn euclidean_distance_and_segment(&self, linestring: &LineString<T>) -> Option<(T, usize)> {
+        let mut nearest: Option<(T, usize)> = None;
+        for (i, chunk) in linestring.0.windows(2).enumerate() {
+            let dist = line_segment_distance(self, &chunk[0], &chunk[1]);
+            nearest = match nearest {
+                Some((best, _)) if best <= dist => nearest,
+                _ => Some((dist, i)),
+            };
+        }
+        nearest
+    }
+}
+
 impl<T> Distance<T, Point<T>> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a LineString to a Point
     fn distance(&self, point: &Point<T>) -> T {
@@ -264,7 +294,7 @@ impl<T> Distance<T, Point<T>> for LineString<T>
 }
 
 impl<T> Distance<T, Point<T>> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a Line to a Point
     fn distance(&self, point: &Point<T>) -> T {
@@ -272,7 +302,7 @@ impl<T> Distance<T, Point<T>> for Line<T>
     }
 }
 impl<T> Distance<T, Line<T>> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Minimum distance from a Line to a Point
     fn distance(&self, line: &Line<T>) -> T {
@@ -280,9 +310,145 @@ impl<T> Distance<T, Line<T>> for Point<T>
     }
 }
 
+impl<T> Distance<T, Bbox<T>> for Point<T>
+    where T: CoordFloat
+{
+    /// Minimum distance from a Point to a Bbox, which is `0.0` if the Point lies inside it
+    fn distance(&self, bbox: &Bbox<T>) -> T {
+        let dx = (bbox.xmin - self.x()).max(self.x() - bbox.xmax).max(T::zero());
+        let dy = (bbox.ymin - self.y()).max(self.y() - bbox.ymax).max(T::zero());
+        dx.hypot(dy)
+    }
+}
+impl<T> Distance<T, Point<T>> for Bbox<T>
+    where T: CoordFloat
+{
+    /// Minimum distance from a Bbox to a Point
+    fn distance(&self, point: &Point<T>) -> T {
+        point.distance(self)
+    }
+}
+
+// Minimum distance between the vertices of each ring and the edges of the other.
+// Only valid for rings whose edges don't cross.
+fn ring_distance<T>(a: &LineString<T>, b: &LineString<T>) -> T
+    where T: CoordFloat
+{
+    let mut dist_queue: BinaryHeap<Mindist<T>> = BinaryHeap::new();
+    for p in &a.0 {
+        dist_queue.push(Mindist { distance: p.distance(b) });
+    }
+    for p in &b.0 {
+        dist_queue.push(Mindist { distance: p.distance(a) });
+    }
+    dist_queue.pop().unwrap().distance
+}
+
+impl<T> Distance<T, Polygon<T>> for Polygon<T>
+    where T: CoordFloat
+{
+    /// Minimum distance between two Polygons
+    ///
+    /// The distance is `0.0` if the Polygons intersect, or one contains the other.
+    /// Otherwise, it is the minimum distance between their rings.
+    fn distance(&self, polygon: &Polygon<T>) -> T {
+        if self.intersects(polygon) {
+            return T::zero();
+        }
+        let mut dist_queue: BinaryHeap<Mindist<T>> = BinaryHeap::new();
+        for a in ::std::iter::once(&self.exterior).chain(self.interiors.iter()) {
+            for b in ::std::iter::once(&polygon.exterior).chain(polygon.interiors.iter()) {
+                if !a.0.is_empty() && !b.0.is_empty() {
+                    dist_queue.push(Mindist { distance: ring_distance(a, b) });
+                }
+            }
+        }
+        dist_queue.pop().map_or(T::zero(), |d| d.distance)
+    }
+}
+
+impl<T> Distance<T, Polygon<T>> for LineString<T>
+    where T: CoordFloat
+{
+    /// Minimum distance from a LineString to a Polygon
+    ///
+    /// The distance is `0.0` if the LineString intersects the Polygon, or lies inside it.
+    /// Otherwise, it is the minimum distance between the LineString and the Polygon's rings.
+    fn distance(&self, polygon: &Polygon<T>) -> T {
+        if self.0.is_empty() || polygon.intersects(self) {
+            return T::zero();
+        }
+        let mut dist_queue: BinaryHeap<Mindist<T>> = BinaryHeap::new();
+        for ring in ::std::iter::once(&polygon.exterior).chain(polygon.interiors.iter()) {
+            if !ring.0.is_empty() {
+                dist_queue.push(Mindist { distance: ring_distance(self, ring) });
+            }
+        }
+        dist_queue.pop().map_or(T::zero(), |d| d.distance)
+    }
+}
+
+impl<T> Distance<T, LineString<T>> for Polygon<T>
+    where T: CoordFloat
+{
+    /// Minimum distance from a Polygon to a LineString
+    fn distance(&self, linestring: &LineString<T>) -> T {
+        linestring.distance(self)
+    }
+}
+
+// Minimum distance between two Bboxes, which is `0.0` if they overlap
+fn bbox_distance<T>(a: &Bbox<T>, b: &Bbox<T>) -> T
+    where T: CoordFloat
+{
+    let dx = (a.xmin - b.xmax).max(b.xmin - a.xmax).max(T::zero());
+    let dy = (a.ymin - b.ymax).max(b.ymin - a.ymax).max(T::zero());
+    dx.hypot(dy)
+}
+
+impl<T> Distance<T, MultiPolygon<T>> for MultiPolygon<T>
+    where T: CoordFloat
+{
+    /// Minimum distance between two MultiPolygons
+    ///
+    /// Pairs of Polygons are measured in order of the distance between their bounding
+    /// boxes, which is a lower bound on their distance, so pairs further apart than the
+    /// nearest pair found so far are skipped. The distance is `0.0` as soon as any pair
+    /// intersects.
+    fn distance(&self, mpolygon: &MultiPolygon<T>) -> T {
+        let bboxes: Vec<_> = mpolygon.0.iter().map(|polygon| polygon.bbox()).collect();
+        let mut pairs = Vec::new();
+        for a in &self.0 {
+            let a_bbox = match a.bbox() {
+                Some(bbox) => bbox,
+                None => continue,
+            };
+            for (b, b_bbox) in mpolygon.0.iter().zip(bboxes.iter()) {
+                if let Some(ref b_bbox) = *b_bbox {
+                    pairs.push((bbox_distance(&a_bbox, b_bbox), a, b));
+                }
+            }
+        }
+        pairs.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(Ordering::Equal));
+        let mut nearest: Option<T> = None;
+        for (lower_bound, a, b) in pairs {
+            match nearest {
+                Some(nearest) if lower_bound >= nearest => break,
+                _ => (),
+            }
+            let dist = a.distance(b);
+            if dist.is_zero() {
+                return dist;
+            }
+            nearest = Some(nearest.map_or(dist, |nearest| nearest.min(dist)));
+        }
+        nearest.unwrap_or_else(T::zero)
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use types::{Point, Line, MultiPoint, LineString, MultiLineString, Polygon, MultiPolygon};
+    use types::{Point, Line, MultiPoint, LineString, MultiLineString, Polygon, MultiPolygon, Bbox};
     use algorithm::distance::{Distance, line_segment_distance};
 
     #[test]
@@ -309,6 +475,15 @@ mod test {
         assert_relative_eq!(zero_dist, 0.0);
     }
     #[test]
+    fn zero_length_segment_distance_test() {
+        let o = Point::new(0., 0.);
+        assert_relative_eq!(line_segment_distance(&Point::new(3., 4.), &o, &o), 5.);
+        assert_relative_eq!(line_segment_distance(&Point::new(0., 0.5), &o, &o), 0.5);
+        // a repeated point, which is nearer than the rest of the line
+        let repeated = LineString(vec![o, o, Point::new(0., 5.)]);
+        assert_relative_eq!(Point::new(0., -0.5).distance(&repeated), 0.5);
+    }
+    #[test]
     // Point to Polygon, outside point
     fn point_polygon_distance_outside_test() {
         // an octagon
@@ -383,6 +558,21 @@ mod test {
         assert_relative_eq!(dist, 0.41036467732879767);
     }
     #[test]
+    // Point to Polygon with two interior rings
+    fn point_polygon_two_holes_test() {
+        let p = |x, y| Point::new(x, y);
+        let outer = LineString(vec![p(0., 0.), p(10., 0.), p(10., 10.), p(0., 10.), p(0., 0.)]);
+        let inner0 = LineString(vec![p(1., 1.), p(2., 1.), p(2., 2.), p(1., 2.), p(1., 1.)]);
+        let inner1 = LineString(vec![p(5., 5.), p(6., 5.), p(6., 6.), p(5., 6.), p(5., 5.)]);
+        let poly = Polygon::new(outer, vec![inner0, inner1]);
+        // inside inner0, nearest to its bottom edge
+        assert_relative_eq!(p(1.5, 1.2).distance(&poly), 0.2);
+        // in the filled region between the holes
+        assert_relative_eq!(p(3.5, 3.5).distance(&poly), 0.0);
+        // on the boundary of inner1
+        assert_relative_eq!(p(5.5, 5.).distance(&poly), 0.0);
+    }
+    #[test]
     fn point_distance_multipolygon_test() {
         let ls1 = LineString(vec![Point::new(0.0, 0.0),
                                   Point::new(1.0, 10.0),
@@ -489,4 +679,132 @@ mod test {
         assert_eq!(line0.distance(&p2), 1.);
         assert_eq!(p2.distance(&line0), 1.);
     }
+    #[test]
+    fn polygon_polygon_disjoint_test() {
+        let square = |x: f64, y: f64| {
+            Polygon::new(LineString(vec![Point::new(x, y),
+                                         Point::new(x + 1., y),
+                                         Point::new(x + 1., y + 1.),
+                                         Point::new(x, y + 1.),
+                                         Point::new(x, y)]),
+                         vec![])
+        };
+        assert_relative_eq!(square(0., 0.).distance(&square(3., 0.)), 2.0);
+        assert_relative_eq!(square(3., 0.).distance(&square(0., 0.)), 2.0);
+        // offset diagonally, so that the nearest points are corners
+        assert_relative_eq!(square(0., 0.).distance(&square(4., 5.)), 5.0);
+    }
+    #[test]
+    fn polygon_polygon_overlapping_test() {
+        let square = |x: f64| {
+            Polygon::new(LineString(vec![Point::new(x, 0.),
+                                         Point::new(x + 2., 0.),
+                                         Point::new(x + 2., 2.),
+                                         Point::new(x, 2.),
+                                         Point::new(x, 0.)]),
+                         vec![])
+        };
+        assert_relative_eq!(square(0.).distance(&square(1.)), 0.0);
+    }
+    #[test]
+    fn polygon_polygon_in_hole_test() {
+        let ring = |min: f64, max: f64| {
+            LineString(vec![Point::new(min, min),
+                            Point::new(max, min),
+                            Point::new(max, max),
+                            Point::new(min, max),
+                            Point::new(min, min)])
+        };
+        let outer = Polygon::new(ring(0., 10.), vec![ring(2., 8.)]);
+        let inner = Polygon::new(ring(3., 7.), vec![]);
+        assert_relative_eq!(outer.distance(&inner), 1.0);
+        assert_relative_eq!(inner.distance(&outer), 1.0);
+        // contained in the filled part of the polygon
+        let filled = Polygon::new(ring(0.5, 1.5), vec![]);
+        assert_relative_eq!(outer.distance(&filled), 0.0);
+    }
+    #[test]
+    fn multipolygon_multipolygon_test() {
+        let square = |x: f64, y: f64| {
+            Polygon::new(LineString(vec![Point::new(x, y),
+                                         Point::new(x + 1., y),
+                                         Point::new(x + 1., y + 1.),
+                                         Point::new(x, y + 1.),
+                                         Point::new(x, y)]),
+                         vec![])
+        };
+        // a row of squares two apart, and a column of squares ten to the right of it
+        let row = MultiPolygon((0..50).map(|i| square(i as f64 * 2., 0.)).collect());
+        let column = MultiPolygon((0..50).map(|i| square(108., i as f64 * 2. - 40.)).collect());
+        assert_relative_eq!(row.distance(&column), 9.0);
+        assert_relative_eq!(column.distance(&row), 9.0);
+        // nearest at the corners
+        let far = MultiPolygon(vec![square(200., 10.), square(101., 3.)]);
+        assert_relative_eq!(row.distance(&far), 2.0f64.hypot(2.));
+
+        // one of the column's squares overlaps the end of the row
+        let crossing = MultiPolygon((0..50).map(|i| square(98.5, i as f64 * 2. - 40.)).collect());
+        assert_relative_eq!(row.distance(&crossing), 0.0);
+        assert_relative_eq!(crossing.distance(&row), 0.0);
+
+        let empty = MultiPolygon(vec![]);
+        assert_relative_eq!(row.distance(&empty), 0.0);
+    }
+    #[test]
+    fn linestring_polygon_test() {
+        let ring = |min: f64, max: f64| {
+            LineString(vec![Point::new(min, min),
+                            Point::new(max, min),
+                            Point::new(max, max),
+                            Point::new(min, max),
+                            Point::new(min, min)])
+        };
+        let poly = Polygon::new(ring(0., 10.), vec![ring(2., 8.)]);
+        // passing through the polygon
+        let through = LineString(vec![Point::new(-5., 1.), Point::new(15., 1.)]);
+        assert_relative_eq!(through.distance(&poly), 0.0);
+        // entirely within the filled part
+        let inside = LineString(vec![Point::new(0.5, 0.5), Point::new(9.5, 0.5)]);
+        assert_relative_eq!(inside.distance(&poly), 0.0);
+        // outside, parallel to the right edge
+        let outside = LineString(vec![Point::new(12., -3.), Point::new(12., 13.)]);
+        assert_relative_eq!(outside.distance(&poly), 2.0);
+        assert_relative_eq!(poly.distance(&outside), 2.0);
+        // inside the hole, nearest to its bottom edge
+        let in_hole = LineString(vec![Point::new(4., 2.5), Point::new(6., 3.), Point::new(5., 6.)]);
+        assert_relative_eq!(in_hole.distance(&poly), 0.5);
+    }
+    #[test]
+    fn distance_and_segment_test() {
+        let ls = LineString(vec![Point::new(0., 0.),
+                                 Point::new(2., 0.),
+                                 Point::new(2., 2.),
+                                 Point::new(5., 2.),
+                                 Point::new(5., 6.)]);
+        let (dist, segment) = Point::new(3.5, 3.).euclidean_distance_and_segment(&ls).unwrap();
+        assert_relative_eq!(dist, 1.);
+        assert_eq!(segment, 2);
+        // on the shared vertex of the first two segments
+        assert_eq!(Point::new(2., 0.).euclidean_distance_and_segment(&ls), Some((0., 0)));
+        // a repeated point makes a segment of zero length
+        let repeated = LineString(vec![Point::new(0., 0.), Point::new(0., 0.), Point::new(0., 5.)]);
+        assert_eq!(Point::new(-3., 4.).euclidean_distance_and_segment(&repeated), Some((3., 1)));
+        assert_eq!(Point::new(3., 4.).euclidean_distance_and_segment(&LineString(vec![])), None);
+        assert_eq!(Point::new(3., 4.).euclidean_distance_and_segment(&LineString(vec![Point::new(0., 0.)])),
+                   None);
+    }
+    #[test]
+    fn point_bbox_test() {
+        let bbox = Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 2. };
+        // inside, and on the boundary
+        assert_eq!(Point::new(1., 1.).distance(&bbox), 0.);
+        assert_eq!(Point::new(4., 1.).distance(&bbox), 0.);
+        // directly to the left
+        assert_eq!(Point::new(-3., 1.5).distance(&bbox), 3.);
+        assert_eq!(bbox.distance(&Point::new(-3., 1.5)), 3.);
+        // directly above
+        assert_eq!(Point::new(2., 2.5).distance(&bbox), 0.5);
+        // diagonally off the top right corner
+        assert_relative_eq!(Point::new(7., 6.).distance(&bbox), 5.);
+    }
 }
@@ -0,0 +1,130 @@
+use types::{CoordFloat, Point, LineString, Polygon};
+
+// the distinct vertices of a ring, without the closing point
+fn ring_vertices<T>(ring: &LineString<T>) -> &[Point<T>]
+    where T: CoordFloat
+{
+    let points = &ring.0[..];
+    if points.len() > 1 && points.first() == points.last() {
+        &points[..points.len() - 1]
+    } else {
+        points
+    }
+}
+
+fn points_equal<T>(a: &Point<T>, b: &Point<T>, epsilon: T) -> bool
+    where T: CoordFloat
+{
+    (a.x() - b.x()).abs() <= epsilon && (a.y() - b.y()).abs() <= epsilon
+}
+
+// compare two rings, allowing for a different starting vertex and winding direction
+fn rings_equal<T>(a: &LineString<T>, b: &LineString<T>, epsilon: T) -> bool
+    where T: CoordFloat
+{
+    let (a, b) = (ring_vertices(a), ring_vertices(b));
+    if a.len() != b.len() {
+        return false;
+    }
+    if a.is_empty() {
+        return true;
+    }
+    let n = a.len();
+    (0..n).any(|offset| {
+        (0..n).all(|i| points_equal(&a[i], &b[(offset + i) % n], epsilon)) ||
+        (0..n).all(|i| points_equal(&a[i], &b[(offset + n - i) % n], epsilon))
+    })
+}
+
+pub trait GeometricallyEqual<T, Rhs = Self> {
+    /// Checks if two geometries describe the same shape, to within `epsilon` in each coordinate.
+    ///
+    /// Rings are compared regardless of which vertex they start from and of their winding
+    /// direction, and the interior rings of a `Polygon` may be listed in any order.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    /// use geo::algorithm::equality::GeometricallyEqual;
+    ///
+    /// let p = |x, y| Point::new(x, y);
+    /// let ccw = Polygon::new(LineString(vec![p(0., 0.), p(1., 0.), p(1., 1.), p(0., 1.), p(0., 0.)]), vec![]);
+    /// let cw = Polygon::new(LineString(vec![p(1., 1.), p(1., 0.), p(0., 0.), p(0., 1.), p(1., 1.)]), vec![]);
+    ///
+    /// assert!(ccw != cw);
+    /// assert!(ccw.geometrically_equal(&cw, 1e-9));
+    /// ```
+    fn geometrically_equal(&self, other: &Rhs, epsilon: T) -> bool;
+}
+
+impl<T> GeometricallyEqual<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn geometrically_equal(&self, other: &Polygon<T>, epsilon: T) -> bool {
+        if self.interiors.len() != other.interiors.len() ||
+           !rings_equal(&self.exterior, &other.exterior, epsilon) {
+            return false;
+        }
+        // match each interior ring to a distinct ring of the other polygon
+        let mut unmatched: Vec<&LineString<T>> = other.interiors.iter().collect();
+        for ring in &self.interiors {
+            match unmatched.iter().position(|other| rings_equal(ring, other, epsilon)) {
+                Some(idx) => {
+                    unmatched.swap_remove(idx);
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon};
+    use super::*;
+
+    #[test]
+    fn square_rotations_test() {
+        let p = |x, y| Point::new(x, y);
+        let corners = [p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.)];
+        let square = |start: usize, reverse: bool| {
+            let mut ring: Vec<Point<f64>> = (0..4).map(|i| corners[(start + i) % 4]).collect();
+            if reverse {
+                ring.reverse();
+            }
+            let first = ring[0];
+            ring.push(first);
+            Polygon::new(LineString(ring), vec![])
+        };
+        let reference = square(0, false);
+        for start in 0..4 {
+            assert!(reference.geometrically_equal(&square(start, false), 1e-9));
+            assert!(reference.geometrically_equal(&square(start, true), 1e-9));
+        }
+    }
+    #[test]
+    fn different_polygons_test() {
+        let p = |x, y| Point::new(x, y);
+        let square = Polygon::new(LineString(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.), p(0., 0.)]),
+                                  vec![]);
+        // same vertices, visited in a different order
+        let bowtie = Polygon::new(LineString(vec![p(0., 0.), p(2., 2.), p(2., 0.), p(0., 2.), p(0., 0.)]),
+                                  vec![]);
+        let triangle = Polygon::new(LineString(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 0.)]), vec![]);
+        assert!(!square.geometrically_equal(&bowtie, 1e-9));
+        assert!(!square.geometrically_equal(&triangle, 1e-9));
+    }
+    #[test]
+    fn epsilon_and_interiors_test() {
+        let p = |x, y| Point::new(x, y);
+        let exterior = LineString(vec![p(0., 0.), p(10., 0.), p(10., 10.), p(0., 10.), p(0., 0.)]);
+        let hole1 = LineString(vec![p(1., 1.), p(2., 1.), p(2., 2.), p(1., 1.)]);
+        let hole2 = LineString(vec![p(5., 5.), p(6., 5.), p(6., 6.), p(5., 5.)]);
+        let hole2_moved = LineString(vec![p(6., 5.), p(5., 5.0001), p(6., 6.), p(6., 5.)]);
+        let a = Polygon::new(exterior.clone(), vec![hole1.clone(), hole2]);
+        let b = Polygon::new(exterior.clone(), vec![hole2_moved, hole1.clone()]);
+        assert!(a.geometrically_equal(&b, 1e-3));
+        assert!(!a.geometrically_equal(&b, 1e-6));
+        assert!(!a.geometrically_equal(&Polygon::new(exterior, vec![hole1]), 1e-3));
+    }
+}
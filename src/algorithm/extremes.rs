@@ -1,5 +1,5 @@
-use num_traits::{Float, Signed};
-use types::{Point, Polygon, MultiPoint, MultiPolygon};
+use num_traits::Signed;
+use types::{CoordFloat, Point, Polygon, MultiPoint, MultiPolygon};
 use algorithm::convexhull::ConvexHull;
 use types::{Extremes, ExtremePoint};
 
@@ -14,20 +14,20 @@ use types::{Extremes, ExtremePoint};
 // Not currently used, but maybe useful in the future
 #[allow(dead_code)]
 fn up<T>(u: &Point<T>, v: &Point<T>) -> bool
-    where T: Float
+    where T: CoordFloat
 {
     u.dot(v) > T::zero()
 }
 
 fn direction_sign<T>(u: &Point<T>, vi: &Point<T>, vj: &Point<T>) -> T
-    where T: Float
+    where T: CoordFloat
 {
     u.dot(&(*vi - *vj))
 }
 
 // true if Vi is above Vj
 fn above<T>(u: &Point<T>, vi: &Point<T>, vj: &Point<T>) -> bool
-    where T: Float
+    where T: CoordFloat
 {
     direction_sign(u, vi, vj) > T::zero()
 }
@@ -36,7 +36,7 @@ fn above<T>(u: &Point<T>, vi: &Point<T>, vj: &Point<T>) -> bool
 // Not currently used, but maybe useful in the future
 #[allow(dead_code)]
 fn below<T>(u: &Point<T>, vi: &Point<T>, vj: &Point<T>) -> bool
-    where T: Float
+    where T: CoordFloat
 {
     direction_sign(u, vi, vj) < T::zero()
 }
@@ -52,10 +52,10 @@ enum ListSign {
 
 // Wrap-around previous-vertex
 impl<T> Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn previous_vertex(&self, current_vertex: &usize) -> usize
-        where T: Float
+        where T: CoordFloat
     {
         (current_vertex + (self.exterior.0.len() - 1) - 1) % (self.exterior.0.len() - 1)
     }
@@ -63,14 +63,14 @@ impl<T> Polygon<T>
 
 // positive implies a -> b -> c is counter-clockwise, negative implies clockwise
 fn cross_prod<T>(p_a: &Point<T>, p_b: &Point<T>, p_c: &Point<T>) -> T
-    where T: Float
+    where T: CoordFloat
 {
     (p_b.x() - p_a.x()) * (p_c.y() - p_a.y()) - (p_b.y() - p_a.y()) * (p_c.x() - p_a.x())
 }
 
 // wrapper for extreme-finding function
 fn find_extreme_indices<T, F>(func: F, polygon: &Polygon<T>) -> Result<Extremes, ()>
-    where T: Float + Signed,
+    where T: CoordFloat + Signed,
           F: Fn(&Point<T>, &Polygon<T>) -> Result<usize, ()>
 {
     // For each consecutive pair of edges of the polygon (each triplet of points),
@@ -119,7 +119,7 @@ fn find_extreme_indices<T, F>(func: F, polygon: &Polygon<T>) -> Result<Extremes,
 // find a convex, counter-clockwise oriented polygon's maximum vertex in a specified direction
 // u: a direction vector. We're using a point to represent this, which is a hack but works fine
 fn polymax_naive_indices<T>(u: &Point<T>, poly: &Polygon<T>) -> Result<usize, ()>
-    where T: Float
+    where T: CoordFloat
 {
     let vertices = &poly.exterior.0;
     let mut max: usize = 0;
@@ -132,7 +132,7 @@ fn polymax_naive_indices<T>(u: &Point<T>, poly: &Polygon<T>) -> Result<usize, ()
     return Ok(max);
 }
 
-pub trait ExtremeIndices<T: Float + Signed> {
+pub trait ExtremeIndices<T: CoordFloat + Signed> {
     /// Find the extreme `x` and `y` indices of a convex Polygon
     ///
     /// The polygon **must be convex and properly (ccw) oriented**.
@@ -155,7 +155,7 @@ pub trait ExtremeIndices<T: Float + Signed> {
 }
 
 impl<T> ExtremeIndices<T> for Polygon<T>
-    where T: Float + Signed
+    where T: CoordFloat + Signed
 {
     fn extreme_indices(&self) -> Result<Extremes, ()> {
         find_extreme_indices(polymax_naive_indices, self)
@@ -163,7 +163,7 @@ impl<T> ExtremeIndices<T> for Polygon<T>
 }
 
 impl<T> ExtremeIndices<T> for MultiPolygon<T>
-    where T: Float + Signed
+    where T: CoordFloat + Signed
 {
     fn extreme_indices(&self) -> Result<Extremes, ()> {
         find_extreme_indices(polymax_naive_indices, &self.convex_hull())
@@ -171,14 +171,14 @@ impl<T> ExtremeIndices<T> for MultiPolygon<T>
 }
 
 impl<T> ExtremeIndices<T> for MultiPoint<T>
-    where T: Float + Signed
+    where T: CoordFloat + Signed
 {
     fn extreme_indices(&self) -> Result<Extremes, ()> {
         find_extreme_indices(polymax_naive_indices, &self.convex_hull())
     }
 }
 
-pub trait ExtremePoints<T: Float> {
+pub trait ExtremePoints<T: CoordFloat> {
     /// Find the extreme `x` and `y` points of a Geometry
     ///
     /// This trait is available to any struct implementing both `ConvexHull` amd `ExtremeIndices`
@@ -200,7 +200,7 @@ pub trait ExtremePoints<T: Float> {
 }
 
 impl<T, G> ExtremePoints<T> for G
-    where T: Float + Signed,
+    where T: CoordFloat + Signed,
           G: ConvexHull<T> + ExtremeIndices<T>
 {
     // Any Geometry implementing `ConvexHull` and `ExtremeIndices` gets this automatically
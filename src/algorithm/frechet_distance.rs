@@ -0,0 +1,140 @@
+use types::{CoordFloat, LineString};
+use algorithm::distance::Distance;
+
+// The table of coupling distances: `table[i][j]` is the discrete Fréchet distance between
+// the first `i + 1` points of `a` and the first `j + 1` points of `b`.
+fn coupling_table<T>(a: &LineString<T>, b: &LineString<T>) -> Vec<Vec<T>>
+    where T: CoordFloat
+{
+    let (n, m) = (a.0.len(), b.0.len());
+    let mut table = vec![vec![T::zero(); m]; n];
+    for i in 0..n {
+        for j in 0..m {
+            let d = a.0[i].distance(&b.0[j]);
+            let before = match (i, j) {
+                (0, 0) => T::zero(),
+                (0, _) => table[0][j - 1],
+                (_, 0) => table[i - 1][0],
+                _ => table[i - 1][j].min(table[i - 1][j - 1]).min(table[i][j - 1]),
+            };
+            table[i][j] = before.max(d);
+        }
+    }
+    table
+}
+
+pub trait FrechetDistance<T, Rhs = Self> {
+    /// Returns the discrete Fréchet distance between two geometries.
+    ///
+    /// This is the shortest leash that lets two walkers cross the vertices of each
+    /// geometry in order, where at each step either or both walkers move on one vertex.
+    /// The distance is `0.0` if either geometry is empty.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::frechet_distance::FrechetDistance;
+    ///
+    /// let a = LineString(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(2., 0.)]);
+    /// let b = LineString(vec![Point::new(0., 1.), Point::new(2., 1.)]);
+    ///
+    /// assert_eq!(a.frechet_distance(&b), 2f64.sqrt());
+    /// ```
+    fn frechet_distance(&self, rhs: &Rhs) -> T where T: CoordFloat;
+
+    /// Returns the discrete Fréchet distance between two geometries, with an optimal
+    /// coupling: the pairs of vertex indices visited together, from `(0, 0)` to the last
+    /// vertex of each. The coupling is empty if either geometry is empty.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::frechet_distance::FrechetDistance;
+    ///
+    /// let a = LineString(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(2., 0.)]);
+    /// let b = LineString(vec![Point::new(0., 1.), Point::new(2., 1.)]);
+    /// let (distance, path) = a.frechet_distance_and_path(&b);
+    ///
+    /// assert_eq!(distance, 2f64.sqrt());
+    /// assert_eq!(path, vec![(0, 0), (1, 0), (2, 1)]);
+    /// ```
+    fn frechet_distance_and_path(&self, rhs: &Rhs) -> (T, Vec<(usize, usize)>) where T: CoordFloat;
+}
+
+impl<T> FrechetDistance<T, LineString<T>> for LineString<T>
+    where T: CoordFloat
+{
+    fn frechet_distance(&self, other: &LineString<T>) -> T {
+        if self.0.is_empty() || other.0.is_empty() {
+            return T::zero();
+        }
+        coupling_table(self, other)[self.0.len() - 1][other.0.len() - 1]
+    }
+
+    fn frechet_distance_and_path(&self, other: &LineString<T>) -> (T, Vec<(usize, usize)>) {
+        if self.0.is_empty() || other.0.is_empty() {
+            return (T::zero(), vec![]);
+        }
+        let table = coupling_table(self, other);
+        let (mut i, mut j) = (self.0.len() - 1, other.0.len() - 1);
+        let distance = table[i][j];
+        // walk back through the table, preferring diagonal steps on ties
+        let mut path = vec![(i, j)];
+        while i > 0 || j > 0 {
+            let (ni, nj) = if i == 0 {
+                (0, j - 1)
+            } else if j == 0 {
+                (i - 1, 0)
+            } else {
+                let diagonal = table[i - 1][j - 1];
+                if diagonal <= table[i - 1][j] && diagonal <= table[i][j - 1] {
+                    (i - 1, j - 1)
+                } else if table[i - 1][j] <= table[i][j - 1] {
+                    (i - 1, j)
+                } else {
+                    (i, j - 1)
+                }
+            };
+            i = ni;
+            j = nj;
+            path.push((i, j));
+        }
+        path.reverse();
+        (distance, path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use super::*;
+
+    #[test]
+    fn identical_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 3.), Point::new(4., 2.), Point::new(6., 5.)]);
+        let (distance, path) = ls.frechet_distance_and_path(&ls);
+        assert_eq!(distance, 0.);
+        assert_eq!(path, vec![(0, 0), (1, 1), (2, 2), (3, 3)]);
+    }
+    #[test]
+    fn path_test() {
+        // `b` zigzags around the start of `a` before following it
+        let a = LineString(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(8., 0.)]);
+        let b = LineString(vec![Point::new(0., 1.), Point::new(0., -1.), Point::new(1., 0.),
+                                Point::new(4., 1.), Point::new(8., 1.)]);
+        let (distance, path) = a.frechet_distance_and_path(&b);
+        assert_eq!(distance, 1.);
+        assert_eq!(distance, a.frechet_distance(&b));
+        assert_eq!(path, vec![(0, 0), (0, 1), (0, 2), (1, 3), (2, 4)]);
+        // every step moves one or both walkers on by one vertex
+        for step in path.windows(2) {
+            let (di, dj) = (step[1].0 - step[0].0, step[1].1 - step[0].1);
+            assert!(di <= 1 && dj <= 1 && di + dj >= 1);
+        }
+    }
+    #[test]
+    fn empty_test() {
+        let empty = LineString::<f64>(vec![]);
+        let ls = LineString(vec![Point::new(0., 0.)]);
+        assert_eq!(empty.frechet_distance(&ls), 0.);
+        assert_eq!(ls.frechet_distance_and_path(&empty), (0., vec![]));
+    }
+}
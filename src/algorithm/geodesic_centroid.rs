@@ -0,0 +1,77 @@
+use types::{CoordFloat, Point, MultiPoint};
+
+pub trait SphericalCentroid<T: CoordFloat> {
+    /// Returns the centroid of a geometry on the surface of a sphere, treating coordinates as
+    /// longitude/latitude in degrees.
+    ///
+    /// Each point is converted to a 3D unit vector, the vectors are averaged, and the mean is
+    /// projected back onto the sphere. Unlike averaging the coordinates directly, this is
+    /// correct across the antimeridian and near the poles. Returns `None` for an empty geometry,
+    /// or if the points balance out exactly, e.g. two antipodal points.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::{Point, MultiPoint};
+    /// use geo::algorithm::geodesic_centroid::SphericalCentroid;
+    ///
+    /// # fn main() {
+    /// let points = MultiPoint(vec![Point::new(0., 0.), Point::new(90., 0.)]);
+    /// let centroid = points.spherical_centroid().unwrap();
+    /// assert_relative_eq!(centroid.x(), 45., epsilon = 1.0e-9);
+    /// assert_relative_eq!(centroid.y(), 0., epsilon = 1.0e-9);
+    /// # }
+    /// ```
+    fn spherical_centroid(&self) -> Option<Point<T>>;
+}
+
+impl<T> SphericalCentroid<T> for MultiPoint<T>
+    where T: CoordFloat
+{
+    fn spherical_centroid(&self) -> Option<Point<T>> {
+        let (x, y, z) = self.0.iter().fold((T::zero(), T::zero(), T::zero()), |sum, p| {
+            let (lng, lat) = (p.x().to_radians(), p.y().to_radians());
+            (sum.0 + lat.cos() * lng.cos(), sum.1 + lat.cos() * lng.sin(), sum.2 + lat.sin())
+        });
+        let horizontal = x.hypot(y);
+        // rounding error in the sum is around epsilon per point
+        let tolerance = T::epsilon() * T::from(self.0.len() * 4).unwrap();
+        if horizontal.hypot(z) <= tolerance {
+            return None;
+        }
+        Some(Point::new(y.atan2(x).to_degrees(), z.atan2(horizontal).to_degrees()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, MultiPoint};
+    use super::*;
+
+    #[test]
+    fn antimeridian_test() {
+        let points = MultiPoint(vec![Point::<f64>::new(179., 10.), Point::new(-179., 10.)]);
+        let centroid = points.spherical_centroid().unwrap();
+        assert_relative_eq!(centroid.x().abs(), 180., epsilon = 1.0e-9);
+        assert!(centroid.y() > 10.);
+        assert!(centroid.y() < 10.1);
+    }
+    #[test]
+    fn pole_test() {
+        // points spread evenly around a parallel are centered on the pole
+        let points = MultiPoint((0..4).map(|i| Point::new(-180. + 90. * i as f64, 80.)).collect());
+        let centroid = points.spherical_centroid().unwrap();
+        assert_relative_eq!(centroid.y(), 90., epsilon = 1.0e-9);
+    }
+    #[test]
+    fn degenerate_test() {
+        assert!(MultiPoint::<f64>(vec![]).spherical_centroid().is_none());
+        let antipodal = MultiPoint(vec![Point::new(0., 0.), Point::new(180., 0.)]);
+        assert!(antipodal.spherical_centroid().is_none());
+        let single = MultiPoint(vec![Point::new(12.5, -33.)]);
+        let centroid = single.spherical_centroid().unwrap();
+        assert_relative_eq!(centroid.x(), 12.5, epsilon = 1.0e-9);
+        assert_relative_eq!(centroid.y(), -33., epsilon = 1.0e-9);
+    }
+}
@@ -0,0 +1,148 @@
+use std::error::Error;
+use std::fmt;
+use types::{CoordFloat, Point, Bbox};
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The ways decoding a geohash can fail.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GeohashError {
+    /// The geohash was empty
+    Empty,
+    /// The geohash contained a character outside the geohash alphabet
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GeohashError::Empty => write!(f, "empty geohash"),
+            GeohashError::InvalidCharacter(c) => write!(f, "invalid geohash character {:?}", c),
+        }
+    }
+}
+
+impl Error for GeohashError {
+    fn description(&self) -> &str {
+        "invalid geohash"
+    }
+}
+
+/// Encodes a point as a geohash of `precision` characters.
+///
+/// The point's x is its longitude and its y its latitude, in degrees. Each character
+/// narrows the covering cell by 5 bits, alternating between longitude and latitude.
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::geohash::encode;
+///
+/// assert_eq!(encode(&Point::new(-5.6, 42.6), 5), "ezs42");
+/// ```
+pub fn encode<T>(p: &Point<T>, precision: usize) -> String
+    where T: CoordFloat
+{
+    let (lng, lat) = (p.lng().to_f64().unwrap(), p.lat().to_f64().unwrap());
+    let (mut lng_range, mut lat_range) = ((-180., 180.), (-90., 90.));
+    let mut hash = String::with_capacity(precision);
+    let mut even = true;
+    for _ in 0..precision {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (value, range) = if even { (lng, &mut lng_range) } else { (lat, &mut lat_range) };
+            let mid = (range.0 + range.1) / 2.;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even = !even;
+        }
+        hash.push(BASE32[index] as char);
+    }
+    hash
+}
+
+/// Decodes a geohash into the center of its cell and the cell itself.
+///
+/// The x coordinates are longitudes and the y coordinates latitudes, in degrees.
+/// Upper-case characters are accepted.
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::geohash::decode;
+/// use geo::algorithm::contains::Contains;
+///
+/// let (center, cell) = decode("ezs42").unwrap();
+///
+/// assert!(cell.contains(&Point::new(-5.6, 42.6)));
+/// assert!((center.lng() - -5.603).abs() < 0.001);
+/// assert!((center.lat() - 42.605).abs() < 0.001);
+/// ```
+pub fn decode(hash: &str) -> Result<(Point<f64>, Bbox<f64>), GeohashError> {
+    if hash.is_empty() {
+        return Err(GeohashError::Empty);
+    }
+    let (mut lng_range, mut lat_range) = ((-180., 180.), (-90., 90.));
+    let mut even = true;
+    for c in hash.chars() {
+        let index = BASE32
+            .iter()
+            .position(|&b| b as char == c.to_ascii_lowercase())
+            .ok_or(GeohashError::InvalidCharacter(c))?;
+        for bit in (0..5).rev() {
+            let range = if even { &mut lng_range } else { &mut lat_range };
+            let mid = (range.0 + range.1) / 2.;
+            if index & (1 << bit) != 0 {
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even = !even;
+        }
+    }
+    let bbox = Bbox { xmin: lng_range.0, xmax: lng_range.1, ymin: lat_range.0, ymax: lat_range.1 };
+    let center = Point::new((lng_range.0 + lng_range.1) / 2., (lat_range.0 + lat_range.1) / 2.);
+    Ok((center, bbox))
+}
+
+#[cfg(test)]
+mod test {
+    use types::Point;
+    use algorithm::contains::Contains;
+    use super::*;
+
+    #[test]
+    fn known_hash_test() {
+        // the Jutland example from geohash.org
+        let p = Point::new(10.40744, 57.64911);
+        assert_eq!(encode(&p, 11), "u4pruydqqvj");
+        let (center, bbox) = decode("u4pruydqqvj").unwrap();
+        assert!(bbox.contains(&p));
+        assert_relative_eq!(center.lng(), 10.40744, epsilon = 1e-5);
+        assert_relative_eq!(center.lat(), 57.64911, epsilon = 1e-5);
+    }
+    #[test]
+    fn round_trip_test() {
+        let points = vec![Point::new(-122.4194, 37.7749), Point::new(151.2093, -33.8688),
+                          Point::new(0., 0.), Point::new(-179.9, -89.9)];
+        for p in points {
+            for precision in 1..10 {
+                let hash = encode(&p, precision);
+                assert_eq!(hash.len(), precision);
+                let (center, bbox) = decode(&hash).unwrap();
+                assert!(bbox.contains(&p));
+                assert!(bbox.contains(&center));
+                assert_eq!(encode(&center, precision), hash);
+            }
+        }
+    }
+    #[test]
+    fn invalid_test() {
+        assert_eq!(decode(""), Err(GeohashError::Empty));
+        assert_eq!(decode("ezs4a"), Err(GeohashError::InvalidCharacter('a')));
+        assert_eq!(decode("EZS42"), decode("ezs42"));
+    }
+}
@@ -0,0 +1,117 @@
+use types::{CoordFloat, Point, Line, LineString, Closest};
+use algorithm::haversine_distance::HaversineDistance;
+use algorithm::haversine_destination::HaversineDestination;
+use algorithm::cross_track_distance::{CrossTrackDistance, initial_bearing};
+
+pub trait HaversineClosestPoint<T: CoordFloat> {
+    /// Returns the point on a geometry nearest to `p`, treating coordinates as
+    /// longitude/latitude and segments as great-circle arcs.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::{Point, LineString, Closest};
+    /// use geo::algorithm::haversine_closest_point::HaversineClosestPoint;
+    ///
+    /// # fn main() {
+    /// let track = LineString(vec![Point::new(0., 0.), Point::new(10., 0.)]);
+    /// match track.haversine_closest_point(&Point::new(5., 1.)) {
+    ///     Closest::Intersection(p) => {
+    ///         assert_relative_eq!(p.x(), 5., epsilon = 1.0e-6);
+    ///         assert_relative_eq!(p.y(), 0., epsilon = 1.0e-6);
+    ///     }
+    ///     _ => panic!("expected the foot of the cross-track perpendicular"),
+    /// }
+    /// # }
+    /// ```
+    fn haversine_closest_point(&self, p: &Point<T>) -> Closest<T>;
+}
+
+// The closest point on a single great-circle segment, and its distance to p
+fn segment_closest_point<T>(line: &Line<T>, p: &Point<T>) -> (Closest<T>, T)
+    where T: CoordFloat
+{
+    let (start, end) = (line.start, line.end);
+    let length = start.haversine_distance(&end);
+    if length == T::zero() {
+        return (Closest::SinglePoint(start), start.haversine_distance(p));
+    }
+    let along_track = p.along_track_distance(&start, &end);
+    if along_track <= T::zero() {
+        (Closest::SinglePoint(start), start.haversine_distance(p))
+    } else if along_track >= length {
+        (Closest::SinglePoint(end), end.haversine_distance(p))
+    } else {
+        let bearing = initial_bearing(&start, &end).to_degrees();
+        let foot = start.haversine_destination(bearing, along_track);
+        (Closest::Intersection(foot), foot.haversine_distance(p))
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for Line<T>
+    where T: CoordFloat
+{
+    fn haversine_closest_point(&self, p: &Point<T>) -> Closest<T> {
+        segment_closest_point(self, p).0
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn haversine_closest_point(&self, p: &Point<T>) -> Closest<T> {
+        if self.0.len() == 1 {
+            return Closest::SinglePoint(self.0[0]);
+        }
+        let mut best = (Closest::Indeterminate, T::infinity());
+        for segment in self.0.windows(2) {
+            let candidate = segment_closest_point(&Line::new(segment[0], segment[1]), p);
+            if candidate.1 < best.1 {
+                best = candidate;
+            }
+        }
+        best.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, Line, LineString, Closest};
+    use super::*;
+
+    #[test]
+    fn cross_track_foot_test() {
+        // the query point is nearer to the vertex at (1, 0) than to (20, 0),
+        // but the closest point on the track lies between them
+        let track = LineString(vec![Point::new(1., 0.), Point::new(20., 0.), Point::new(20., 10.)]);
+        match track.haversine_closest_point(&Point::new(3., 1.)) {
+            Closest::Intersection(p) => {
+                assert_relative_eq!(p.x(), 3., epsilon = 1.0e-6);
+                assert_relative_eq!(p.y(), 0., epsilon = 1.0e-6);
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+    #[test]
+    fn vertex_test() {
+        let line = Line::new(Point::new(0., 0.), Point::new(10., 0.));
+        assert_eq!(line.haversine_closest_point(&Point::new(-2., 1.)),
+                   Closest::SinglePoint(Point::new(0., 0.)));
+        assert_eq!(line.haversine_closest_point(&Point::new(12., -1.)),
+                   Closest::SinglePoint(Point::new(10., 0.)));
+    }
+    #[test]
+    fn meridian_test() {
+        let track = LineString(vec![Point::new(10., 40.), Point::new(10., 50.)]);
+        match track.haversine_closest_point(&Point::new(11., 45.)) {
+            Closest::Intersection(p) => assert_relative_eq!(p.x(), 10., epsilon = 1.0e-6),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+    #[test]
+    fn empty_linestring_test() {
+        let track = LineString::<f64>(vec![]);
+        assert_eq!(track.haversine_closest_point(&Point::new(1., 1.)), Closest::Indeterminate);
+    }
+}
@@ -0,0 +1,143 @@
+use types::{CoordFloat, Point, LineString, MultiLineString};
+use algorithm::densify::MAX_PIECES_PER_SEGMENT;
+
+// a point of longitude/latitude in degrees as a 3D unit vector
+fn to_vector<T>(p: &Point<T>) -> (T, T, T)
+    where T: CoordFloat
+{
+    let (lng, lat) = (p.x().to_radians(), p.y().to_radians());
+    (lat.cos() * lng.cos(), lat.cos() * lng.sin(), lat.sin())
+}
+
+// densify a slice of points along great circles, so that no piece spans more than
+// `max_angle` radians of arc
+fn densify_points<T>(points: &[Point<T>], max_angle: T) -> Vec<Point<T>>
+    where T: CoordFloat
+{
+    let mut densified = Vec::with_capacity(points.len());
+    for segment in points.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        densified.push(start);
+        let (a, b) = (to_vector(&start), to_vector(&end));
+        // the central angle, from both its sine and cosine to stay accurate when small
+        let cross = (a.1 * b.2 - a.2 * b.1, a.2 * b.0 - a.0 * b.2, a.0 * b.1 - a.1 * b.0);
+        let sin = (cross.0 * cross.0 + cross.1 * cross.1 + cross.2 * cross.2).sqrt();
+        let angle = sin.atan2(a.0 * b.0 + a.1 * b.1 + a.2 * b.2);
+        let pieces = (angle / max_angle).ceil();
+        // antipodal points have no single great circle between them; a segment which
+        // would need too many pieces is left as it is
+        if sin <= T::epsilon() * T::from(4).unwrap() || !pieces.is_finite() {
+            continue;
+        }
+        let count = pieces.to_usize().unwrap_or(0);
+        if count > MAX_PIECES_PER_SEGMENT {
+            continue;
+        }
+        for i in 1..count {
+            // spherical linear interpolation between the two unit vectors
+            let t = T::from(i).unwrap() / pieces;
+            let ka = ((T::one() - t) * angle).sin() / sin;
+            let kb = (t * angle).sin() / sin;
+            let (x, y, z) = (ka * a.0 + kb * b.0, ka * a.1 + kb * b.1, ka * a.2 + kb * b.2);
+            densified.push(Point::new(y.atan2(x).to_degrees(), z.atan2(x.hypot(y)).to_degrees()));
+        }
+    }
+    if let Some(&last) = points.last() {
+        densified.push(last);
+    }
+    densified
+}
+
+pub trait HaversineDensify<T> {
+    /// Returns a geometry with points added along the great circles between its points,
+    /// treating coordinates as longitude/latitude in degrees, so that the path turns by no
+    /// more than `max_angle_deg` at each added point.
+    ///
+    /// Consecutive chords of a great circle turn by the angle of arc each one spans, so
+    /// each segment is split into the fewest equal arcs no wider than `max_angle_deg`. The
+    /// original points are all kept. Segments between antipodal points are left alone.
+    ///
+    /// If `max_angle_deg` isn't positive and finite, the geometry is returned unchanged.
+    /// Segments which would need more than `MAX_PIECES_PER_SEGMENT` pieces are kept whole.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::haversine_densify::HaversineDensify;
+    ///
+    /// // a quarter of the way around the equator, in pieces of no more than 25°
+    /// let ls = LineString(vec![Point::<f64>::new(0.0, 0.0), Point::new(90.0, 0.0)]);
+    /// let densified = ls.densify_max_angle(25.0);
+    ///
+    /// assert_eq!(densified.0.len(), 5);
+    /// assert!((densified.0[1].x() - 22.5).abs() < 1e-9);
+    /// ```
+    fn densify_max_angle(&self, max_angle_deg: T) -> Self where T: CoordFloat;
+}
+
+impl<T> HaversineDensify<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn densify_max_angle(&self, max_angle_deg: T) -> Self {
+        if !(max_angle_deg > T::zero() && max_angle_deg.is_finite()) {
+            return self.clone();
+        }
+        LineString(densify_points(&self.0, max_angle_deg.to_radians()))
+    }
+}
+
+impl<T> HaversineDensify<T> for MultiLineString<T>
+    where T: CoordFloat
+{
+    fn densify_max_angle(&self, max_angle_deg: T) -> Self {
+        MultiLineString(self.0.iter().map(|ls| ls.densify_max_angle(max_angle_deg)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use algorithm::cross_track_distance::CrossTrackDistance;
+    use super::*;
+
+    #[test]
+    fn smaller_angles_add_more_points_test() {
+        // from London to Tokyo, about 86° of arc
+        let (london, tokyo) = (Point::<f64>::new(-0.1278, 51.5074), Point::new(139.6917, 35.6895));
+        let ls = LineString(vec![london, tokyo]);
+        let mut last = 0;
+        for &(max_angle, expected) in &[(90., 2), (45., 3), (10., 10), (1., 87)] {
+            let densified = ls.densify_max_angle(max_angle);
+            assert_eq!(densified.0.len(), expected);
+            assert!(densified.0.len() > last);
+            last = densified.0.len();
+            assert_eq!(densified.0[0], london);
+            assert_eq!(densified.0[expected - 1], tokyo);
+            // every added point lies on the great circle
+            for p in &densified.0 {
+                assert!(p.cross_track_distance(&london, &tokyo).abs() < 1e-3);
+            }
+        }
+    }
+    #[test]
+    fn pole_test() {
+        // over the north pole, where the longitude jumps
+        let ls = LineString(vec![Point::new(0., 60.), Point::new(180., 60.)]);
+        let densified = ls.densify_max_angle(31.);
+        assert_eq!(densified.0.len(), 3);
+        assert_relative_eq!(densified.0[1].y(), 90.);
+    }
+    #[test]
+    fn degenerate_test() {
+        let ls = LineString(vec![Point::new(10., 10.), Point::new(10., 10.)]);
+        assert_eq!(ls.densify_max_angle(1.), ls);
+        let antipodes = LineString(vec![Point::new(0., 0.), Point::new(180., 0.)]);
+        assert_eq!(antipodes.densify_max_angle(1.), antipodes);
+        assert_eq!(LineString::<f64>(vec![]).densify_max_angle(1.), LineString(vec![]));
+    }    #[test]
+    fn invalid_angle_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(10., 0.), Point::new(10., 10.)]);
+        for &max_angle in &[0., -1., f64::NAN, f64::INFINITY, 1e-300] {
+            assert_eq!(ls.densify_max_angle(max_angle), ls);
+        }
+    }
+}
@@ -1,9 +1,9 @@
-use num_traits::{Float, FromPrimitive};
-use types::Point;
+use types::{CoordFloat, Point};
+use constants::MEAN_EARTH_RADIUS;
 
 /// Returns a new Point using the distance to the existing Point and a bearing for the direction
 
-pub trait HaversineDestination<T: Float> {
+pub trait HaversineDestination<T: CoordFloat> {
     /// Returns a new Point using distance to the existing Point and a bearing for the direction
     ///
     /// ```
@@ -17,33 +17,55 @@ pub trait HaversineDestination<T: Float> {
     fn haversine_destination(&self, bearing: T, distance: T) -> Point<T>;
 }
 
+// wrap a longitude in degrees into [-180, 180)
+fn normalize_longitude<T>(lng: T) -> T
+    where T: CoordFloat
+{
+    let (half_turn, full_turn) = (T::from(180.).unwrap(), T::from(360.).unwrap());
+    if lng >= -half_turn && lng < half_turn {
+        return lng;
+    }
+    let mut wrapped = (lng + half_turn) % full_turn;
+    // adding a full turn to a tiny negative remainder can round up to a full turn
+    if wrapped < T::zero() {
+        wrapped = wrapped + full_turn;
+    }
+    if wrapped >= full_turn {
+        wrapped = T::zero();
+    }
+    wrapped - half_turn
+}
+
 impl<T> HaversineDestination<T> for Point<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
     fn haversine_destination(&self, bearing: T, distance: T) -> Point<T> {
         let center_lng = self.x().to_radians();
         let center_lat = self.y().to_radians();
         let bearing_rad = bearing.to_radians();
 
-        // WGS84 equatorial radius is 6378137.0
-        let rad = distance / T::from(6371000.0).unwrap();
+        let rad = distance / T::from(MEAN_EARTH_RADIUS).unwrap();
 
+        // rounding can push the sine just past 1 near the poles
         let lat = {
                 center_lat.sin() * rad.cos() + center_lat.cos() * rad.sin() * bearing_rad.cos()
             }
+            .max(-T::one())
+            .min(T::one())
             .asin();
         let lng = {
                 bearing_rad.sin() * rad.sin() * center_lat.cos()
             }
             .atan2(rad.cos() - center_lat.sin() * lat.sin()) + center_lng;
 
-        Point::new(lng.to_degrees(), lat.to_degrees())
+        Point::new(normalize_longitude(lng.to_degrees()), lat.to_degrees())
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::f64::consts::PI;
     use algorithm::haversine_distance::HaversineDistance;
 
     #[test]
@@ -54,4 +76,31 @@ mod test {
         let distance = p_1.haversine_distance(&p_2);
         assert_relative_eq!(distance, 10000., epsilon = 1.0e-6)
     }
+    #[test]
+    fn long_distances_test() {
+        let circumference = 2. * PI * 6371000.;
+        let start = Point::<f64>::new(170., 20.);
+        for &bearing in &[0., 45., 90., 135., 180., 270.] {
+            for &fraction in &[0.25, 0.5, 0.75, 1.] {
+                let p = start.haversine_destination(bearing, fraction * circumference);
+                assert!(p.y().is_finite() && p.y().abs() <= 90.);
+                assert!(p.x() >= -180. && p.x() < 180.);
+            }
+        }
+        // half way round the world is the antipode
+        let antipode = start.haversine_destination(90., circumference / 2.);
+        assert_relative_eq!(antipode.x(), -10., epsilon = 1e-9);
+        assert_relative_eq!(antipode.y(), -20., epsilon = 1e-9);
+        // a quarter of the way east along the equator crosses the antimeridian
+        let east = Point::<f64>::new(170., 0.).haversine_destination(90., circumference / 4.);
+        assert_relative_eq!(east.x(), -100., epsilon = 1e-9);
+    }
+    #[test]
+    fn over_the_pole_test() {
+        // straight over the north pole, where the sine of the latitude rounds to 1
+        let circumference = 2. * PI * 6371000.;
+        let p = Point::<f64>::new(0., 0.).haversine_destination(0., circumference / 4.);
+        assert!(p.y().is_finite());
+        assert_relative_eq!(p.y(), 90., epsilon = 1e-6);
+    }
 }
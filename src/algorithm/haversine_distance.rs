@@ -1,5 +1,5 @@
-use num_traits::{Float, FromPrimitive};
-use types::Point;
+use types::{CoordFloat, Point};
+use constants::MEAN_EARTH_RADIUS;
 
 /// Returns the Haversine distance between two geometries.
 
@@ -23,7 +23,7 @@ pub trait HaversineDistance<T, Rhs = Self> {
 }
 
 impl<T> HaversineDistance<T, Point<T>> for Point<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
     fn haversine_distance(&self, rhs: &Point<T>) -> T {
         let two = T::one() + T::one();
@@ -34,8 +34,7 @@ impl<T> HaversineDistance<T, Point<T>> for Point<T>
         let a = (delta_theta / two).sin().powi(2) +
                 theta1.cos() * theta2.cos() * (delta_lambda / two).sin().powi(2);
         let c = two * a.sqrt().asin();
-        // WGS84 equatorial radius is 6378137.0
-        T::from(6371000.0).unwrap() * c
+        T::from(MEAN_EARTH_RADIUS).unwrap() * c
     }
 }
 
@@ -0,0 +1,100 @@
+use types::{CoordFloat, Point, LineString};
+
+// scale a vector
+fn scale<T>(p: Point<T>, k: T) -> Point<T>
+    where T: CoordFloat
+{
+    Point::new(p.x() * k, p.y() * k)
+}
+
+/// Returns a smooth curve through the points of a `LineString`, sampled as a `LineString`.
+///
+/// Each segment is replaced by a uniform Catmull-Rom spline, sampled at
+/// `samples_per_segment` evenly spaced parameters, so the curve passes exactly through
+/// every original point and the result has `(n - 1) * samples_per_segment + 1` points.
+/// The tangents at the two ends are clamped, as if each end point were repeated.
+///
+/// Linestrings with fewer than three points, or `samples_per_segment` below `2`, are
+/// returned unchanged.
+///
+/// ```
+/// use geo::{Point, LineString};
+/// use geo::algorithm::interpolate_spline::catmull_rom;
+///
+/// let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 1.), Point::new(2., 0.)]);
+/// let curve = catmull_rom(&ls, 4);
+///
+/// assert_eq!(curve.0.len(), 9);
+/// assert_eq!(curve.0[4], Point::new(1., 1.));
+/// // the curve bulges above the straight segments on the way up
+/// assert!(curve.0[2].y() > curve.0[2].x());
+/// ```
+pub fn catmull_rom<T>(ls: &LineString<T>, samples_per_segment: usize) -> LineString<T>
+    where T: CoordFloat
+{
+    let points = &ls.0;
+    let n = points.len();
+    if n < 3 || samples_per_segment < 2 {
+        return ls.clone();
+    }
+    let half = T::from(0.5).unwrap();
+    let (two, three) = (T::from(2).unwrap(), T::from(3).unwrap());
+    let steps = T::from(samples_per_segment).unwrap();
+    let tangent = |i: usize| {
+        let prev = points[i.saturating_sub(1)];
+        let next = points[(i + 1).min(n - 1)];
+        scale(next - prev, half)
+    };
+    let mut curve = Vec::with_capacity((n - 1) * samples_per_segment + 1);
+    for i in 0..(n - 1) {
+        let (p1, p2) = (points[i], points[i + 1]);
+        let (m1, m2) = (tangent(i), tangent(i + 1));
+        curve.push(p1);
+        for step in 1..samples_per_segment {
+            // the cubic Hermite basis functions
+            let t = T::from(step).unwrap() / steps;
+            let (t2, t3) = (t * t, t * t * t);
+            let h00 = two * t3 - three * t2 + T::one();
+            let h10 = t3 - two * t2 + t;
+            let h01 = three * t2 - two * t3;
+            let h11 = t3 - t2;
+            curve.push(scale(p1, h00) + scale(m1, h10) + scale(p2, h01) + scale(m2, h11));
+        }
+    }
+    curve.push(points[n - 1]);
+    LineString(curve)
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use super::*;
+
+    #[test]
+    fn passes_through_control_points_test() {
+        let p = |x, y| Point::new(x, y);
+        let ls = LineString(vec![p(0., 0.), p(2., 3.), p(5., 1.), p(6., 6.), p(9., 0.)]);
+        let curve = catmull_rom(&ls, 8);
+        assert_eq!(curve.0.len(), 4 * 8 + 1);
+        for (i, point) in ls.0.iter().enumerate() {
+            assert_eq!(curve.0[i * 8], *point);
+        }
+    }
+    #[test]
+    fn collinear_test() {
+        // evenly spaced collinear points give evenly spaced samples on the same line
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(2., 0.),
+                                 Point::new(3., 0.)]);
+        let curve = catmull_rom(&ls, 2);
+        assert_eq!(curve.0.len(), 7);
+        assert_relative_eq!(curve.0[3].x(), 1.5);
+        assert_relative_eq!(curve.0[3].y(), 0.);
+    }
+    #[test]
+    fn short_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 1.)]);
+        assert_eq!(catmull_rom(&ls, 5), ls);
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 1.), Point::new(2., 0.)]);
+        assert_eq!(catmull_rom(&ls, 1), ls);
+    }
+}
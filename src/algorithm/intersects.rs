@@ -1,6 +1,7 @@
-use num_traits::Float;
-use types::{Line, LineString, Polygon, Bbox, Point};
+use types::{CoordFloat, Line, LineString, Polygon, Bbox, Point};
+use constants::DEFAULT_EPSILON;
 use algorithm::contains::Contains;
+use algorithm::distance::Distance;
 
 /// Checks if the geometry A intersects the geometry B.
 
@@ -22,47 +23,59 @@ pub trait Intersects<Rhs = Self> {
     fn intersects(&self, rhs: &Rhs) -> bool;
 }
 
+/// Checks if a point lies on a line, to within a tolerance.
+pub trait IntersectsWithEpsilon<T, Rhs> {
+    /// Checks if the geometry A comes within `epsilon` of the geometry B.
+    ///
+    /// `intersects` uses `DEFAULT_EPSILON` for the same check.
+    ///
+    /// ```
+    /// use geo::{Point, Line};
+    /// use geo::algorithm::intersects::{Intersects, IntersectsWithEpsilon};
+    ///
+    /// let line = Line::new(Point::new(0., 0.), Point::new(2., 2.));
+    /// let near = Point::new(1., 1.001);
+    ///
+    /// assert!(line.intersects_with_epsilon(&near, 0.01));
+    /// assert!(!line.intersects(&near));
+    /// ```
+    fn intersects_with_epsilon(&self, rhs: &Rhs, epsilon: T) -> bool;
+}
+
 impl<T> Intersects<Point<T>> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, p: &Point<T>) -> bool {
-        let dx = self.end.x() - self.start.x();
-        let dy = self.end.y() - self.start.y();
-        let tx = if dx == T::zero() {None} else {Some((p.x() - self.start.x()) / dx)};
-        let ty = if dy == T::zero() {None} else {Some((p.y() - self.start.y()) / dy)};
-        match (tx, ty) {
-            (None, None) => { // Degenerate line
-                *p == self.start
-            },
-            (Some(t), None) => { // Horizontal line
-                p.y() == self.start.y() &&
-                    T::zero() <= t &&
-                    t <= T::one()
-            },
-            (None, Some(t)) => { // Vertical line
-                p.x() == self.start.x() &&
-                    T::zero() <= t &&
-                    t <= T::one()
-            },
-            (Some(t_x), Some(t_y)) => { // All other lines
-                (t_x - t_y).abs() <= T::epsilon()  &&
-                    T::zero() <= t_x &&
-                    t_x <= T::one()
-            }
-        }
+        self.intersects_with_epsilon(p, T::from(DEFAULT_EPSILON).unwrap())
+    }
+}
+
+impl<T> IntersectsWithEpsilon<T, Point<T>> for Line<T>
+    where T: CoordFloat
+{
+    fn intersects_with_epsilon(&self, p: &Point<T>, epsilon: T) -> bool {
+        self.distance(p) <= epsilon
     }
 }
 
 impl<T> Intersects<Line<T>> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, line: &Line<T>) -> bool {
         line.intersects(self)
     }
 }
 
+impl<T> IntersectsWithEpsilon<T, Line<T>> for Point<T>
+    where T: CoordFloat
+{
+    fn intersects_with_epsilon(&self, line: &Line<T>, epsilon: T) -> bool {
+        line.intersects_with_epsilon(self, epsilon)
+    }
+}
+
 impl<T> Intersects<Line<T>> for Line<T> 
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, line: &Line<T>) -> bool {
         // Using Cramer's Rule:
@@ -94,7 +107,7 @@ impl<T> Intersects<Line<T>> for Line<T>
 }
 
 impl<T> Intersects<LineString<T>> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, linestring: &LineString<T>) -> bool {
         linestring.0
@@ -105,7 +118,7 @@ impl<T> Intersects<LineString<T>> for Line<T>
 }
 
 impl<T> Intersects<Line<T>> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, line: &Line<T>) -> bool {
         line.intersects(self)
@@ -113,7 +126,7 @@ impl<T> Intersects<Line<T>> for LineString<T>
 }
 
 impl<T> Intersects<Polygon<T>> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, p: &Polygon<T>) -> bool {
         p.exterior.intersects(self) ||
@@ -124,7 +137,7 @@ impl<T> Intersects<Polygon<T>> for Line<T>
 }
 
 impl<T> Intersects<Line<T>> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, line: &Line<T>) -> bool {
         line.intersects(self)
@@ -132,7 +145,7 @@ impl<T> Intersects<Line<T>> for Polygon<T>
 }
 
 impl<T> Intersects<LineString<T>> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     // See: https://github.com/brandonxiang/geojson-python-utils/blob/33b4c00c6cf27921fb296052d0c0341bd6ca1af2/geojson_utils.py
     fn intersects(&self, linestring: &LineString<T>) -> bool {
@@ -164,7 +177,7 @@ impl<T> Intersects<LineString<T>> for LineString<T>
 }
 
 impl<T> Intersects<LineString<T>> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, linestring: &LineString<T>) -> bool {
         // line intersects inner or outer polygon edge
@@ -178,7 +191,7 @@ impl<T> Intersects<LineString<T>> for Polygon<T>
 }
 
 impl<T> Intersects<Bbox<T>> for Bbox<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, bbox: &Bbox<T>) -> bool {
         // line intersects inner or outer polygon edge
@@ -192,7 +205,7 @@ impl<T> Intersects<Bbox<T>> for Bbox<T>
 }
 
 impl<T> Intersects<Polygon<T>> for Bbox<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, polygon: &Polygon<T>) -> bool {
         polygon.intersects(self)
@@ -200,7 +213,7 @@ impl<T> Intersects<Polygon<T>> for Bbox<T>
 }
 
 impl<T> Intersects<Bbox<T>> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, bbox: &Bbox<T>) -> bool {
         let p = Polygon::new(LineString(vec![Point::new(bbox.xmin, bbox.ymin),
@@ -214,7 +227,7 @@ impl<T> Intersects<Bbox<T>> for Polygon<T>
 }
 
 impl<T> Intersects<Polygon<T>> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn intersects(&self, polygon: &Polygon<T>) -> bool {
         // self intersects (or contains) any line in polygon
@@ -228,7 +241,7 @@ impl<T> Intersects<Polygon<T>> for Polygon<T>
 #[cfg(test)]
 mod test {
     use types::{Coordinate, Point, Line, LineString, Polygon, Bbox};
-    use algorithm::intersects::Intersects;
+    use algorithm::intersects::{Intersects, IntersectsWithEpsilon};
     /// Tests: intersection LineString and LineString
     #[test]
     fn empty_linestring1_test() {
@@ -534,5 +547,15 @@ mod test {
 
         assert!(!line0.intersects(&poly2));
         assert!(!poly2.intersects(&line0));
+    }    #[test]
+    fn point_near_line_test() {
+        let line = Line::new(Point::new(0., 0.), Point::new(3., 1.));
+        // 1e-12 off the line, measured perpendicular to it
+        let near = Point::new(1.5 - 1e-13, 0.5 + 3e-13);
+        assert!(line.intersects(&near));
+        assert!(near.intersects(&line));
+        assert!(!line.intersects_with_epsilon(&near, 1e-15));
+        assert!(!line.intersects(&Point::new(1.5, 0.5 + 1e-6)));
+        assert!(line.intersects_with_epsilon(&Point::new(1.5, 0.5 + 1e-6), 1e-5));
     }
 }
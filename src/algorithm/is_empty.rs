@@ -0,0 +1,133 @@
+use types::{CoordFloat, Point, Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon,
+            GeometryCollection, Geometry};
+
+pub trait IsEmpty {
+    /// Checks if a geometry has no points.
+    ///
+    /// A `Point` or `Line` is never empty. A `Polygon` is empty if its exterior ring is,
+    /// and a collection of geometries is empty if all of its members are, or it has none.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, MultiLineString};
+    /// use geo::algorithm::is_empty::IsEmpty;
+    ///
+    /// assert!(LineString::<f64>(vec![]).is_empty());
+    /// assert!(!Point::new(1., 1.).is_empty());
+    /// assert!(MultiLineString::<f64>(vec![LineString(vec![])]).is_empty());
+    /// ```
+    fn is_empty(&self) -> bool;
+}
+
+impl<T> IsEmpty for Point<T>
+    where T: CoordFloat
+{
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl<T> IsEmpty for Line<T>
+    where T: CoordFloat
+{
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl<T> IsEmpty for LineString<T>
+    where T: CoordFloat
+{
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T> IsEmpty for Polygon<T>
+    where T: CoordFloat
+{
+    fn is_empty(&self) -> bool {
+        self.exterior.is_empty()
+    }
+}
+
+impl<T> IsEmpty for MultiPoint<T>
+    where T: CoordFloat
+{
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T> IsEmpty for MultiLineString<T>
+    where T: CoordFloat
+{
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|ls| ls.is_empty())
+    }
+}
+
+impl<T> IsEmpty for MultiPolygon<T>
+    where T: CoordFloat
+{
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|poly| poly.is_empty())
+    }
+}
+
+impl<T> IsEmpty for GeometryCollection<T>
+    where T: CoordFloat
+{
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|geom| geom.is_empty())
+    }
+}
+
+impl<T> IsEmpty for Geometry<T>
+    where T: CoordFloat
+{
+    fn is_empty(&self) -> bool {
+        match *self {
+            Geometry::Point(ref g) => g.is_empty(),
+            Geometry::LineString(ref g) => g.is_empty(),
+            Geometry::Polygon(ref g) => g.is_empty(),
+            Geometry::MultiPoint(ref g) => g.is_empty(),
+            Geometry::MultiLineString(ref g) => g.is_empty(),
+            Geometry::MultiPolygon(ref g) => g.is_empty(),
+            Geometry::GeometryCollection(ref g) => g.is_empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, Line, LineString, Polygon, MultiPoint, MultiPolygon, GeometryCollection, Geometry};
+    use super::*;
+
+    #[test]
+    fn linestring_test() {
+        assert!(LineString::<f64>(vec![]).is_empty());
+        assert!(!LineString(vec![Point::new(1., 1.)]).is_empty());
+    }
+    #[test]
+    fn point_test() {
+        assert!(!Point::new(0., 0.).is_empty());
+        assert!(!Line::new(Point::new(0., 0.), Point::new(0., 0.)).is_empty());
+        assert!(MultiPoint::<f64>(vec![]).is_empty());
+        assert!(!MultiPoint(vec![Point::new(0., 0.)]).is_empty());
+    }
+    #[test]
+    fn polygon_test() {
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert!(empty.is_empty());
+        let square = Polygon::new(LineString(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(1., 1.),
+                                                  Point::new(0., 0.)]),
+                                  vec![]);
+        assert!(!square.is_empty());
+        assert!(MultiPolygon(vec![empty.clone(), empty.clone()]).is_empty());
+        assert!(!MultiPolygon(vec![empty.clone(), square.clone()]).is_empty());
+
+        let collection = GeometryCollection(vec![Geometry::Polygon(empty), Geometry::MultiPolygon(MultiPolygon(vec![]))]);
+        assert!(collection.is_empty());
+        assert!(!Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Polygon(square)])).is_empty());
+    }
+}
@@ -0,0 +1,97 @@
+use types::{CoordFloat, Line, LineString};
+use algorithm::sweep::find_intersections;
+
+// do the segments, where the first ends and the second starts, overlap beyond that point?
+// Segments meeting at an angle only touch there, so they overlap only if the second turns
+// straight back along the first
+fn doubles_back<T>(first: &Line<T>, second: &Line<T>) -> bool
+    where T: CoordFloat
+{
+    let (a, b) = (first.end - first.start, second.end - second.start);
+    a.x() * b.y() - a.y() * b.x() == T::zero() && a.dot(&b) < T::zero()
+}
+
+pub trait IsSimple {
+    /// Checks if a geometry is simple, i.e. doesn't intersect itself.
+    ///
+    /// A `LineString` is simple if none of its non-adjacent segments intersect. The first
+    /// and last segments of a closed `LineString` are adjacent, so a valid ring is simple.
+    /// Adjacent segments may only meet at their shared point, so a `LineString` that turns
+    /// straight back along itself isn't simple.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::is_simple::IsSimple;
+    ///
+    /// // a square, and a "bowtie" crossing itself in the middle
+    /// let square = LineString(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(1., 1.),
+    ///                              Point::new(0., 1.), Point::new(0., 0.)]);
+    /// let bowtie = LineString(vec![Point::new(0., 0.), Point::new(1., 1.), Point::new(1., 0.),
+    ///                              Point::new(0., 1.), Point::new(0., 0.)]);
+    /// assert!(square.is_simple());
+    /// assert!(!bowtie.is_simple());
+    /// ```
+    fn is_simple(&self) -> bool;
+}
+
+impl<T> IsSimple for LineString<T>
+    where T: CoordFloat
+{
+    fn is_simple(&self) -> bool {
+        let segments: Vec<Line<T>> = self.0
+            .windows(2)
+            .filter(|ps| ps[0] != ps[1])
+            .map(|ps| Line::new(ps[0], ps[1]))
+            .collect();
+        let closed = self.0.len() > 1 && self.0.first() == self.0.last();
+        let n = segments.len();
+        // consecutive segments always meet at their shared vertex
+        find_intersections(&segments).iter().all(|&(i, j, _)| {
+            (j == i + 1 && !doubles_back(&segments[i], &segments[j])) ||
+            (closed && i == 0 && j == n - 1 && !doubles_back(&segments[j], &segments[i]))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use super::*;
+
+    #[test]
+    fn bowtie_test() {
+        let p = |x, y| Point::new(x, y);
+        let bowtie = LineString(vec![p(0., 0.), p(2., 2.), p(2., 0.), p(0., 2.), p(0., 0.)]);
+        assert!(!bowtie.is_simple());
+    }
+    #[test]
+    fn convex_ring_test() {
+        let p = |x, y| Point::new(x, y);
+        let ring = LineString(vec![p(1., 0.), p(2., 1.), p(1., 2.), p(0., 1.), p(1., 0.)]);
+        assert!(ring.is_simple());
+    }
+    #[test]
+    fn open_linestring_test() {
+        let p = |x, y| Point::new(x, y);
+        assert!(LineString(vec![p(0., 0.), p(1., 0.), p(1., 1.), p(2., 1.)]).is_simple());
+        // returns to touch its own start
+        assert!(!LineString(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(1., 0.)]).is_simple());
+        assert!(LineString::<f64>(vec![]).is_simple());
+    }
+    #[test]
+    fn repeated_point_test() {
+        let p = |x, y| Point::new(x, y);
+        let ring = LineString(vec![p(0., 0.), p(1., 0.), p(1., 0.), p(1., 1.), p(0., 0.)]);
+        assert!(ring.is_simple());
+    }
+    #[test]
+    fn doubling_back_test() {
+        let p = |x, y| Point::new(x, y);
+        assert!(!LineString(vec![p(0., 0.), p(2., 0.), p(1., 0.)]).is_simple());
+        // carrying straight on is fine
+        assert!(LineString(vec![p(0., 0.), p(1., 0.), p(2., 0.)]).is_simple());
+        // the closing segment runs back along the first
+        assert!(!LineString(vec![p(0., 0.), p(2., 0.), p(2., 1.), p(1., 0.), p(0., 0.)]).is_simple());
+        assert!(!LineString(vec![p(0., 0.), p(1., 0.), p(0., 0.)]).is_simple());
+    }
+}
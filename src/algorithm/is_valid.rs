@@ -0,0 +1,84 @@
+use types::{CoordFloat, Polygon, MultiPolygon};
+use algorithm::is_simple::IsSimple;
+use algorithm::make_valid::any_overlap;
+
+pub trait IsValid {
+    /// Checks if a geometry is valid, to the extent `MakeValid` repairs it.
+    ///
+    /// A `Polygon` is valid if none of its rings cross themselves. A `MultiPolygon` is valid
+    /// if its members are, and no two members' filled areas overlap; members may touch
+    /// along their boundaries.
+    ///
+    /// ```
+    /// use geo::{Bbox, MultiPolygon};
+    /// use geo::algorithm::is_valid::IsValid;
+    /// use geo::algorithm::make_valid::MakeValid;
+    ///
+    /// let a = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 2. }.to_polygon();
+    /// let b = Bbox { xmin: 1., xmax: 3., ymin: 0., ymax: 2. }.to_polygon();
+    /// let mp = MultiPolygon(vec![a, b]);
+    ///
+    /// assert!(!mp.is_valid());
+    /// assert!(mp.make_valid().is_valid());
+    /// ```
+    fn is_valid(&self) -> bool;
+}
+
+impl<T> IsValid for Polygon<T>
+    where T: CoordFloat
+{
+    fn is_valid(&self) -> bool {
+        self.exterior.is_simple() && self.interiors.iter().all(|ring| ring.is_simple())
+    }
+}
+
+impl<T> IsValid for MultiPolygon<T>
+    where T: CoordFloat
+{
+    fn is_valid(&self) -> bool {
+        self.0.iter().all(|poly| poly.is_valid()) && !any_overlap(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPolygon, Bbox};
+    use algorithm::area::Area;
+    use algorithm::make_valid::MakeValid;
+    use super::*;
+
+    fn square(xmin: f64, ymin: f64, size: f64) -> Polygon<f64> {
+        Bbox { xmin, xmax: xmin + size, ymin, ymax: ymin + size }.to_polygon()
+    }
+
+    #[test]
+    fn overlapping_squares_test() {
+        let mp = MultiPolygon(vec![square(0., 0., 10.), square(5., 5., 10.)]);
+        assert!(!mp.is_valid());
+        let valid = mp.make_valid();
+        assert!(valid.is_valid());
+        assert_eq!(valid.area(), 100. + 100. - 25.);
+    }
+    #[test]
+    fn nested_and_identical_test() {
+        assert!(!MultiPolygon(vec![square(0., 0., 10.), square(2., 2., 1.)]).is_valid());
+        assert!(!MultiPolygon(vec![square(0., 0., 1.), square(0., 0., 1.)]).is_valid());
+    }
+    #[test]
+    fn valid_test() {
+        // disjoint, touching along an edge, and touching at a corner
+        let mp = MultiPolygon(vec![square(0., 0., 1.), square(5., 5., 1.), square(1., 0., 1.), square(2., 1., 1.)]);
+        assert!(mp.is_valid());
+        // an island inside another member's hole
+        let holed = Polygon::new(square(0., 0., 10.).exterior, vec![square(2., 2., 6.).exterior]);
+        assert!(MultiPolygon(vec![holed, square(4., 4., 2.)]).is_valid());
+        assert!(MultiPolygon::<f64>(vec![]).is_valid());
+    }
+    #[test]
+    fn bowtie_member_test() {
+        let p = |x, y| Point::new(x, y);
+        let bowtie = Polygon::new(LineString(vec![p(0., 0.), p(2., 2.), p(2., 0.), p(0., 2.), p(0., 0.)]), vec![]);
+        assert!(!bowtie.is_valid());
+        assert!(!MultiPolygon(vec![bowtie]).is_valid());
+    }
+}
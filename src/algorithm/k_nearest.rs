@@ -0,0 +1,58 @@
+use std::cmp::Ordering;
+use types::{CoordFloat, Point, MultiPoint};
+use algorithm::distance::Distance;
+
+/// Finds the `k` points of a `MultiPoint` nearest to `query`, returning their indices and
+/// distances, nearest first.
+///
+/// Points at exactly the same distance are returned in ascending order of index, so the
+/// result is deterministic. Fewer than `k` points are returned if there aren't enough.
+///
+/// ```
+/// use geo::{Point, MultiPoint};
+/// use geo::algorithm::k_nearest::k_nearest;
+///
+/// let points = MultiPoint(vec![Point::new(5., 0.), Point::new(0., 1.), Point::new(-1., 0.),
+///                              Point::new(3., 4.)]);
+///
+/// assert_eq!(k_nearest(&points, &Point::new(0., 0.), 3), vec![(1, 1.), (2, 1.), (0, 5.)]);
+/// ```
+pub fn k_nearest<T>(points: &MultiPoint<T>, query: &Point<T>, k: usize) -> Vec<(usize, T)>
+    where T: CoordFloat
+{
+    let mut nearest: Vec<(usize, T)> = points.0
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i, p.distance(query)))
+        .collect();
+    nearest.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal).then(a.0.cmp(&b.0)));
+    nearest.truncate(k);
+    nearest
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, MultiPoint};
+    use super::*;
+
+    #[test]
+    fn ties_test() {
+        // every point but the first is at distance 2 from the origin
+        let points = MultiPoint(vec![Point::new(1., 0.),
+                                     Point::new(0., -2.),
+                                     Point::new(2., 0.),
+                                     Point::new(-2., 0.),
+                                     Point::new(0., 2.)]);
+        let origin = Point::new(0., 0.);
+        assert_eq!(k_nearest(&points, &origin, 4), vec![(0, 1.), (1, 2.), (2, 2.), (3, 2.)]);
+        let mut reversed = points.clone();
+        reversed.0.reverse();
+        assert_eq!(k_nearest(&reversed, &origin, 3), vec![(4, 1.), (0, 2.), (1, 2.)]);
+    }
+    #[test]
+    fn too_few_test() {
+        let points = MultiPoint(vec![Point::new(1., 1.), Point::new(0., 0.)]);
+        assert_eq!(k_nearest(&points, &Point::new(0., 0.), 5).len(), 2);
+        assert!(k_nearest(&points, &Point::new(0., 0.), 0).is_empty());
+    }
+}
@@ -1,6 +1,4 @@
-use num_traits::Float;
-
-use types::{Line, LineString, MultiLineString};
+use types::{CoordFloat, Line, LineString, MultiLineString, Polygon};
 use algorithm::distance::Distance;
 
 /// Calculation of the length
@@ -24,7 +22,7 @@ pub trait Length<T, RHS = Self> {
 }
 
 impl<T> Length<T> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn length(&self) -> T {
         self.start.distance(&self.end)
@@ -32,7 +30,7 @@ impl<T> Length<T> for Line<T>
 }
 
 impl<T> Length<T> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn length(&self) -> T {
         self.0.windows(2)
@@ -41,16 +39,35 @@ impl<T> Length<T> for LineString<T>
 }
 
 impl<T> Length<T> for MultiLineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn length(&self) -> T {
         self.0.iter().fold(T::zero(), |total, line| total + line.length())
     }
 }
 
+impl<T> Polygon<T>
+    where T: CoordFloat
+{
+    /// The total length of the boundary of a Polygon, including its interior rings
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    ///
+    /// let exterior = LineString(vec![Point::new(0., 0.), Point::new(3., 0.), Point::new(3., 4.),
+    ///                                Point::new(0., 4.), Point::new(0., 0.)]);
+    /// let polygon = Polygon::new(exterior, vec![]);
+    ///
+    /// assert_eq!(polygon.perimeter(), 14.);
+    /// ```
+    pub fn perimeter(&self) -> T {
+        self.interiors.iter().fold(self.exterior.length(), |total, ring| total + ring.length())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use types::{Coordinate, Point, Line, LineString, MultiLineString};
+    use types::{Coordinate, Point, Line, LineString, MultiLineString, Polygon};
     use algorithm::length::Length;
 
     #[test]
@@ -83,4 +100,12 @@ mod test {
         assert_eq!(line0.length(), 1.);
         assert_eq!(line1.length(), 5.);
     }
+    #[test]
+    fn polygon_perimeter_test() {
+        let p = |x, y| Point::new(x, y);
+        let exterior = LineString(vec![p(0., 0.), p(3., 0.), p(3., 4.), p(0., 4.), p(0., 0.)]);
+        let hole = LineString(vec![p(1., 1.), p(1., 2.), p(2., 2.), p(2., 1.), p(1., 1.)]);
+        assert_eq!(Polygon::new(exterior.clone(), vec![]).perimeter(), 14.);
+        assert_eq!(Polygon::new(exterior, vec![hole]).perimeter(), 18.);
+    }
 }
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use types::{CoordFloat, Point, LineString, MultiLineString, HashablePoint};
+
+// the index of the node at `point`, adding it if it isn't there yet
+fn node_index<T>(nodes: &mut HashMap<HashablePoint<T>, usize>, point: Point<T>) -> usize
+    where T: CoordFloat
+{
+    let next = nodes.len();
+    *nodes.entry(HashablePoint(point)).or_insert(next)
+}
+
+// the edges of the graph: each input line, and the indices of its start and end nodes
+struct Graph<'a, T>
+    where T: CoordFloat + 'a
+{
+    edges: Vec<(&'a LineString<T>, usize, usize)>,
+    // the edges incident to each node
+    incident: Vec<Vec<usize>>,
+}
+
+impl<'a, T> Graph<'a, T>
+    where T: CoordFloat
+{
+    fn new(lines: &'a MultiLineString<T>) -> Graph<'a, T> {
+        let mut nodes = HashMap::new();
+        let mut edges: Vec<(&LineString<T>, usize, usize)> = vec![];
+        // the edges between each pair of nodes, as the only ones a line could duplicate
+        let mut between: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for line in lines.0.iter().filter(|line| line.0.len() > 1) {
+            let start = node_index(&mut nodes, line.0[0]);
+            let end = node_index(&mut nodes, line.0[line.0.len() - 1]);
+            let parallel = between.entry((start.min(end), start.max(end))).or_default();
+            // drop lines which duplicate an earlier line, in either direction
+            let duplicate = parallel.iter().any(|&idx| {
+                let other = edges[idx].0;
+                other.0 == line.0 || other.0.iter().rev().eq(line.0.iter())
+            });
+            if !duplicate {
+                parallel.push(edges.len());
+                edges.push((line, start, end));
+            }
+        }
+        let mut incident = vec![vec![]; nodes.len()];
+        for (idx, &(_, start, end)) in edges.iter().enumerate() {
+            incident[start].push(idx);
+            incident[end].push(idx);
+        }
+        Graph { edges, incident }
+    }
+
+    // follow the chain of edges leaving `node` along `edge`, through nodes of degree 2
+    fn walk(&self, mut node: usize, mut edge: usize, visited: &mut [bool]) -> LineString<T> {
+        let mut points: Vec<Point<T>> = vec![];
+        loop {
+            visited[edge] = true;
+            let (line, start, end) = self.edges[edge];
+            // the first point of each edge after the first repeats the last point so far
+            let skip = if points.is_empty() { 0 } else { 1 };
+            if start == node {
+                points.extend(line.0.iter().skip(skip));
+                node = end;
+            } else {
+                points.extend(line.0.iter().rev().skip(skip));
+                node = start;
+            }
+            if self.incident[node].len() != 2 {
+                break;
+            }
+            match self.incident[node].iter().find(|&&e| !visited[e]) {
+                Some(&e) => edge = e,
+                None => break,
+            }
+        }
+        LineString(points)
+    }
+}
+
+/// Joins lines which share endpoints into the longest possible chains, reversing lines
+/// as needed.
+///
+/// Chains only continue through points where exactly two lines meet; a point shared by three
+/// or more lines (a branch) ends every chain reaching it. Lines with fewer than two points, and
+/// lines duplicating an earlier line in either direction, are dropped.
+///
+/// ```
+/// use geo::{Point, LineString, MultiLineString};
+/// use geo::algorithm::line_merge::line_merge;
+///
+/// let a = LineString(vec![Point::new(0., 0.), Point::new(1., 0.)]);
+/// let b = LineString(vec![Point::new(2., 0.), Point::new(1., 0.)]);
+/// let merged = line_merge(&MultiLineString(vec![a, b]));
+///
+/// assert_eq!(merged.0, vec![LineString(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(2., 0.)])]);
+/// ```
+pub fn line_merge<T: CoordFloat>(lines: &MultiLineString<T>) -> MultiLineString<T> {
+    let graph = Graph::new(lines);
+    let mut visited = vec![false; graph.edges.len()];
+    let mut merged = vec![];
+    // start from the ends and branch points of the network
+    for (node, incident) in graph.incident.iter().enumerate() {
+        if incident.len() == 2 {
+            continue;
+        }
+        for &edge in incident {
+            if !visited[edge] {
+                merged.push(graph.walk(node, edge, &mut visited));
+            }
+        }
+    }
+    // anything left over forms closed loops
+    for edge in 0..graph.edges.len() {
+        if !visited[edge] {
+            let start = graph.edges[edge].1;
+            merged.push(graph.walk(start, edge, &mut visited));
+        }
+    }
+    MultiLineString(merged)
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, MultiLineString};
+    use super::*;
+
+    #[test]
+    fn end_to_end_test() {
+        let p = |x, y| Point::new(x, y);
+        let lines = MultiLineString(vec![LineString(vec![p(1., 0.), p(2., 1.)]),
+                                         // reversed relative to its neighbours
+                                         LineString(vec![p(3., 0.), p(2., 1.)]),
+                                         LineString(vec![p(0., 0.), p(0.5, 0.5), p(1., 0.)])]);
+        let merged = line_merge(&lines);
+        assert_eq!(merged.0.len(), 1);
+        let chain = &merged.0[0].0;
+        assert_eq!(chain.len(), 5);
+        let forwards = vec![p(0., 0.), p(0.5, 0.5), p(1., 0.), p(2., 1.), p(3., 0.)];
+        let backwards: Vec<Point<f64>> = forwards.iter().rev().cloned().collect();
+        assert!(*chain == forwards || *chain == backwards);
+    }
+    #[test]
+    fn y_junction_test() {
+        let p = |x, y| Point::new(x, y);
+        let lines = MultiLineString(vec![LineString(vec![p(0., 0.), p(0., 1.)]),
+                                         LineString(vec![p(0., 1.), p(-1., 2.)]),
+                                         LineString(vec![p(0., 1.), p(1., 2.)]),
+                                         // extends one arm of the junction
+                                         LineString(vec![p(1., 2.), p(2., 2.)])]);
+        let merged = line_merge(&lines);
+        assert_eq!(merged.0.len(), 3);
+        let mut lengths: Vec<usize> = merged.0.iter().map(|ls| ls.0.len()).collect();
+        lengths.sort();
+        assert_eq!(lengths, vec![2, 2, 3]);
+        // every chain ends at the branch point
+        assert!(merged.0.iter().all(|ls| ls.0.first() == Some(&p(0., 1.)) || ls.0.last() == Some(&p(0., 1.))));
+    }
+    #[test]
+    fn loop_and_duplicates_test() {
+        let p = |x, y| Point::new(x, y);
+        let lines = MultiLineString(vec![LineString(vec![p(0., 0.), p(1., 0.)]),
+                                         LineString(vec![p(1., 0.), p(1., 1.)]),
+                                         LineString(vec![p(1., 1.), p(0., 0.)]),
+                                         LineString(vec![p(1., 0.), p(0., 0.)]),
+                                         LineString(vec![p(5., 5.)])]);
+        let merged = line_merge(&lines);
+        assert_eq!(merged.0.len(), 1);
+        let ring = &merged.0[0].0;
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring.first(), ring.last());
+    }
+}
@@ -0,0 +1,460 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use types::{CoordFloat, Point, LineString, Polygon, MultiPolygon, HashablePoint};
+use algorithm::area::Area;
+use algorithm::contains::Contains;
+use algorithm::intersects::Intersects;
+use algorithm::orient::Winding;
+use algorithm::sweep::find_intersections;
+
+// the point where the segments p1-p2 and p3-p4 cross, if they do
+fn segment_crossing<T>(p1: Point<T>, p2: Point<T>, p3: Point<T>, p4: Point<T>) -> Option<Point<T>>
+    where T: CoordFloat
+{
+    let (d1, d2) = (p2 - p1, p4 - p3);
+    let denom = d1.x() * d2.y() - d1.y() * d2.x();
+    // parallel or collinear segments don't have a single crossing point
+    if denom == T::zero() {
+        return None;
+    }
+    let diff = p3 - p1;
+    let t = (diff.x() * d2.y() - diff.y() * d2.x()) / denom;
+    let u = (diff.x() * d1.y() - diff.y() * d1.x()) / denom;
+    if t < T::zero() || t > T::one() || u < T::zero() || u > T::one() {
+        return None;
+    }
+    Some(Point::new(p1.x() + t * d1.x(), p1.y() + t * d1.y()))
+}
+
+// close a list of vertices into a ring, dropping repeated points
+fn close_ring<T>(mut vertices: Vec<Point<T>>) -> LineString<T>
+    where T: CoordFloat
+{
+    vertices.dedup();
+    while vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    if let Some(&first) = vertices.first() {
+        vertices.push(first);
+    }
+    LineString(vertices)
+}
+
+// split a ring at its first self-intersection, recursing until each piece is simple
+fn split_ring<T>(ring: LineString<T>, output: &mut Vec<LineString<T>>)
+    where T: CoordFloat
+{
+    // a ring needs at least three distinct vertices to enclose an area
+    if ring.0.len() < 4 {
+        return;
+    }
+    let vertices = &ring.0[..ring.0.len() - 1];
+    let n = vertices.len();
+    let segment = |i: usize| (vertices[i], vertices[(i + 1) % n]);
+    for i in 0..n {
+        for j in (i + 2)..n {
+            // the first and last segments are adjacent
+            if i == 0 && j == n - 1 {
+                continue;
+            }
+            let ((p1, p2), (p3, p4)) = (segment(i), segment(j));
+            if let Some(crossing) = segment_crossing(p1, p2, p3, p4) {
+                // the loop between the two segments
+                let mut inner = vec![crossing];
+                inner.extend_from_slice(&vertices[i + 1..j + 1]);
+                // and the rest of the ring
+                let mut outer = vertices[..i + 1].to_vec();
+                outer.push(crossing);
+                outer.extend_from_slice(&vertices[j + 1..]);
+                let (inner, outer) = (close_ring(inner), close_ring(outer));
+                // touching at a vertex isn't a crossing; don't split off an empty piece
+                if inner.0.len() >= 4 && outer.0.len() >= 4 {
+                    split_ring(inner, output);
+                    split_ring(outer, output);
+                    return;
+                }
+            }
+        }
+    }
+    if Polygon::new(ring.clone(), vec![]).area() != T::zero() {
+        output.push(ring);
+    }
+}
+
+fn simple_rings<T>(ring: &LineString<T>) -> Vec<LineString<T>>
+    where T: CoordFloat
+{
+    let mut rings = vec![];
+    split_ring(close_ring(ring.0.clone()), &mut rings);
+    rings
+}
+
+// A piece of an edge of one of the polygons being overlaid, between the points where it
+// meets the other edges. Rings are oriented so the polygon's filled side is on the left.
+struct Piece<T>
+    where T: CoordFloat
+{
+    start: Point<T>,
+    end: Point<T>,
+    owner: usize,
+}
+
+impl<T> Piece<T>
+    where T: CoordFloat
+{
+    fn midpoint(&self) -> Point<T> {
+        let half = T::from(0.5).unwrap();
+        Point::new(self.start.x() + (self.end.x() - self.start.x()) * half,
+                   self.start.y() + (self.end.y() - self.start.y()) * half)
+    }
+}
+
+// For each piece, whether a polygon other than its owner fills its left side, and whether
+// any polygon fills its right side.
+// Pieces are split wherever edges meet, so a polygon's boundary either runs along the whole
+// of a piece, as a piece of its own in one direction or the other, or doesn't touch its
+// middle at all. In the first case the direction tells which side that polygon fills, and in
+// the second both sides are filled or neither is, whatever the scale of the geometry.
+fn side_fills<T>(pieces: &[Piece<T>], polygons: &[Polygon<T>]) -> Vec<(bool, bool)>
+    where T: CoordFloat
+{
+    let mut owners: HashMap<(HashablePoint<T>, HashablePoint<T>), Vec<usize>> = HashMap::new();
+    for piece in pieces {
+        owners.entry((HashablePoint(piece.start), HashablePoint(piece.end))).or_default().push(piece.owner);
+    }
+    let none = vec![];
+    pieces.iter().map(|piece| {
+        let along = owners.get(&(HashablePoint(piece.start), HashablePoint(piece.end))).unwrap_or(&none);
+        let against = owners.get(&(HashablePoint(piece.end), HashablePoint(piece.start))).unwrap_or(&none);
+        let mid = piece.midpoint();
+        let (mut left, mut right) = (false, false);
+        for (i, poly) in polygons.iter().enumerate() {
+            let (is_along, is_against) = (along.contains(&i), against.contains(&i));
+            let inside = !is_along && !is_against && poly.contains(&mid);
+            left |= i != piece.owner && (is_along || inside);
+            right |= is_against || inside;
+        }
+        (left, right)
+    }).collect()
+}
+
+// split the edges of every ring of the polygons wherever they meet
+fn overlay_pieces<T>(polygons: &[Polygon<T>]) -> Vec<Piece<T>>
+    where T: CoordFloat
+{
+    let mut segments = vec![];
+    let mut owners = vec![];
+    for (owner, poly) in polygons.iter().enumerate() {
+        let mut exterior = poly.exterior.clone();
+        exterior.make_ccw_winding();
+        let mut rings = vec![exterior];
+        for ring in &poly.interiors {
+            let mut ring = ring.clone();
+            ring.make_cw_winding();
+            rings.push(ring);
+        }
+        for ring in rings {
+            for line in ring.lines().filter(|line| line.start != line.end) {
+                segments.push(line);
+                owners.push(owner);
+            }
+        }
+    }
+    let mut splits: Vec<Vec<Point<T>>> = vec![vec![]; segments.len()];
+    for (i, j, crossing) in find_intersections(&segments) {
+        let (a, b) = (&segments[i], &segments[j]);
+        // where the segments touch or overlap, split at their exact end points
+        let mut touching = false;
+        for &(p, other, k) in &[(a.start, b, j), (a.end, b, j), (b.start, a, i), (b.end, a, i)] {
+            if other.intersects(&p) {
+                splits[k].push(p);
+                touching = true;
+            }
+        }
+        if !touching {
+            splits[i].push(crossing);
+            splits[j].push(crossing);
+        }
+    }
+    let mut pieces = vec![];
+    for ((segment, mut points), owner) in segments.into_iter().zip(splits).zip(owners) {
+        let along = |p: &Point<T>| (*p - segment.start).dot(&(segment.end - segment.start));
+        points.push(segment.start);
+        points.push(segment.end);
+        points.sort_by(|p, q| along(p).partial_cmp(&along(q)).unwrap_or(Ordering::Equal));
+        points.dedup();
+        for pair in points.windows(2) {
+            pieces.push(Piece { start: pair[0], end: pair[1], owner });
+        }
+    }
+    pieces
+}
+
+// do the filled areas of any two of the polygons overlap?
+pub(crate) fn any_overlap<T>(polygons: &[Polygon<T>]) -> bool
+    where T: CoordFloat
+{
+    // the boundary of any overlap is made of pieces with the overlap on their left
+    let pieces = overlay_pieces(polygons);
+    side_fills(&pieces, polygons).into_iter().any(|(left, _)| left)
+}
+
+// the clockwise angle from direction a to direction b, in [0, 2π)
+fn clockwise_angle<T>(a: Point<T>, b: Point<T>) -> T
+    where T: CoordFloat
+{
+    let angle = (a.x() * b.y() - a.y() * b.x()).atan2(a.dot(&b));
+    let full_turn = T::from(2. * ::std::f64::consts::PI).unwrap();
+    if angle > T::zero() { full_turn - angle } else { -angle }
+}
+
+// the union of the polygons, with exterior rings counter-clockwise and interior rings clockwise
+fn union<T>(polygons: &[Polygon<T>]) -> Vec<Polygon<T>>
+    where T: CoordFloat
+{
+    // keep the pieces of the boundary with nothing filled on their right, once each
+    let mut boundary: Vec<Piece<T>> = vec![];
+    let pieces = overlay_pieces(polygons);
+    let fills = side_fills(&pieces, polygons);
+    for (piece, (_, right)) in pieces.into_iter().zip(fills) {
+        if !right &&
+           !boundary.iter().any(|b| b.start == piece.start && b.end == piece.end) {
+            boundary.push(piece);
+        }
+    }
+    let mut outgoing: HashMap<HashablePoint<T>, Vec<usize>> = HashMap::new();
+    for (i, piece) in boundary.iter().enumerate() {
+        outgoing.entry(HashablePoint(piece.start)).or_default().push(i);
+    }
+    // walk the pieces into rings, at each vertex taking the first piece clockwise from the
+    // way we came, which keeps the filled area on the left
+    let mut used = vec![false; boundary.len()];
+    let mut shells = vec![];
+    let mut holes = vec![];
+    for first in 0..boundary.len() {
+        if used[first] {
+            continue;
+        }
+        let mut ring = vec![boundary[first].start];
+        let mut current = first;
+        loop {
+            used[current] = true;
+            let (start, end) = (boundary[current].start, boundary[current].end);
+            ring.push(end);
+            if end == ring[0] {
+                break;
+            }
+            let back = start - end;
+            let next = outgoing.get(&HashablePoint(end)).and_then(|candidates| {
+                candidates
+                    .iter()
+                    .cloned()
+                    .filter(|&i| !used[i])
+                    .min_by(|&i, &j| {
+                        let angle = |k: usize| clockwise_angle(back, boundary[k].end - end);
+                        angle(i).partial_cmp(&angle(j)).unwrap_or(Ordering::Equal)
+                    })
+            });
+            match next {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+        let ring = close_ring(ring);
+        let area = Polygon::new(ring.clone(), vec![]).area();
+        if area > T::zero() {
+            shells.push(Polygon::new(ring, vec![]));
+        } else if area < T::zero() {
+            holes.push(ring);
+        }
+    }
+    for hole in holes {
+        let owner = shells
+            .iter_mut()
+            .filter(|poly| hole.0.iter().all(|p| poly.exterior.contains(p) || poly.contains(p)))
+            .min_by(|a, b| a.area().partial_cmp(&b.area()).unwrap_or(Ordering::Equal));
+        if let Some(poly) = owner {
+            poly.interiors.push(hole);
+        }
+    }
+    shells
+}
+
+pub trait MakeValid<T> {
+    /// Repairs a polygon with self-intersecting rings, returning valid polygons covering the
+    /// same area.
+    ///
+    /// Each ring is split at the points where it crosses itself, and every resulting loop
+    /// becomes a separate polygon. Interior rings are repaired the same way, and each piece is
+    /// assigned to the polygon that contains it. Exterior rings are wound counter-clockwise,
+    /// interior rings clockwise. Loops with zero area are dropped.
+    ///
+    /// This is a best-effort repair: loops nested inside other loops of the same ring are
+    /// returned as separate, overlapping polygons rather than as holes.
+    ///
+    /// For a `MultiPolygon`, each member is repaired, and then members whose filled areas
+    /// overlap are replaced by their union.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::make_valid::MakeValid;
+    ///
+    /// // a "bowtie", crossing itself at (1, 1)
+    /// let p = |x, y| Point::new(x, y);
+    /// let bowtie = Polygon::new(LineString(vec![p(0., 0.), p(2., 2.), p(2., 0.), p(0., 2.), p(0., 0.)]), vec![]);
+    ///
+    /// let valid = bowtie.make_valid();
+    /// assert_eq!(valid.0.len(), 2);
+    /// assert_eq!(valid.area(), 2.);
+    /// ```
+    fn make_valid(&self) -> MultiPolygon<T> where T: CoordFloat;
+}
+
+impl<T> MakeValid<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn make_valid(&self) -> MultiPolygon<T> {
+        let mut polygons: Vec<Polygon<T>> = simple_rings(&self.exterior)
+            .into_iter()
+            .map(|mut ring| {
+                ring.make_ccw_winding();
+                Polygon::new(ring, vec![])
+            })
+            .collect();
+        for mut hole in self.interiors.iter().flat_map(|ring| simple_rings(ring)) {
+            hole.make_cw_winding();
+            let owner = polygons
+                .iter_mut()
+                .find(|poly| hole.0.iter().all(|p| poly.exterior.contains(p) || poly.contains(p)));
+            if let Some(poly) = owner {
+                poly.interiors.push(hole);
+            }
+        }
+        MultiPolygon(polygons)
+    }
+}
+
+impl<T> MakeValid<T> for MultiPolygon<T>
+    where T: CoordFloat
+{
+    fn make_valid(&self) -> MultiPolygon<T> {
+        let repaired: Vec<Polygon<T>> = self.0.iter().flat_map(|poly| poly.make_valid().0).collect();
+        // group the members that overlap, directly or through others
+        let n = repaired.len();
+        let mut group: Vec<usize> = (0..n).collect();
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if group[i] != group[j] && any_overlap(&[repaired[i].clone(), repaired[j].clone()]) {
+                    let (from, to) = (group[j], group[i]);
+                    for g in group.iter_mut().filter(|g| **g == from) {
+                        *g = to;
+                    }
+                }
+            }
+        }
+        let mut polygons = vec![];
+        for i in 0..n {
+            let members: Vec<Polygon<T>> = (0..n)
+                .filter(|&j| group[j] == i)
+                .map(|j| repaired[j].clone())
+                .collect();
+            match members.len() {
+                0 => {}
+                1 => polygons.extend(members),
+                _ => polygons.extend(union(&members)),
+            }
+        }
+        MultiPolygon(polygons)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPolygon, Bbox};
+    use algorithm::area::Area;
+    use super::*;
+
+    #[test]
+    fn bowtie_test() {
+        let p = |x, y| Point::new(x, y);
+        let bowtie = Polygon::new(LineString(vec![p(0., 0.), p(4., 4.), p(4., 0.), p(0., 4.), p(0., 0.)]),
+                                  vec![]);
+        let valid = bowtie.make_valid();
+        assert_eq!(valid.0.len(), 2);
+        // each lobe is a triangle with base 4 and height 2
+        for poly in &valid.0 {
+            assert_eq!(poly.exterior.0.len(), 4);
+            assert_eq!(poly.area(), 4.);
+        }
+        assert_eq!(valid.area(), 8.);
+        assert!(valid.0.iter().any(|poly| poly.exterior.0.contains(&p(0., 0.))));
+        assert!(valid.0.iter().any(|poly| poly.exterior.0.contains(&p(4., 0.))));
+    }
+    #[test]
+    fn valid_polygon_unchanged_test() {
+        let p = |x, y| Point::new(x, y);
+        let exterior = LineString(vec![p(0., 0.), p(10., 0.), p(10., 10.), p(0., 10.), p(0., 0.)]);
+        let interior = LineString(vec![p(2., 2.), p(2., 4.), p(4., 4.), p(4., 2.), p(2., 2.)]);
+        let poly = Polygon::new(exterior, vec![interior]);
+        assert_eq!(poly.make_valid(), MultiPolygon(vec![poly]));
+    }
+    #[test]
+    fn hole_assigned_to_lobe_test() {
+        let p = |x, y| Point::new(x, y);
+        let bowtie = LineString(vec![p(0., 0.), p(4., 4.), p(4., 0.), p(0., 4.), p(0., 0.)]);
+        let hole = LineString(vec![p(3., 1.5), p(3.5, 1.5), p(3.5, 2.5), p(3., 1.5)]);
+        let valid = Polygon::new(bowtie, vec![hole]).make_valid();
+        assert_eq!(valid.0.len(), 2);
+        let holes: usize = valid.0.iter().map(|poly| poly.interiors.len()).sum();
+        assert_eq!(holes, 1);
+        let lobe = valid.0.iter().find(|poly| !poly.interiors.is_empty()).unwrap();
+        assert!(lobe.exterior.0.contains(&p(4., 0.)));
+    }
+    #[test]
+    fn degenerate_ring_test() {
+        let p = |x, y| Point::new(x, y);
+        let line = Polygon::new(LineString(vec![p(0., 0.), p(1., 1.), p(0., 0.)]), vec![]);
+        assert!(line.make_valid().0.is_empty());
+    }
+    #[test]
+    fn overlapping_squares_test() {
+        let a = Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 4. }.to_polygon();
+        let b = Bbox { xmin: 2., xmax: 6., ymin: 2., ymax: 6. }.to_polygon();
+        let valid = MultiPolygon(vec![a, b]).make_valid();
+        assert_eq!(valid.0.len(), 1);
+        // an L-shaped octagon, minus the doubled overlap
+        assert_eq!(valid.0[0].exterior.0.len(), 9);
+        assert_eq!(valid.area(), 16. + 16. - 4.);
+    }
+    #[test]
+    fn union_with_hole_test() {
+        // a ring of four overlapping rectangles around a square hole
+        let r = |xmin, xmax, ymin, ymax| Bbox { xmin, xmax, ymin, ymax }.to_polygon();
+        let frame = MultiPolygon(vec![r(0., 6., 0., 2.), r(4., 6., 0., 6.), r(0., 6., 4., 6.), r(0., 2., 0., 6.),
+                                      r(10., 11., 0., 1.)]);
+        let valid = frame.make_valid();
+        assert_eq!(valid.0.len(), 2);
+        assert_eq!(valid.0[0].interiors.len(), 1);
+        assert_eq!(Polygon::new(valid.0[0].exterior.clone(), vec![]).area(), 36.);
+        assert_eq!(Polygon::new(valid.0[0].interiors[0].clone(), vec![]).area(), -4.);
+        assert_eq!(valid.0[1], r(10., 11., 0., 1.));
+    }
+    #[test]
+    fn touching_squares_unchanged_test() {
+        let a = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 2. }.to_polygon();
+        let b = Bbox { xmin: 2., xmax: 4., ymin: 0., ymax: 2. }.to_polygon();
+        let mp = MultiPolygon(vec![a, b]);
+        assert_eq!(mp.make_valid(), mp);
+    }
+    #[test]
+    fn long_close_disjoint_test() {
+        // much longer than the gap between them
+        let a = Bbox { xmin: 0., xmax: 1e6, ymin: 0., ymax: 0.5 }.to_polygon();
+        let b = Bbox { xmin: 0., xmax: 1e6, ymin: 0.6, ymax: 3. }.to_polygon();
+        let mp = MultiPolygon(vec![a, b]);
+        assert!(!any_overlap(&mp.0));
+        assert_eq!(mp.make_valid(), mp);
+        assert_eq!(mp.make_valid().area(), 2.9e6);
+    }
+}
@@ -0,0 +1,197 @@
+use types::{CoordFloat, Coordinate, Point, Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon,
+            GeometryCollection, Geometry};
+
+pub trait MapCoordsInplace<T: CoordFloat> {
+    /// Applies a function to every `Coordinate` of a geometry, replacing it in place.
+    ///
+    /// ```
+    /// use geo::{Coordinate, Point, LineString};
+    /// use geo::algorithm::map_coords::MapCoordsInplace;
+    ///
+    /// let mut ls = LineString(vec![Point::new(1., 2.), Point::new(3., 4.)]);
+    /// ls.map_coords_inplace(|c| Coordinate { x: c.y, y: c.x });
+    ///
+    /// assert_eq!(ls, LineString(vec![Point::new(2., 1.), Point::new(4., 3.)]));
+    /// ```
+    fn map_coords_inplace<F>(&mut self, f: F) where F: Fn(&Coordinate<T>) -> Coordinate<T>;
+}
+
+impl<T> MapCoordsInplace<T> for Point<T>
+    where T: CoordFloat
+{
+    fn map_coords_inplace<F>(&mut self, f: F) where F: Fn(&Coordinate<T>) -> Coordinate<T> {
+        self.0 = f(&self.0);
+    }
+}
+
+impl<T> MapCoordsInplace<T> for Line<T>
+    where T: CoordFloat
+{
+    fn map_coords_inplace<F>(&mut self, f: F) where F: Fn(&Coordinate<T>) -> Coordinate<T> {
+        self.start.0 = f(&self.start.0);
+        self.end.0 = f(&self.end.0);
+    }
+}
+
+impl<T> MapCoordsInplace<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn map_coords_inplace<F>(&mut self, f: F) where F: Fn(&Coordinate<T>) -> Coordinate<T> {
+        for p in &mut self.0 {
+            p.0 = f(&p.0);
+        }
+    }
+}
+
+impl<T> MapCoordsInplace<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn map_coords_inplace<F>(&mut self, f: F) where F: Fn(&Coordinate<T>) -> Coordinate<T> {
+        self.exterior.map_coords_inplace(&f);
+        for ring in &mut self.interiors {
+            ring.map_coords_inplace(&f);
+        }
+    }
+}
+
+impl<T> MapCoordsInplace<T> for MultiPoint<T>
+    where T: CoordFloat
+{
+    fn map_coords_inplace<F>(&mut self, f: F) where F: Fn(&Coordinate<T>) -> Coordinate<T> {
+        for p in &mut self.0 {
+            p.map_coords_inplace(&f);
+        }
+    }
+}
+
+impl<T> MapCoordsInplace<T> for MultiLineString<T>
+    where T: CoordFloat
+{
+    fn map_coords_inplace<F>(&mut self, f: F) where F: Fn(&Coordinate<T>) -> Coordinate<T> {
+        for ls in &mut self.0 {
+            ls.map_coords_inplace(&f);
+        }
+    }
+}
+
+impl<T> MapCoordsInplace<T> for MultiPolygon<T>
+    where T: CoordFloat
+{
+    fn map_coords_inplace<F>(&mut self, f: F) where F: Fn(&Coordinate<T>) -> Coordinate<T> {
+        for poly in &mut self.0 {
+            poly.map_coords_inplace(&f);
+        }
+    }
+}
+
+impl<T> MapCoordsInplace<T> for GeometryCollection<T>
+    where T: CoordFloat
+{
+    fn map_coords_inplace<F>(&mut self, f: F) where F: Fn(&Coordinate<T>) -> Coordinate<T> {
+        // a trait object, so that nested collections don't instantiate this without end
+        let f: &dyn Fn(&Coordinate<T>) -> Coordinate<T> = &f;
+        for geom in &mut self.0 {
+            geom.map_coords_inplace(f);
+        }
+    }
+}
+
+impl<T> MapCoordsInplace<T> for Geometry<T>
+    where T: CoordFloat
+{
+    fn map_coords_inplace<F>(&mut self, f: F) where F: Fn(&Coordinate<T>) -> Coordinate<T> {
+        match *self {
+            Geometry::Point(ref mut g) => g.map_coords_inplace(f),
+            Geometry::LineString(ref mut g) => g.map_coords_inplace(f),
+            Geometry::Polygon(ref mut g) => g.map_coords_inplace(f),
+            Geometry::MultiPoint(ref mut g) => g.map_coords_inplace(f),
+            Geometry::MultiLineString(ref mut g) => g.map_coords_inplace(f),
+            Geometry::MultiPolygon(ref mut g) => g.map_coords_inplace(f),
+            Geometry::GeometryCollection(ref mut g) => g.map_coords_inplace(f),
+        }
+    }
+}
+
+pub trait AngleUnitsInplace<T: CoordFloat> {
+    /// Converts every coordinate of a geometry from degrees to radians, in place.
+    ///
+    /// This lets trigonometry-heavy code convert a geometry once, rather than on every use.
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::map_coords::AngleUnitsInplace;
+    ///
+    /// let mut ls = LineString(vec![Point::new(180., 90.)]);
+    /// ls.to_radians_inplace();
+    ///
+    /// assert_eq!(ls.0[0], Point::new(PI, PI / 2.));
+    /// ```
+    fn to_radians_inplace(&mut self);
+
+    /// Converts every coordinate of a geometry from radians to degrees, in place.
+    fn to_degrees_inplace(&mut self);
+}
+
+impl<T, G> AngleUnitsInplace<T> for G
+    where T: CoordFloat,
+          G: MapCoordsInplace<T>
+{
+    fn to_radians_inplace(&mut self) {
+        self.map_coords_inplace(|c| Coordinate { x: c.x.to_radians(), y: c.y.to_radians() });
+    }
+
+    fn to_degrees_inplace(&mut self) {
+        self.map_coords_inplace(|c| Coordinate { x: c.x.to_degrees(), y: c.y.to_degrees() });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPolygon, GeometryCollection, Geometry};
+    use super::*;
+
+    #[test]
+    fn linestring_round_trip_test() {
+        let original = LineString(vec![Point::new(-122.42, 37.77), Point::new(151.21, -33.87),
+                                       Point::new(0., 89.9)]);
+        let mut ls = original.clone();
+        ls.to_radians_inplace();
+        assert_relative_eq!(ls.0[0].x(), -2.1366, epsilon = 1e-4);
+        assert_relative_eq!(ls.0[2].y(), 1.5691, epsilon = 1e-4);
+        ls.to_degrees_inplace();
+        for (p, q) in ls.0.iter().zip(&original.0) {
+            assert_relative_eq!(p.x(), q.x(), epsilon = 1e-12);
+            assert_relative_eq!(p.y(), q.y(), epsilon = 1e-12);
+        }
+    }
+    #[test]
+    fn polygon_test() {
+        let ring = |d: f64| LineString(vec![Point::new(0., 0.), Point::new(d, 0.), Point::new(d, d),
+                                           Point::new(0., 0.)]);
+        let mut mp = MultiPolygon(vec![Polygon::new(ring(90.), vec![ring(45.)])]);
+        mp.to_radians_inplace();
+        assert_relative_eq!(mp.0[0].exterior.0[2].y(), ::std::f64::consts::FRAC_PI_2);
+        assert_relative_eq!(mp.0[0].interiors[0].0[1].x(), ::std::f64::consts::FRAC_PI_4);
+    }
+    #[test]
+    fn map_coords_inplace_test() {
+        let mut p = Point::new(1., 2.);
+        p.map_coords_inplace(|c| Coordinate { x: c.x * 10., y: c.y - 1. });
+        assert_eq!(p, Point::new(10., 1.));
+    }
+    #[test]
+    fn geometry_collection_test() {
+        let nested = GeometryCollection(vec![Geometry::Point(Point::new(1., 2.))]);
+        let mut gc = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::LineString(LineString(vec![Point::new(0., 0.), Point::new(3., 4.)])),
+            Geometry::GeometryCollection(nested),
+        ]));
+        gc.map_coords_inplace(|c| Coordinate { x: c.x + 1., y: -c.y });
+        let expected = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::LineString(LineString(vec![Point::new(1., 0.), Point::new(4., -4.)])),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Point(Point::new(2., -2.))])),
+        ]));
+        assert_eq!(gc, expected);
+    }
+}
@@ -1,3 +1,5 @@
+/// Geometric predicates shared by the algorithms.
+pub mod util;
 /// Calculation of the centroid.
 pub mod centroid;
 /// Checks if the geometry A is completely inside the B geometry.
@@ -14,6 +16,10 @@ pub mod distance;
 pub mod haversine_destination;
 /// Returns the Haversine distance between two geometries.
 pub mod haversine_distance;
+/// Returns the cross-track and along-track distances of a point from a great-circle path.
+pub mod cross_track_distance;
+/// Returns the point on a geometry nearest to a given point, on the sphere.
+pub mod haversine_closest_point;
 /// Returns the Bbox of a geometry.
 pub mod boundingbox;
 /// Simplifies a `LineString` using the Ramer-Douglas-Peucker algorithm.
@@ -30,3 +36,81 @@ pub mod extremes;
 pub mod rotate;
 /// Translates a geometry along the given offsets.
 pub mod translate;
+/// Clips geometries to a bounding box.
+pub mod clip;
+/// Snaps the coordinates of a geometry to a regular grid.
+pub mod snap;
+/// Returns the turning angles at the vertices of a `LineString`.
+pub mod vertex_angles;
+/// Iterates over the coordinates of a geometry.
+pub mod coords_iter;
+/// Adds points along the segments of a geometry.
+pub mod densify;
+/// Rounds the coordinates of a geometry to a number of decimal places.
+pub mod reduce_precision;
+/// Checks if a geometry intersects itself.
+pub mod is_simple;
+/// Compares geometries independently of ring start and direction.
+pub mod equality;
+/// Resamples a LineString to evenly-spaced points.
+pub mod resample;
+/// Repairs self-intersecting polygons.
+pub mod make_valid;
+/// Measures and travels along rhumb lines (constant bearing).
+pub mod rhumb;
+/// Matches points to the polygons containing them.
+pub mod spatial_join;
+/// Estimates the density of points over a grid.
+pub mod density;
+/// Computes the smallest circle enclosing a geometry.
+pub mod bounding_circle;
+/// Merges lines sharing endpoints into longer chains.
+pub mod line_merge;
+/// Projects a point onto a LineString, for linear referencing.
+pub mod project_point;
+/// Calculates centroids on the surface of a sphere.
+pub mod geodesic_centroid;
+/// Approximates the area of geographic polygons in a local projection.
+pub mod projected_area;
+/// Shrinks polygons inwards by a distance.
+pub mod shrink;
+/// Finds the intersections among many line segments with a Bentley–Ottmann sweep.
+pub mod sweep;
+/// Samples smooth splines through the points of linestrings.
+pub mod interpolate_spline;
+/// Signed distances from points to the boundaries of polygons.
+pub mod signed_distance;
+/// Finds the closest pair among a set of points.
+pub mod closest_pair;
+/// Finds the points of a set nearest to a query point.
+pub mod k_nearest;
+/// Measures the similarity of two linestrings by their Fréchet distance.
+pub mod frechet_distance;
+/// Encodes points as geohashes, and decodes geohashes to cells.
+pub mod geohash;
+/// Returns the bearing between two points along a great circle or a rhumb line.
+pub mod bearing;
+/// Nests polygons lying inside others as their interior rings.
+pub mod assign_holes;
+/// Checks if a geometry is valid, such as a multipolygon without overlapping members.
+pub mod is_valid;
+/// Transforms the coordinates of geometries in place.
+pub mod map_coords;
+/// Scores how compact the shapes of polygons are.
+pub mod compactness;
+/// Adds points along the great circles between the points of a geometry.
+pub mod haversine_densify;
+/// Applies affine transformations to geometries.
+pub mod affine_transform;
+/// Checks if a geometry has no points.
+pub mod is_empty;
+/// Indexes bounding boxes in a quadtree, for range queries.
+pub mod quadtree;
+/// Checks if the line between two points is blocked by polygon obstacles.
+pub mod visibility;
+/// Chains coordinate transforms, such as a projection and an affine transform.
+pub mod pipeline;
+/// Snaps nearly coincident coordinates of geometries together.
+pub mod snap_together;
+/// Approximates circles and ellipses with polygons.
+pub mod circle;
@@ -1,5 +1,4 @@
-use num_traits::Float;
-use types::{LineString, Polygon, MultiPolygon};
+use types::{CoordFloat, LineString, Polygon, MultiPolygon};
 
 pub trait Orient<T> {
     /// Orients a Polygon's exterior and interior rings according to convention
@@ -32,7 +31,7 @@ pub trait Orient<T> {
 }
 
 impl<T> Orient<T> for Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn orient(&self, direction: Direction) -> Polygon<T> {
         orient(self, direction)
@@ -40,7 +39,7 @@ impl<T> Orient<T> for Polygon<T>
 }
 
 impl<T> Orient<T> for MultiPolygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn orient(&self, direction: Direction) -> MultiPolygon<T> {
         MultiPolygon(self.0
@@ -50,6 +49,103 @@ impl<T> Orient<T> for MultiPolygon<T>
     }
 }
 
+impl<T> Polygon<T>
+    where T: CoordFloat
+{
+    /// Returns the Polygon wound as GeoJSON ([RFC 7946](https://tools.ietf.org/html/rfc7946#section-3.1.6))
+    /// requires: its exterior ring counter-clockwise, and its interior rings clockwise.
+    ///
+    /// This is `orient(Direction::Default)`, for use before serializing to GeoJSON.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    ///
+    /// // a clockwise square
+    /// let exterior = LineString(vec![Point::new(0., 0.), Point::new(0., 1.), Point::new(1., 1.),
+    ///                                Point::new(1., 0.), Point::new(0., 0.)]);
+    /// let poly = Polygon::new(exterior, vec![]).to_rfc7946_winding();
+    ///
+    /// assert_eq!(poly.exterior.0[1], Point::new(1., 0.));
+    /// ```
+    pub fn to_rfc7946_winding(&self) -> Polygon<T> {
+        self.orient(Direction::Default)
+    }
+}
+
+impl<T> MultiPolygon<T>
+    where T: CoordFloat
+{
+    /// Returns the MultiPolygon with each Polygon wound as GeoJSON
+    /// ([RFC 7946](https://tools.ietf.org/html/rfc7946#section-3.1.6)) requires: exterior
+    /// rings counter-clockwise, and interior rings clockwise.
+    pub fn to_rfc7946_winding(&self) -> MultiPolygon<T> {
+        self.orient(Direction::Default)
+    }
+
+    /// Creates a MultiPolygon from Polygons of any winding, orienting each as `Orient` does
+    /// by default: exterior rings counter-clockwise, and interior rings clockwise.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon, MultiPolygon};
+    ///
+    /// // a clockwise square
+    /// let exterior = LineString(vec![Point::new(0., 0.), Point::new(0., 1.), Point::new(1., 1.),
+    ///                                Point::new(1., 0.), Point::new(0., 0.)]);
+    /// let mp = MultiPolygon::from_polygons_oriented(vec![Polygon::new(exterior, vec![])]);
+    ///
+    /// assert_eq!(mp.0[0].exterior.0[1], Point::new(1., 0.));
+    /// ```
+    pub fn from_polygons_oriented(polys: Vec<Polygon<T>>) -> MultiPolygon<T> {
+        MultiPolygon(polys
+                         .into_iter()
+                         .map(|mut poly| {
+                             poly.exterior.make_ccw_winding();
+                             for ring in &mut poly.interiors {
+                                 ring.make_cw_winding();
+                             }
+                             poly
+                         })
+                         .collect())
+    }
+}
+
+pub trait Winding<T> {
+    /// Reverses a ring in place if it is wound clockwise, so that it ends up
+    /// counter-clockwise. A ring that is already counter-clockwise is left untouched.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::orient::Winding;
+    /// // a clockwise triangle
+    /// let mut ls = LineString(vec![Point::new(0.0, 0.0), Point::new(0.0, 1.0),
+    ///                              Point::new(1.0, 0.0), Point::new(0.0, 0.0)]);
+    /// ls.make_ccw_winding();
+    /// assert_eq!(ls.0, vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0),
+    ///                       Point::new(0.0, 1.0), Point::new(0.0, 0.0)]);
+    /// ```
+    fn make_ccw_winding(&mut self);
+
+    /// Reverses a ring in place if it is wound counter-clockwise, so that it ends up
+    /// clockwise. A ring that is already clockwise is left untouched.
+    fn make_cw_winding(&mut self);
+}
+
+impl<T> Winding<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn make_ccw_winding(&mut self) {
+        if signed_ring_area(self) < T::zero() {
+            self.0.reverse();
+        }
+    }
+
+    fn make_cw_winding(&mut self) {
+        if signed_ring_area(self) > T::zero() {
+            self.0.reverse();
+        }
+    }
+}
+
 /// By default, a properly-oriented Polygon has its outer ring oriented counter-clockwise,
 /// and its inner ring(s) oriented clockwise. Selecting `Reversed` will result in a Polygon
 /// with a clockwise-oriented exterior ring, and counter-clockwise interior ring(s)
@@ -63,7 +159,7 @@ pub enum Direction {
 
 // the signed area of a linear ring
 fn signed_ring_area<T>(linestring: &LineString<T>) -> T
-    where T: Float
+    where T: CoordFloat
 {
     if linestring.0.is_empty() || linestring.0.len() == 1 {
         return T::zero();
@@ -79,7 +175,7 @@ fn signed_ring_area<T>(linestring: &LineString<T>) -> T
 // by default, the exterior ring will be oriented ccw
 // and the interior ring(s) will be oriented clockwise
 fn orient<T>(poly: &Polygon<T>, direction: Direction) -> Polygon<T>
-    where T: Float
+    where T: CoordFloat
 {
     let sign = match direction {
         Direction::Default => T::one(),
@@ -104,7 +200,7 @@ fn orient<T>(poly: &Polygon<T>, direction: Direction) -> Polygon<T>
 
 #[cfg(test)]
 mod test {
-    use types::{Polygon, LineString, Point};
+    use types::{Polygon, MultiPolygon, LineString, Point};
     use super::*;
     #[test]
     fn test_polygon_orientation() {
@@ -138,4 +234,60 @@ mod test {
         assert_eq!(oriented.exterior.0, oriented_ext_ls.0);
         assert_eq!(oriented.interiors[0].0, oriented_int_ls.0);
     }
+    #[test]
+    fn test_make_ccw_winding() {
+        let cw = [(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)];
+        let mut ls = LineString(cw.iter().map(|e| Point::new(e.0, e.1)).collect::<Vec<_>>());
+        assert!(signed_ring_area(&ls) < 0.0);
+        ls.make_ccw_winding();
+        assert!(signed_ring_area(&ls) > 0.0);
+        // already ccw, so this is a no-op
+        let ccw = ls.clone();
+        ls.make_ccw_winding();
+        assert_eq!(ls, ccw);
+    }
+    #[test]
+    fn test_make_cw_winding() {
+        let ccw = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)];
+        let mut ls = LineString(ccw.iter().map(|e| Point::new(e.0, e.1)).collect::<Vec<_>>());
+        ls.make_cw_winding();
+        assert!(signed_ring_area(&ls) < 0.0);
+        let cw = ls.clone();
+        ls.make_cw_winding();
+        assert_eq!(ls, cw);
+    }
+    #[test]
+    fn test_rfc7946_winding() {
+        let ring = |points: &[(f64, f64)]| LineString(points.iter().map(|e| Point::new(e.0, e.1)).collect());
+        // a clockwise exterior, with one counter-clockwise and one clockwise hole
+        let exterior = ring(&[(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)]);
+        let ccw_hole = ring(&[(1., 1.), (3., 1.), (3., 3.), (1., 3.), (1., 1.)]);
+        let cw_hole = ring(&[(5., 5.), (5., 7.), (7., 7.), (7., 5.), (5., 5.)]);
+        let poly = Polygon::new(exterior, vec![ccw_hole, cw_hole.clone()]);
+        let wound = poly.to_rfc7946_winding();
+        assert!(signed_ring_area(&wound.exterior) > 0.0);
+        assert!(wound.interiors.iter().all(|hole| signed_ring_area(hole) < 0.0));
+        assert_eq!(wound.interiors[1], cw_hole);
+        // already compliant, so nothing changes
+        assert_eq!(wound.to_rfc7946_winding(), wound);
+
+        let mp = MultiPolygon(vec![poly.clone(), wound.clone()]).to_rfc7946_winding();
+        assert_eq!(mp.0, vec![wound.clone(), wound]);
+    }
+    #[test]
+    fn test_from_polygons_oriented() {
+        let ring = |points: &[(f64, f64)]| LineString(points.iter().map(|e| Point::new(e.0, e.1)).collect());
+        let cw_square = |x: f64| ring(&[(x, 0.), (x, 3.), (x + 3., 3.), (x + 3., 0.), (x, 0.)]);
+        let ccw_hole = |x: f64| ring(&[(x + 1., 1.), (x + 2., 1.), (x + 2., 2.), (x + 1., 2.), (x + 1., 1.)]);
+        let polys = vec![Polygon::new(cw_square(0.), vec![]),
+                         Polygon::new(cw_square(5.), vec![ccw_hole(5.)]),
+                         Polygon::new(LineString(cw_square(10.).0.into_iter().rev().collect()), vec![])];
+        let mp = MultiPolygon::from_polygons_oriented(polys);
+        assert_eq!(mp.0.len(), 3);
+        for poly in &mp.0 {
+            assert!(signed_ring_area(&poly.exterior) > 0.0);
+            assert!(poly.interiors.iter().all(|hole| signed_ring_area(hole) < 0.0));
+        }
+        assert!(MultiPolygon::<f64>::from_polygons_oriented(vec![]).0.is_empty());
+    }
 }
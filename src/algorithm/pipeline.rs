@@ -0,0 +1,137 @@
+use types::{CoordFloat, Coordinate, Geometry};
+use algorithm::affine_transform::AffineTransform;
+use algorithm::map_coords::MapCoordsInplace;
+
+// a single transform of the pipeline
+type Step<T> = Box<dyn Fn(&Coordinate<T>) -> Coordinate<T>>;
+
+/// An ordered list of coordinate transforms, such as a projection followed by an
+/// `AffineTransform` to pixels, applied as one step.
+///
+/// Each coordinate passes through every transform in turn, so no intermediate geometries
+/// are built.
+///
+/// ```
+/// use geo::{Coordinate, Point, Geometry};
+/// use geo::algorithm::affine_transform::AffineTransform;
+/// use geo::algorithm::pipeline::TransformPipeline;
+///
+/// let pipeline = TransformPipeline::new()
+///     .then(|c: &Coordinate<f64>| Coordinate { x: c.x.to_radians(), y: c.y.to_radians() })
+///     .then_affine(AffineTransform::scale(100., -100., &Point::new(0., 0.)));
+///
+/// let transformed = pipeline.transform(&Geometry::Point(Point::new(180., 90.)));
+/// let expected = Point::new(100. * ::std::f64::consts::PI, -50. * ::std::f64::consts::PI);
+/// assert_eq!(transformed, Geometry::Point(expected));
+/// ```
+pub struct TransformPipeline<T>
+    where T: CoordFloat
+{
+    steps: Vec<Step<T>>,
+}
+
+impl<T> TransformPipeline<T>
+    where T: CoordFloat
+{
+    /// Creates a pipeline with no transforms, which leaves coordinates unchanged.
+    pub fn new() -> TransformPipeline<T> {
+        TransformPipeline { steps: vec![] }
+    }
+
+    /// Adds a transform to the end of the pipeline.
+    pub fn then<F>(mut self, f: F) -> TransformPipeline<T>
+        where F: Fn(&Coordinate<T>) -> Coordinate<T> + 'static
+    {
+        self.steps.push(Box::new(f));
+        self
+    }
+
+    /// Adds an affine transform to the end of the pipeline.
+    pub fn then_affine(self, transform: AffineTransform<T>) -> TransformPipeline<T>
+        where T: 'static
+    {
+        self.then(move |c| transform.apply(c))
+    }
+
+    /// The number of transforms in the pipeline.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Checks if the pipeline has no transforms.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Applies every transform in turn to a coordinate.
+    pub fn transform_coord(&self, c: &Coordinate<T>) -> Coordinate<T> {
+        self.steps.iter().fold(*c, |c, step| step(&c))
+    }
+
+    /// Returns a geometry with every transform applied in turn to each of its coordinates.
+    pub fn transform(&self, geom: &Geometry<T>) -> Geometry<T> {
+        let mut transformed = geom.clone();
+        transformed.map_coords_inplace(|c| self.transform_coord(c));
+        transformed
+    }
+}
+
+impl<T> Default for TransformPipeline<T>
+    where T: CoordFloat
+{
+    fn default() -> TransformPipeline<T> {
+        TransformPipeline::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::PI;
+    use types::{Coordinate, Point, LineString, Polygon, Geometry};
+    use algorithm::affine_transform::{AffineOps, AffineTransform};
+    use algorithm::map_coords::MapCoordsInplace;
+    use super::*;
+
+    // spherical web mercator, in metres
+    fn mercator(c: &Coordinate<f64>) -> Coordinate<f64> {
+        let radius = 6378137.;
+        Coordinate {
+            x: radius * c.x.to_radians(),
+            y: radius * (PI / 4. + c.y.to_radians() / 2.).tan().ln(),
+        }
+    }
+
+    #[test]
+    fn project_then_scale_test() {
+        // metres to the pixels of a 256 pixel tile covering the world, with y down
+        let half_world = PI * 6378137.;
+        let to_pixels = AffineTransform::translate(half_world, -half_world)
+            .compose(&AffineTransform::scale(256. / (2. * half_world), -256. / (2. * half_world),
+                                             &Point::new(0., 0.)));
+        let pipeline = TransformPipeline::new().then(mercator).then_affine(to_pixels);
+        assert_eq!(pipeline.len(), 2);
+
+        let polygon = Polygon::new(LineString(vec![Point::new(-0.13, 51.5), Point::new(2.35, 48.86),
+                                                   Point::new(13.4, 52.52), Point::new(-0.13, 51.5)]),
+                                   vec![]);
+        let mut separately = polygon.clone();
+        separately.map_coords_inplace(mercator);
+        let separately = separately.affine_transform(&to_pixels);
+
+        match pipeline.transform(&Geometry::Polygon(polygon)) {
+            Geometry::Polygon(piped) => assert_eq!(piped, separately),
+            other => panic!("expected a Polygon, got {:?}", other),
+        }
+        // the origin lands in the middle of the tile
+        let center = pipeline.transform_coord(&Coordinate { x: 0., y: 0. });
+        assert_relative_eq!(center.x, 128.);
+        assert_relative_eq!(center.y, 128.);
+    }
+    #[test]
+    fn empty_pipeline_test() {
+        let pipeline = TransformPipeline::<f64>::default();
+        assert!(pipeline.is_empty());
+        let point = Geometry::Point(Point::new(3., 4.));
+        assert_eq!(pipeline.transform(&point), point);
+    }
+}
@@ -0,0 +1,263 @@
+use types::{CoordFloat, Point, LineString, Projection};
+use algorithm::distance::Distance;
+
+// the point on the segment from start to end nearest to p
+fn segment_projection<T>(p: &Point<T>, start: &Point<T>, end: &Point<T>) -> Point<T>
+    where T: CoordFloat
+{
+    let delta = *end - *start;
+    let length_squared = delta.dot(&delta);
+    if length_squared == T::zero() {
+        return *start;
+    }
+    let t = ((*p - *start).dot(&delta) / length_squared).max(T::zero()).min(T::one());
+    Point::new(start.x() + t * delta.x(), start.y() + t * delta.y())
+}
+
+impl<T> Point<T>
+    where T: CoordFloat
+{
+    /// Returns the foot of the perpendicular from the point to the infinite line through `a`
+    /// and `b`. Unlike `project_point`, this isn't clamped to the segment between them. If `a`
+    /// and `b` are the same point, it is returned.
+    ///
+    /// ```
+    /// use geo::Point;
+    ///
+    /// let p = Point::new(6., 3.);
+    ///
+    /// assert_eq!(p.project_onto_line(&Point::new(0., 0.), &Point::new(4., 0.)), Point::new(6., 0.));
+    /// ```
+    pub fn project_onto_line(&self, a: &Point<T>, b: &Point<T>) -> Point<T> {
+        let delta = *b - *a;
+        let length_squared = delta.dot(&delta);
+        if length_squared == T::zero() {
+            return *a;
+        }
+        let t = (*self - *a).dot(&delta) / length_squared;
+        Point::new(a.x() + t * delta.x(), a.y() + t * delta.y())
+    }
+}
+
+pub trait ProjectPoint<T: CoordFloat> {
+    /// Projects `p` onto a geometry, returning the nearest point on the geometry, its distance
+    /// along the geometry, and its distance from `p`. Returns `None` if the geometry is empty.
+    ///
+    /// If several points are equally near, the one nearest the start of the geometry is used.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::project_point::ProjectPoint;
+    ///
+    /// let ls = LineString(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(4., 4.)]);
+    /// let projection = ls.project_point(&Point::new(5., 1.)).unwrap();
+    ///
+    /// assert_eq!(projection.point, Point::new(4., 1.));
+    /// assert_eq!(projection.along_distance, 5.);
+    /// assert_eq!(projection.offset_distance, 1.);
+    /// ```
+    fn project_point(&self, p: &Point<T>) -> Option<Projection<T>>;
+}
+
+impl<T> ProjectPoint<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn project_point(&self, p: &Point<T>) -> Option<Projection<T>> {
+        let first = *self.0.first()?;
+        let mut best = Projection {
+            point: first,
+            along_distance: T::zero(),
+            offset_distance: p.distance(&first),
+        };
+        let mut travelled = T::zero();
+        for segment in self.0.windows(2) {
+            let (start, end) = (segment[0], segment[1]);
+            let projected = segment_projection(p, &start, &end);
+            let offset = p.distance(&projected);
+            if offset < best.offset_distance {
+                best = Projection {
+                    point: projected,
+                    along_distance: travelled + start.distance(&projected),
+                    offset_distance: offset,
+                };
+            }
+            travelled = travelled + start.distance(&end);
+        }
+        Some(best)
+    }
+}
+
+impl<T> LineString<T>
+    where T: CoordFloat
+{
+    /// Returns the index of the segment nearest to `p`, where segment `i` runs from point `i`
+    /// to point `i + 1`. Returns `None` if the `LineString` has fewer than two points.
+    ///
+    /// If several segments are equally near, the first of them is used.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let ls = LineString(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(4., 4.)]);
+    ///
+    /// assert_eq!(ls.closest_segment_index(&Point::new(1., -1.)), Some(0));
+    /// assert_eq!(ls.closest_segment_index(&Point::new(5., 2.)), Some(1));
+    /// ```
+    pub fn closest_segment_index(&self, p: &Point<T>) -> Option<usize> {
+        let mut best: Option<(usize, T)> = None;
+        for (i, segment) in self.0.windows(2).enumerate() {
+            let offset = p.distance(&segment_projection(p, &segment[0], &segment[1]));
+            best = match best {
+                Some((_, nearest)) if nearest <= offset => best,
+                _ => Some((i, offset)),
+            };
+        }
+        best.map(|(i, _)| i)
+    }
+
+    /// Returns the part of the `LineString` between two fractions of its length, where `0.0`
+    /// is its start and `1.0` its end. Fractions outside that range are clamped to it.
+    ///
+    /// The ends of the result are interpolated along the segments they fall on, and every
+    /// point of the `LineString` between them is kept. If `start_fraction` is greater than
+    /// `end_fraction`, the result runs backwards. A `LineString` of zero length gives its
+    /// first point twice, and an empty one gives an empty `LineString`.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let ls = LineString(vec![Point::new(0., 0.), Point::new(4., 0.), Point::new(4., 4.)]);
+    ///
+    /// assert_eq!(ls.line_substring(0.25, 0.75).0,
+    ///            vec![Point::new(2., 0.), Point::new(4., 0.), Point::new(4., 2.)]);
+    /// assert_eq!(ls.line_substring(0.75, 0.25).0,
+    ///            vec![Point::new(4., 2.), Point::new(4., 0.), Point::new(2., 0.)]);
+    /// ```
+    pub fn line_substring(&self, start_fraction: T, end_fraction: T) -> LineString<T> {
+        if start_fraction > end_fraction {
+            let mut reversed = self.line_substring(end_fraction, start_fraction);
+            reversed.0.reverse();
+            return reversed;
+        }
+        let first = match self.0.first() {
+            Some(&first) => first,
+            None => return LineString(vec![]),
+        };
+        // the distance along the line of each point
+        let mut along = Vec::with_capacity(self.0.len());
+        along.push(T::zero());
+        for segment in self.0.windows(2) {
+            let travelled = along[along.len() - 1] + segment[0].distance(&segment[1]);
+            along.push(travelled);
+        }
+        let total = along[along.len() - 1];
+        if total.is_zero() {
+            return LineString(vec![first, first]);
+        }
+        let clamp = |fraction: T| fraction.max(T::zero()).min(T::one()) * total;
+        let (start, end) = (clamp(start_fraction), clamp(end_fraction));
+        let point_at = |distance: T| {
+            // the last segment starting at or before the distance, skipping zero-length ones
+            let i = (0..self.0.len() - 1)
+                .rev()
+                .find(|&i| along[i] <= distance && along[i] < along[i + 1])
+                .unwrap_or(0);
+            let t = ((distance - along[i]) / (along[i + 1] - along[i])).min(T::one());
+            self.0[i].lerp(&self.0[i + 1], t)
+        };
+        let mut substring = vec![point_at(start)];
+        substring.extend((0..self.0.len()).filter(|&i| start < along[i] && along[i] < end).map(|i| self.0[i]));
+        substring.push(point_at(end));
+        LineString(substring)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use algorithm::length::Length;
+    use super::*;
+
+    #[test]
+    fn straight_line_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(10., 0.)]);
+        let projection = ls.project_point(&Point::new(3., -2.)).unwrap();
+        assert_eq!(projection.point, Point::new(3., 0.));
+        assert_eq!(projection.along_distance, 3.);
+        assert_eq!(projection.offset_distance, 2.);
+    }
+    #[test]
+    fn beyond_the_end_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(3., 0.), Point::new(3., 4.)]);
+        let projection = ls.project_point(&Point::new(6., 8.)).unwrap();
+        assert_eq!(projection.point, Point::new(3., 4.));
+        assert_eq!(projection.along_distance, 7.);
+        assert_eq!(projection.offset_distance, 5.);
+        let before = ls.project_point(&Point::new(-1., 0.)).unwrap();
+        assert_eq!(before.point, Point::new(0., 0.));
+        assert_eq!(before.along_distance, 0.);
+    }
+    #[test]
+    fn degenerate_test() {
+        assert!(LineString::<f64>(vec![]).project_point(&Point::new(1., 1.)).is_none());
+        let single = LineString(vec![Point::new(1., 1.)]);
+        let projection = single.project_point(&Point::new(4., 5.)).unwrap();
+        assert_eq!(projection.point, Point::new(1., 1.));
+        assert_eq!(projection.along_distance, 0.);
+        assert_eq!(projection.offset_distance, 5.);
+    }
+    #[test]
+    fn closest_segment_index_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(2., 0.), Point::new(2., 2.), Point::new(4., 2.)]);
+        assert_eq!(ls.closest_segment_index(&Point::new(2.5, 1.)), Some(1));
+        assert_eq!(ls.closest_segment_index(&Point::new(1., 0.5)), Some(0));
+        assert_eq!(ls.closest_segment_index(&Point::new(5., 3.)), Some(2));
+        // the shared vertex of the first two segments is equally near both
+        assert_eq!(ls.closest_segment_index(&Point::new(3., -1.)), Some(0));
+        assert_eq!(LineString(vec![Point::new(0., 0.)]).closest_segment_index(&Point::new(1., 1.)), None);
+    }
+    #[test]
+    fn line_substring_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(10., 0.)]);
+        let middle = ls.line_substring(0.25, 0.75);
+        assert_eq!(middle.0, vec![Point::new(2.5, 0.), Point::new(7.5, 0.)]);
+        assert_eq!(middle.length(), 5.);
+        // backwards, and clamped to the ends
+        assert_eq!(ls.line_substring(0.75, 0.25).0, vec![Point::new(7.5, 0.), Point::new(2.5, 0.)]);
+        assert_eq!(ls.line_substring(-1., 2.), ls);
+        assert_eq!(ls.line_substring(0.5, 0.5).0, vec![Point::new(5., 0.), Point::new(5., 0.)]);
+    }
+    #[test]
+    fn line_substring_vertices_test() {
+        // with a repeated point, which makes a segment of zero length
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(3., 0.), Point::new(3., 0.), Point::new(3., 4.),
+                                 Point::new(6., 4.)]);
+        let substring = ls.line_substring(0.1, 0.9);
+        assert_eq!(substring.0, vec![Point::new(1., 0.), Point::new(3., 0.), Point::new(3., 0.),
+                                     Point::new(3., 4.), Point::new(5., 4.)]);
+        assert_relative_eq!(substring.length(), 8.);
+        // ending exactly on a vertex
+        assert_eq!(ls.line_substring(0., 0.3).0, vec![Point::new(0., 0.), Point::new(3., 0.)]);
+        assert_eq!(ls.line_substring(0.3, 0.3).0, vec![Point::new(3., 0.), Point::new(3., 0.)]);
+    }
+    #[test]
+    fn line_substring_degenerate_test() {
+        assert_eq!(LineString::<f64>(vec![]).line_substring(0., 1.), LineString(vec![]));
+        let point = LineString(vec![Point::new(1., 1.)]);
+        assert_eq!(point.line_substring(0., 1.).0, vec![Point::new(1., 1.), Point::new(1., 1.)]);
+    }
+    #[test]
+    fn project_onto_line_test() {
+        let (a, b) = (Point::new(1., 1.), Point::new(3., 3.));
+        // beyond b, where the segment's nearest point is b itself
+        let p = Point::new(6., 4.);
+        assert_eq!(p.project_onto_line(&a, &b), Point::new(5., 5.));
+        assert_eq!(LineString(vec![a, b]).project_point(&p).unwrap().point, b);
+        // before a, and within the segment
+        assert_eq!(Point::new(-1., -3.).project_onto_line(&a, &b), Point::new(-2., -2.));
+        assert_eq!(Point::new(3., 1.).project_onto_line(&a, &b), Point::new(2., 2.));
+        // a point already on the line
+        assert_eq!(Point::new(7., 7.).project_onto_line(&a, &b), Point::new(7., 7.));
+        assert_eq!(p.project_onto_line(&a, &a), a);
+    }
+}
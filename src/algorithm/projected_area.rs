@@ -0,0 +1,116 @@
+use types::{CoordFloat, Point, LineString, Polygon, MultiPolygon};
+use algorithm::area::Area;
+use algorithm::centroid::Centroid;
+use constants::MEAN_EARTH_RADIUS;
+
+// project a lon/lat point onto the azimuthal equidistant plane centered on `center`, in meters
+fn azimuthal_equidistant<T>(p: &Point<T>, center: &Point<T>) -> Point<T>
+    where T: CoordFloat
+{
+    let radius = T::from(MEAN_EARTH_RADIUS).unwrap();
+    let (lat0, lat) = (center.y().to_radians(), p.y().to_radians());
+    let delta_lng = (p.x() - center.x()).to_radians();
+    let cos_c = lat0.sin() * lat.sin() + lat0.cos() * lat.cos() * delta_lng.cos();
+    // the angular distance from the center; clamp rounding error before taking acos
+    let c = cos_c.max(-T::one()).min(T::one()).acos();
+    let k = if c == T::zero() { T::one() } else { c / c.sin() };
+    Point::new(radius * k * lat.cos() * delta_lng.sin(),
+               radius * k * (lat0.cos() * lat.sin() - lat0.sin() * lat.cos() * delta_lng.cos()))
+}
+
+// the unsigned area of a ring, projected around `center`
+fn projected_ring_area<T>(ring: &LineString<T>, center: &Point<T>) -> T
+    where T: CoordFloat
+{
+    let projected = ring.0.iter().map(|p| azimuthal_equidistant(p, center)).collect();
+    Polygon::new(LineString(projected), vec![]).area().abs()
+}
+
+pub trait ProjectedArea<T> {
+    /// Returns the approximate area in square meters of a geometry whose coordinates are
+    /// longitude/latitude in degrees.
+    ///
+    /// Each polygon is projected onto an azimuthal equidistant plane centered on its centroid,
+    /// and its planar area is taken there. The distortion grows with the distance from the
+    /// center, so this is accurate for small and medium-sized polygons, e.g. up to the size of a
+    /// city or a small country.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    /// use geo::algorithm::projected_area::ProjectedArea;
+    ///
+    /// // about 1.1km by 1.1km, on the equator
+    /// let p = |x, y| Point::new(x, y);
+    /// let square = Polygon::new(LineString(vec![p(0., 0.), p(0.01, 0.), p(0.01, 0.01), p(0., 0.01), p(0., 0.)]), vec![]);
+    /// let area: f64 = square.projected_area_m2();
+    /// assert!((area - 1_236_000.).abs() < 1_000.);
+    /// ```
+    fn projected_area_m2(&self) -> T where T: CoordFloat;
+}
+
+impl<T> ProjectedArea<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn projected_area_m2(&self) -> T {
+        let center = match self.centroid() {
+            Some(center) => center,
+            None => return T::zero(),
+        };
+        self.interiors.iter().fold(projected_ring_area(&self.exterior, &center),
+                                   |total, ring| total - projected_ring_area(ring, &center))
+    }
+}
+
+impl<T> ProjectedArea<T> for MultiPolygon<T>
+    where T: CoordFloat
+{
+    fn projected_area_m2(&self) -> T {
+        self.0.iter().fold(T::zero(), |total, poly| total + poly.projected_area_m2())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPolygon};
+    use super::*;
+
+    // the area of a ring on the sphere, from its spherical excess
+    fn spherical_ring_area(ring: &LineString<f64>) -> f64 {
+        let radius = MEAN_EARTH_RADIUS;
+        let sum = ring.0.windows(2).fold(0., |sum, ps| {
+            let delta_lng = (ps[1].x() - ps[0].x()).to_radians();
+            sum + delta_lng * (2. + ps[0].y().to_radians().sin() + ps[1].y().to_radians().sin())
+        });
+        (sum * radius * radius / 2.).abs()
+    }
+
+    #[test]
+    fn city_sized_polygon_test() {
+        // an irregular polygon roughly the size of Paris
+        let p = |x, y| Point::new(x, y);
+        let exterior = LineString(vec![p(2.22, 48.82), p(2.41, 48.81), p(2.47, 48.85), p(2.41, 48.90),
+                                       p(2.32, 48.90), p(2.25, 48.87), p(2.22, 48.82)]);
+        let expected = spherical_ring_area(&exterior);
+        let poly = Polygon::new(exterior, vec![]);
+        let area = poly.projected_area_m2();
+        assert!(area > 0.);
+        assert!(((area - expected) / expected).abs() < 0.01);
+    }
+    #[test]
+    fn holes_and_multipolygon_test() {
+        let p = |x, y| Point::new(x, y);
+        let square = |x: f64, y: f64, size: f64| {
+            LineString(vec![p(x, y), p(x + size, y), p(x + size, y + size), p(x, y + size), p(x, y)])
+        };
+        let solid = Polygon::new(square(10., 45., 0.1), vec![]);
+        // a clockwise hole, a quarter of the area
+        let mut hole = square(10.025, 45.025, 0.05);
+        hole.0.reverse();
+        let holed = Polygon::new(square(10., 45., 0.1), vec![hole]);
+        assert_relative_eq!(holed.projected_area_m2() / solid.projected_area_m2(), 0.75, epsilon = 1e-3);
+        let both = MultiPolygon(vec![solid.clone(), holed.clone()]);
+        assert_relative_eq!(both.projected_area_m2(),
+                            solid.projected_area_m2() + holed.projected_area_m2());
+        assert_eq!(Polygon::new(LineString::<f64>(vec![]), vec![]).projected_area_m2(), 0.);
+    }
+}
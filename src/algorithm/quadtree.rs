@@ -0,0 +1,192 @@
+use types::{CoordFloat, Bbox};
+
+// subdivision stops at this depth, so that many identical bboxes can't recurse forever
+const MAX_DEPTH: usize = 16;
+
+// whether two bboxes share any point, including along their edges
+fn overlaps<T>(a: &Bbox<T>, b: &Bbox<T>) -> bool
+    where T: CoordFloat
+{
+    a.xmin <= b.xmax && b.xmin <= a.xmax && a.ymin <= b.ymax && b.ymin <= a.ymax
+}
+
+fn encloses<T>(outer: &Bbox<T>, inner: &Bbox<T>) -> bool
+    where T: CoordFloat
+{
+    outer.xmin <= inner.xmin && inner.xmax <= outer.xmax && outer.ymin <= inner.ymin &&
+    inner.ymax <= outer.ymax
+}
+
+#[derive(Clone, Debug)]
+struct Node<T>
+    where T: CoordFloat
+{
+    bounds: Bbox<T>,
+    depth: usize,
+    // entries which don't fit inside a single child stay here
+    entries: Vec<(usize, Bbox<T>)>,
+    children: Vec<Node<T>>,
+}
+
+impl<T> Node<T>
+    where T: CoordFloat
+{
+    fn new(bounds: Bbox<T>, depth: usize) -> Node<T> {
+        Node { bounds, depth, entries: vec![], children: vec![] }
+    }
+
+    fn insert(&mut self, index: usize, bbox: Bbox<T>, capacity: usize) {
+        if let Some(child) = self.children.iter_mut().find(|child| encloses(&child.bounds, &bbox)) {
+            child.insert(index, bbox, capacity);
+            return;
+        }
+        self.entries.push((index, bbox));
+        if self.children.is_empty() && self.entries.len() > capacity && self.depth < MAX_DEPTH {
+            self.subdivide(capacity);
+        }
+    }
+
+    fn subdivide(&mut self, capacity: usize) {
+        let b = self.bounds;
+        let two = T::one() + T::one();
+        let (xmid, ymid) = ((b.xmin + b.xmax) / two, (b.ymin + b.ymax) / two);
+        self.children = vec![Node::new(Bbox { xmin: b.xmin, xmax: xmid, ymin: b.ymin, ymax: ymid }, self.depth + 1),
+                             Node::new(Bbox { xmin: xmid, xmax: b.xmax, ymin: b.ymin, ymax: ymid }, self.depth + 1),
+                             Node::new(Bbox { xmin: b.xmin, xmax: xmid, ymin: ymid, ymax: b.ymax }, self.depth + 1),
+                             Node::new(Bbox { xmin: xmid, xmax: b.xmax, ymin: ymid, ymax: b.ymax }, self.depth + 1)];
+        for (index, bbox) in ::std::mem::take(&mut self.entries) {
+            self.insert(index, bbox, capacity);
+        }
+    }
+
+    fn query(&self, range: &Bbox<T>, found: &mut Vec<usize>) {
+        found.extend(self.entries.iter().filter(|entry| overlaps(&entry.1, range)).map(|entry| entry.0));
+        for child in &self.children {
+            if overlaps(&child.bounds, range) {
+                child.query(range, found);
+            }
+        }
+    }
+}
+
+/// A quadtree of bounding boxes, for finding those which overlap a query range.
+///
+/// Each node holds up to `capacity` entries before splitting into four quadrants. Entries
+/// straddling the quadrants, or lying outside the tree's bounds, are kept in the node
+/// enclosing them, so any bboxes can be inserted; the tree is just fastest when they lie
+/// within its bounds.
+///
+/// ```
+/// use geo::Bbox;
+/// use geo::algorithm::quadtree::QuadTree;
+///
+/// let mut tree = QuadTree::new(Bbox { xmin: 0., xmax: 100., ymin: 0., ymax: 100. }, 4);
+/// tree.insert(0, Bbox { xmin: 10., xmax: 20., ymin: 10., ymax: 20. });
+/// tree.insert(1, Bbox { xmin: 60., xmax: 70., ymin: 60., ymax: 70. });
+///
+/// assert_eq!(tree.query_range(&Bbox { xmin: 15., xmax: 30., ymin: 0., ymax: 30. }), vec![0]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct QuadTree<T>
+    where T: CoordFloat
+{
+    root: Node<T>,
+    capacity: usize,
+    len: usize,
+}
+
+impl<T> QuadTree<T>
+    where T: CoordFloat
+{
+    /// Creates an empty quadtree covering `bounds`, splitting nodes holding more than
+    /// `capacity` entries.
+    pub fn new(bounds: Bbox<T>, capacity: usize) -> QuadTree<T> {
+        QuadTree { root: Node::new(bounds, 0), capacity: capacity.max(1), len: 0 }
+    }
+
+    /// Adds a bbox to the tree, identified by `index`.
+    pub fn insert(&mut self, index: usize, bbox: Bbox<T>) {
+        self.root.insert(index, bbox, self.capacity);
+        self.len += 1;
+    }
+
+    /// The number of entries in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the tree has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the indices of the entries whose bboxes overlap `range`, including those only
+    /// touching its edges, in ascending order.
+    pub fn query_range(&self, range: &Bbox<T>) -> Vec<usize> {
+        let mut found = vec![];
+        self.root.query(range, &mut found);
+        found.sort();
+        found
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::Bbox;
+    use super::*;
+
+    fn random_bboxes(n: usize) -> Vec<Bbox<f64>> {
+        // a deterministic scatter of small bboxes
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 8) as f64 / (1 << 24) as f64
+        };
+        (0..n)
+            .map(|_| {
+                let (x, y) = (next() * 1000., next() * 1000.);
+                Bbox { xmin: x, xmax: x + next() * 20., ymin: y, ymax: y + next() * 20. }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_linear_scan_test() {
+        let bboxes = random_bboxes(1000);
+        let mut tree = QuadTree::new(Bbox { xmin: 0., xmax: 1000., ymin: 0., ymax: 1000. }, 8);
+        for (i, bbox) in bboxes.iter().enumerate() {
+            tree.insert(i, *bbox);
+        }
+        assert_eq!(tree.len(), 1000);
+        for range in random_bboxes(50).iter().map(|b| Bbox { xmax: b.xmax + 100., ymax: b.ymax + 50., ..*b }) {
+            let expected: Vec<usize> = (0..bboxes.len()).filter(|&i| overlaps(&bboxes[i], &range)).collect();
+            assert_eq!(tree.query_range(&range), expected);
+        }
+        // the whole area
+        let all = tree.query_range(&Bbox { xmin: -1., xmax: 2000., ymin: -1., ymax: 2000. });
+        assert_eq!(all, (0..1000).collect::<Vec<_>>());
+        // the tree did split, and most queries only visit a few entries
+        assert!(!tree.root.children.is_empty());
+        assert!(tree.root.entries.len() < 100);
+    }
+    #[test]
+    fn out_of_bounds_test() {
+        let mut tree = QuadTree::new(Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. }, 1);
+        tree.insert(0, Bbox { xmin: 20., xmax: 30., ymin: 20., ymax: 30. });
+        tree.insert(1, Bbox { xmin: 1., xmax: 2., ymin: 1., ymax: 2. });
+        tree.insert(2, Bbox { xmin: 1., xmax: 2., ymin: 1., ymax: 2. });
+        assert_eq!(tree.query_range(&Bbox { xmin: 25., xmax: 26., ymin: 25., ymax: 26. }), vec![0]);
+        assert_eq!(tree.query_range(&Bbox { xmin: 2., xmax: 3., ymin: 2., ymax: 3. }), vec![1, 2]);
+        assert!(tree.query_range(&Bbox { xmin: 5., xmax: 6., ymin: 5., ymax: 6. }).is_empty());
+    }
+    #[test]
+    fn duplicates_test() {
+        // identical bboxes can never be separated, and stop splitting at the maximum depth
+        let mut tree = QuadTree::new(Bbox { xmin: 0., xmax: 1., ymin: 0., ymax: 1. }, 2);
+        for i in 0..100 {
+            tree.insert(i, Bbox { xmin: 0.1, xmax: 0.1, ymin: 0.1, ymax: 0.1 });
+        }
+        assert_eq!(tree.query_range(&Bbox { xmin: 0., xmax: 0.5, ymin: 0., ymax: 0.5 }).len(), 100);
+        assert!(QuadTree::<f64>::new(Bbox { xmin: 0., xmax: 1., ymin: 0., ymax: 1. }, 2).is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+use types::{CoordFloat, Point, LineString, Polygon, MultiLineString, MultiPolygon};
+use algorithm::util::{orient2d, Orientation};
+
+// round a point's coordinates, given the scale factor 10^decimals
+fn round_point<T>(point: &Point<T>, scale: T) -> Point<T>
+    where T: CoordFloat
+{
+    Point::new((point.x() * scale).round() / scale,
+               (point.y() * scale).round() / scale)
+}
+
+// round a slice of points, removing consecutive points that become identical
+fn round_points<T>(points: &[Point<T>], scale: T) -> Vec<Point<T>>
+    where T: CoordFloat
+{
+    let mut rounded: Vec<Point<T>> = points.iter().map(|p| round_point(p, scale)).collect();
+    rounded.dedup();
+    rounded
+}
+
+// does a rounded ring still have three points which aren't collinear, needed to enclose an
+// area? Repeating points, as in A-B-A-B-A, or lining them up isn't enough.
+fn is_ring<T>(ring: &LineString<T>) -> bool
+    where T: CoordFloat
+{
+    let first = match ring.0.first() {
+        Some(first) => first,
+        None => return false,
+    };
+    match ring.0.iter().find(|p| *p != first) {
+        Some(second) => ring.0.iter().any(|p| orient2d(first, second, p) != Orientation::Collinear),
+        None => false,
+    }
+}
+
+fn scale<T>(decimals: u32) -> T
+    where T: CoordFloat
+{
+    T::from(10).unwrap().powi(decimals as i32)
+}
+
+pub trait ReducePrecision<T> {
+    /// Rounds every coordinate of a geometry to `decimals` decimal places.
+    ///
+    /// Consecutive points which become identical after rounding are merged, so that
+    /// rounding doesn't introduce zero-length segments. Rings stay closed.
+    ///
+    /// Interior rings which collapse, to fewer than three vertices that aren't collinear,
+    /// are dropped. A `Polygon` whose exterior ring collapses is returned with an empty
+    /// exterior and no interior rings, and is dropped from a `MultiPolygon`. Rings which
+    /// come to cross or touch themselves, or each other, are kept as they are: rounding
+    /// doesn't repair the self-intersections it may introduce.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::reduce_precision::ReducePrecision;
+    ///
+    /// let ls = LineString(vec![Point::new(0.1234, 0.1), Point::new(0.1201, 0.1), Point::new(1.0, 1.0)]);
+    /// let reduced = ls.reduce_precision(2);
+    /// assert_eq!(reduced, LineString(vec![Point::new(0.12, 0.1), Point::new(1.0, 1.0)]));
+    /// ```
+    fn reduce_precision(&self, decimals: u32) -> Self where T: CoordFloat;
+}
+
+impl<T> ReducePrecision<T> for Point<T>
+    where T: CoordFloat
+{
+    fn reduce_precision(&self, decimals: u32) -> Self {
+        round_point(self, scale(decimals))
+    }
+}
+
+impl<T> ReducePrecision<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn reduce_precision(&self, decimals: u32) -> Self {
+        LineString(round_points(&self.0, scale(decimals)))
+    }
+}
+
+impl<T> ReducePrecision<T> for MultiLineString<T>
+    where T: CoordFloat
+{
+    fn reduce_precision(&self, decimals: u32) -> Self {
+        MultiLineString(self.0.iter().map(|ls| ls.reduce_precision(decimals)).collect())
+    }
+}
+
+impl<T> ReducePrecision<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn reduce_precision(&self, decimals: u32) -> Self {
+        let exterior = self.exterior.reduce_precision(decimals);
+        if !is_ring(&exterior) {
+            return Polygon::new(LineString(vec![]), vec![]);
+        }
+        Polygon::new(exterior,
+                     self.interiors
+                         .iter()
+                         .map(|ring| ring.reduce_precision(decimals))
+                         .filter(is_ring)
+                         .collect())
+    }
+}
+
+impl<T> ReducePrecision<T> for MultiPolygon<T>
+    where T: CoordFloat
+{
+    fn reduce_precision(&self, decimals: u32) -> Self {
+        MultiPolygon(self.0
+                         .iter()
+                         .map(|poly| poly.reduce_precision(decimals))
+                         .filter(|poly| !poly.exterior.0.is_empty())
+                         .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPolygon};
+    use super::*;
+
+    #[test]
+    fn point_test() {
+        let p = Point::new(1.23456789, 2.3456789);
+        assert_eq!(p.reduce_precision(3), Point::new(1.235, 2.346));
+        assert_eq!(p.reduce_precision(0), Point::new(1.0, 2.0));
+    }
+    #[test]
+    fn ring_stays_closed_test() {
+        let exterior = LineString(vec![Point::new(0.00001, 0.00002),
+                                       Point::new(1.00004, 0.0),
+                                       Point::new(1.00001, 0.00001),
+                                       Point::new(1.0, 1.0),
+                                       Point::new(0.0, 1.00004),
+                                       Point::new(0.00001, 0.00002)]);
+        let reduced = Polygon::new(exterior, vec![]).reduce_precision(3);
+        assert_eq!(reduced.exterior.0,
+                   vec![Point::new(0.0, 0.0),
+                        Point::new(1.0, 0.0),
+                        Point::new(1.0, 1.0),
+                        Point::new(0.0, 1.0),
+                        Point::new(0.0, 0.0)]);
+        assert_eq!(reduced.exterior.0.first(), reduced.exterior.0.last());
+    }
+    #[test]
+    fn collapsed_rings_test() {
+        let square = |min: f64, max: f64| {
+            LineString(vec![Point::new(min, min), Point::new(max, min), Point::new(max, max),
+                            Point::new(min, max), Point::new(min, min)])
+        };
+        let poly = Polygon::new(square(0., 10.), vec![square(2., 4.), square(5.001, 5.002)]);
+        let reduced = poly.reduce_precision(1);
+        assert_eq!(reduced, Polygon::new(square(0., 10.), vec![square(2., 4.)]));
+        let tiny = Polygon::new(square(5.001, 5.002), vec![]);
+        assert!(tiny.reduce_precision(1).exterior.0.is_empty());
+        let mp = MultiPolygon(vec![tiny, poly]);
+        assert_eq!(mp.reduce_precision(1), MultiPolygon(vec![reduced]));
+    }    #[test]
+    fn flattened_rings_test() {
+        let p = |x, y| Point::new(x, y);
+        // rounds to A-B-A-B-A, which repeats two points without enclosing anything
+        let zigzag = Polygon::new(LineString(vec![p(0., 0.), p(1., 0.), p(0., 0.01), p(1., 0.01), p(0., 0.)]),
+                                  vec![]);
+        assert!(zigzag.reduce_precision(1).exterior.0.is_empty());
+        // rounds to three distinct, but collinear, points
+        let sliver = Polygon::new(LineString(vec![p(0., 0.), p(1., 0.01), p(2., 0.), p(0., 0.)]), vec![]);
+        assert!(sliver.reduce_precision(1).exterior.0.is_empty());
+    }
+}
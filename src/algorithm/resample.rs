@@ -0,0 +1,99 @@
+use types::{CoordFloat, Point, LineString};
+use algorithm::distance::Distance;
+
+pub trait Resample<T> {
+    /// Returns a `LineString` with `n` points, evenly spaced along the length of the original.
+    ///
+    /// The first and last points are always kept. If `n` is less than 2, only the endpoints
+    /// are returned.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::resample::Resample;
+    ///
+    /// let ls = LineString(vec![Point::new(0.0, 0.0), Point::new(3.0, 0.0), Point::new(3.0, 1.0)]);
+    /// let resampled = ls.resample(3);
+    /// assert_eq!(resampled.0, vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(3.0, 1.0)]);
+    /// ```
+    fn resample(&self, n: usize) -> LineString<T> where T: CoordFloat;
+}
+
+impl<T> Resample<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn resample(&self, n: usize) -> LineString<T> {
+        let (first, last) = match (self.0.first(), self.0.last()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => return LineString(vec![]),
+        };
+        if self.0.len() == 1 {
+            return LineString(vec![first]);
+        }
+        if n < 2 {
+            return LineString(vec![first, last]);
+        }
+        let lengths: Vec<T> = self.0.windows(2).map(|ps| ps[0].distance(&ps[1])).collect();
+        let total = lengths.iter().fold(T::zero(), |sum, &len| sum + len);
+        let step = total / T::from(n - 1).unwrap();
+
+        let mut points = Vec::with_capacity(n);
+        points.push(first);
+        // index of the current segment, and the distance along the line at its start
+        let mut segment = 0;
+        let mut travelled = T::zero();
+        for i in 1..(n - 1) {
+            let target = step * T::from(i).unwrap();
+            while segment < lengths.len() - 1 && travelled + lengths[segment] < target {
+                travelled = travelled + lengths[segment];
+                segment += 1;
+            }
+            let (start, end) = (self.0[segment], self.0[segment + 1]);
+            let len = lengths[segment];
+            let t = if len > T::zero() {
+                ((target - travelled) / len).min(T::one())
+            } else {
+                T::zero()
+            };
+            points.push(Point::new(start.x() + t * (end.x() - start.x()),
+                                   start.y() + t * (end.y() - start.y())));
+        }
+        points.push(last);
+        LineString(points)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use super::*;
+
+    #[test]
+    fn straight_line_test() {
+        let ls = LineString(vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)]);
+        let resampled = ls.resample(6);
+        let xs: Vec<f64> = resampled.0.iter().map(|p| p.x()).collect();
+        assert_eq!(xs, vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+    }
+    #[test]
+    fn bent_line_test() {
+        let ls = LineString(vec![Point::new(0.0, 0.0),
+                                 Point::new(1.0, 0.0),
+                                 Point::new(1.0, 0.5),
+                                 Point::new(1.0, 3.0)]);
+        let resampled = ls.resample(5);
+        assert_eq!(resampled.0,
+                   vec![Point::new(0.0, 0.0),
+                        Point::new(1.0, 0.0),
+                        Point::new(1.0, 1.0),
+                        Point::new(1.0, 2.0),
+                        Point::new(1.0, 3.0)]);
+    }
+    #[test]
+    fn few_points_test() {
+        let ls = LineString(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(2.0, 0.0)]);
+        assert_eq!(ls.resample(0).0, vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0)]);
+        assert_eq!(ls.resample(1).0, vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0)]);
+        assert!(LineString::<f64>(vec![]).resample(4).0.is_empty());
+        assert_eq!(LineString(vec![Point::new(1.0, 1.0)]).resample(4).0, vec![Point::new(1.0, 1.0)]);
+    }
+}
@@ -0,0 +1,272 @@
+use types::{CoordFloat, Point, Line, LineString, MultiLineString};
+use constants::MEAN_EARTH_RADIUS;
+use algorithm::bearing::normalize_bearing;
+
+fn mean_earth_radius<T>() -> T
+    where T: CoordFloat
+{
+    T::from(MEAN_EARTH_RADIUS).unwrap()
+}
+
+// the difference between two longitudes in radians, taking the shorter way around the globe
+fn longitude_delta<T>(from: T, to: T) -> T
+    where T: CoordFloat
+{
+    let pi = T::from(::std::f64::consts::PI).unwrap();
+    let mut delta = (to - from).to_radians();
+    if delta > pi {
+        delta = delta - (pi + pi);
+    } else if delta < -pi {
+        delta = delta + (pi + pi);
+    }
+    delta
+}
+
+// the difference between two latitudes (in radians) stretched by the Mercator projection
+fn projected_latitude_delta<T>(lat1: T, lat2: T) -> T
+    where T: CoordFloat
+{
+    let quarter_pi = T::from(::std::f64::consts::FRAC_PI_4).unwrap();
+    let two = T::one() + T::one();
+    ((quarter_pi + lat2 / two).tan() / (quarter_pi + lat1 / two).tan()).ln()
+}
+
+// the ratio of the latitude delta to the projected latitude delta,
+// which tends to cos(lat) along a parallel; below 1e-12 radians of projected latitude
+// the division loses precision, so the limit is used instead
+fn stretch_factor<T>(lat1: T, delta_lat: T, delta_projected: T) -> T
+    where T: CoordFloat
+{
+    if delta_projected.abs() > T::from(1e-12).unwrap() {
+        delta_lat / delta_projected
+    } else {
+        lat1.cos()
+    }
+}
+
+pub trait RhumbDestination<T: CoordFloat> {
+    /// Returns the point reached by travelling `distance` meters from the existing Point,
+    /// holding the constant `bearing` (in degrees clockwise from north) of a rhumb line.
+    ///
+    /// See: https://www.movable-type.co.uk/scripts/latlong.html#rhumblines
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::Point;
+    /// use geo::algorithm::rhumb::RhumbDestination;
+    ///
+    /// # fn main() {
+    /// // heading due east along the equator
+    /// let p = Point::<f64>::new(0., 0.).rhumb_destination(90., 111194.92664455874);
+    /// assert_relative_eq!(p.x(), 1., epsilon = 1.0e-9);
+    /// assert_relative_eq!(p.y(), 0., epsilon = 1.0e-9);
+    /// # }
+    /// ```
+    fn rhumb_destination(&self, bearing: T, distance: T) -> Point<T>;
+}
+
+impl<T> RhumbDestination<T> for Point<T>
+    where T: CoordFloat
+{
+    fn rhumb_destination(&self, bearing: T, distance: T) -> Point<T> {
+        let half_pi = T::from(::std::f64::consts::FRAC_PI_2).unwrap();
+        let pi = half_pi + half_pi;
+        let bearing_rad = bearing.to_radians();
+        let angular_distance = distance / mean_earth_radius();
+
+        let lat1 = self.y().to_radians();
+        let delta_lat = angular_distance * bearing_rad.cos();
+        let mut lat2 = lat1 + delta_lat;
+        // travelling past a pole comes back down the other side
+        if lat2 > half_pi {
+            lat2 = pi - lat2;
+        } else if lat2 < -half_pi {
+            lat2 = -pi - lat2;
+        }
+
+        let delta_projected = projected_latitude_delta(lat1, lat2);
+        let q = stretch_factor(lat1, delta_lat, delta_projected);
+        let delta_lng = angular_distance * bearing_rad.sin() / q;
+
+        let mut lng = self.x() + delta_lng.to_degrees();
+        let (half_turn, full_turn) = (T::from(180.).unwrap(), T::from(360.).unwrap());
+        if lng > half_turn {
+            lng = lng - full_turn;
+        } else if lng < -half_turn {
+            lng = lng + full_turn;
+        }
+        Point::new(lng, lat2.to_degrees())
+    }
+}
+
+pub trait RhumbDistance<T, Rhs = Self> {
+    /// Returns the distance in meters along the rhumb line (loxodrome) between two points,
+    /// i.e. the path that crosses every meridian at the same angle.
+    ///
+    /// A rhumb line is never shorter than the great circle between the same points.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::Point;
+    /// use geo::algorithm::rhumb::RhumbDistance;
+    ///
+    /// # fn main() {
+    /// let a = Point::<f64>::new(0., 0.);
+    /// assert_relative_eq!(a.rhumb_distance(&Point::new(0., 1.)), 111194.92664455874, epsilon = 1.0e-6);
+    /// # }
+    /// ```
+    fn rhumb_distance(&self, rhs: &Rhs) -> T;
+}
+
+impl<T> RhumbDistance<T, Point<T>> for Point<T>
+    where T: CoordFloat
+{
+    fn rhumb_distance(&self, rhs: &Point<T>) -> T {
+        let lat1 = self.y().to_radians();
+        let lat2 = rhs.y().to_radians();
+        let delta_lat = lat2 - lat1;
+        let delta_lng = longitude_delta(self.x(), rhs.x());
+        let delta_projected = projected_latitude_delta(lat1, lat2);
+        let q = stretch_factor(lat1, delta_lat, delta_projected);
+        (delta_lat * delta_lat + q * q * delta_lng * delta_lng).sqrt() * mean_earth_radius()
+    }
+}
+
+pub trait RhumbBearing<T: CoordFloat> {
+    /// Returns the constant bearing of the rhumb line from the existing Point to `rhs`,
+    /// in degrees clockwise from north, in the range `[0, 360)`.
+    ///
+    /// Unlike the initial bearing of a great circle, this is the heading held for the whole
+    /// way; two points on the same parallel are always due east or west of each other.
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::rhumb::RhumbBearing;
+    ///
+    /// let a = Point::<f64>::new(-10., 50.);
+    /// assert_eq!(a.rhumb_bearing(&Point::new(10., 50.)), 90.);
+    /// assert_eq!(a.rhumb_bearing(&Point::new(-10., 40.)), 180.);
+    /// ```
+    fn rhumb_bearing(&self, rhs: &Point<T>) -> T;
+}
+
+impl<T> RhumbBearing<T> for Point<T>
+    where T: CoordFloat
+{
+    fn rhumb_bearing(&self, rhs: &Point<T>) -> T {
+        let delta_lng = longitude_delta(self.x(), rhs.x());
+        let delta_projected = projected_latitude_delta(self.y().to_radians(), rhs.y().to_radians());
+        normalize_bearing(delta_lng.atan2(delta_projected).to_degrees())
+    }
+}
+
+pub trait RhumbLength<T> {
+    /// Returns the length in meters of a geometry, following a rhumb line along each segment.
+    ///
+    /// ```
+    /// # extern crate geo;
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::rhumb::RhumbLength;
+    ///
+    /// # fn main() {
+    /// let ls = LineString(vec![Point::<f64>::new(0., 0.), Point::new(1., 0.), Point::new(1., 1.)]);
+    /// assert_relative_eq!(ls.rhumb_length(), 2. * 111194.92664455874, epsilon = 1.0e-6);
+    /// # }
+    /// ```
+    fn rhumb_length(&self) -> T;
+}
+
+impl<T> RhumbLength<T> for Line<T>
+    where T: CoordFloat
+{
+    fn rhumb_length(&self) -> T {
+        self.start.rhumb_distance(&self.end)
+    }
+}
+
+impl<T> RhumbLength<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn rhumb_length(&self) -> T {
+        self.0
+            .windows(2)
+            .fold(T::zero(), |total, ps| total + ps[0].rhumb_distance(&ps[1]))
+    }
+}
+
+impl<T> RhumbLength<T> for MultiLineString<T>
+    where T: CoordFloat
+{
+    fn rhumb_length(&self) -> T {
+        self.0.iter().fold(T::zero(), |total, ls| total + ls.rhumb_length())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, MultiLineString};
+    use algorithm::haversine_distance::HaversineDistance;
+    use algorithm::cross_track_distance::initial_bearing;
+    use super::*;
+
+    #[test]
+    fn destination_round_trip_test() {
+        let start = Point::<f64>::new(-0.1278, 51.5074);
+        for &bearing in &[10., 45., 135., 200., 300.] {
+            let end = start.rhumb_destination(bearing, 500000.);
+            assert_relative_eq!(start.rhumb_distance(&end), 500000., epsilon = 1.0e-6);
+            assert_relative_eq!(end.rhumb_distance(&start), 500000., epsilon = 1.0e-6);
+            // the great circle is a shortcut
+            assert!(start.haversine_distance(&end) < 500000.);
+        }
+    }
+    #[test]
+    fn due_north_matches_great_circle_test() {
+        let a = Point::<f64>::new(10., 20.);
+        let b = a.rhumb_destination(0., 100000.);
+        assert_relative_eq!(b.x(), 10., epsilon = 1.0e-9);
+        assert_relative_eq!(a.rhumb_distance(&b), a.haversine_distance(&b), epsilon = 1.0e-6);
+    }
+    #[test]
+    fn antimeridian_test() {
+        let a = Point::<f64>::new(179.5, 0.);
+        let b = a.rhumb_destination(90., 111194.92664455874);
+        assert_relative_eq!(b.x(), -179.5, epsilon = 1.0e-9);
+        assert_relative_eq!(a.rhumb_distance(&b), 111194.92664455874, epsilon = 1.0e-6);
+    }
+    #[test]
+    fn bearing_along_parallel_test() {
+        let a = Point::<f64>::new(-30., 45.);
+        let b = Point::<f64>::new(30., 45.);
+        assert_eq!(a.rhumb_bearing(&b), 90.);
+        assert_eq!(b.rhumb_bearing(&a), 270.);
+        // the great circle starts off heading north of east
+        let great_circle = initial_bearing(&a, &b).to_degrees();
+        assert!(great_circle < 80.);
+    }
+    #[test]
+    fn bearing_round_trip_test() {
+        let a = Point::<f64>::new(2.35, 48.85);
+        for &bearing in &[0., 30., 135., 260.] {
+            let b = a.rhumb_destination(bearing, 250000.);
+            assert_relative_eq!(a.rhumb_bearing(&b), bearing, epsilon = 1.0e-9);
+        }
+    }
+    #[test]
+    fn length_test() {
+        let a = Point::<f64>::new(2.35, 48.85);
+        let b = a.rhumb_destination(60., 10000.);
+        let c = b.rhumb_destination(150., 20000.);
+        let ls = LineString(vec![a, b, c]);
+        assert_relative_eq!(ls.rhumb_length(), 30000., epsilon = 1.0e-6);
+        let mls = MultiLineString(vec![ls.clone(), ls]);
+        assert_relative_eq!(mls.rhumb_length(), 60000., epsilon = 1.0e-6);
+        assert_eq!(LineString::<f64>(vec![]).rhumb_length(), 0.);
+    }
+}
@@ -1,15 +1,13 @@
-use num_traits::{Float, FromPrimitive};
-use types::{Point, Line, Polygon, LineString, MultiPoint, MultiPolygon, MultiLineString};
+use types::{CoordFloat, Point, Line, Polygon, LineString, MultiPoint, MultiPolygon, MultiLineString};
 use algorithm::centroid::Centroid;
 
-// rotate a slice of points "angle" degrees about an origin
+// rotate a slice of points "angle" radians about an origin
 // origin can be an arbitrary point, pass &Point::new(0., 0.)
 // for the actual origin
 fn rotation_matrix<T>(angle: T, origin: &Point<T>, points: &[Point<T>]) -> Vec<Point<T>>
-    where T: Float
+    where T: CoordFloat
 {
-    let cos_theta = angle.to_radians().cos();
-    let sin_theta = angle.to_radians().sin();
+    let (sin_theta, cos_theta) = angle.sin_cos();
     let x0 = origin.x();
     let y0 = origin.y();
     points
@@ -45,7 +43,29 @@ pub trait Rotate<T> {
     /// let correct_ls = LineString(correct);
     /// assert_eq!(rotated, correct_ls);
     /// ```
-    fn rotate(&self, angle: T) -> Self where T: Float;
+    fn rotate(&self, angle: T) -> Self
+        where T: CoordFloat,
+              Self: Sized
+    {
+        self.rotate_radians(angle.to_radians())
+    }
+
+    /// Rotate a Geometry around its centroid by an angle, in radians
+    ///
+    /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::rotate::{Rotate};
+    ///
+    /// let linestring = LineString(vec![Point::new(0.0, 0.0), Point::new(10.0, 10.0)]);
+    /// let rotated = linestring.rotate_radians(-PI / 4.0);
+    ///
+    /// assert!((rotated.0[0].x() + 2.0710678118654755).abs() < 1e-9);
+    /// assert!((rotated.0[0].y() - 5.0).abs() < 1e-9);
+    /// ```
+    fn rotate_radians(&self, angle: T) -> Self where T: CoordFloat;
 }
 
 pub trait RotatePoint<T> {
@@ -70,32 +90,42 @@ pub trait RotatePoint<T> {
     /// let correct_ls = LineString(correct);
     /// assert_eq!(rotated, correct_ls);
     /// ```
-    fn rotate_around_point(&self, angle: T, point: &Point<T>) -> Self where T: Float;
+    fn rotate_around_point(&self, angle: T, point: &Point<T>) -> Self
+        where T: CoordFloat,
+              Self: Sized
+    {
+        self.rotate_radians_around_point(angle.to_radians(), point)
+    }
+
+    /// Rotate a Geometry around an arbitrary point by an angle, given in radians
+    ///
+    /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
+    fn rotate_radians_around_point(&self, angle: T, point: &Point<T>) -> Self where T: CoordFloat;
 }
 
 impl<T> Rotate<T> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
-    /// Rotate the Point about itself by the given number of degrees
+    /// Rotate the Point about itself by the given angle, in radians
     /// This operation leaves the point coordinates unchanged
-    fn rotate(&self, angle: T) -> Self {
+    fn rotate_radians(&self, angle: T) -> Self {
         rotation_matrix(angle, &self.centroid().unwrap(), &[*self])[0]
     }
 }
 
 impl<T> RotatePoint<T> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
-    /// Rotate the Point about another point by the given number of degrees
-    fn rotate_around_point(&self, angle: T, point: &Point<T>) -> Self {
+    /// Rotate the Point about another point by the given angle, in radians
+    fn rotate_radians_around_point(&self, angle: T, point: &Point<T>) -> Self {
         rotation_matrix(angle, point, &[*self])[0]
     }
 }
 
 impl<T> Rotate<T> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
-    fn rotate(&self, angle: T) -> Self {
+    fn rotate_radians(&self, angle: T) -> Self {
         let pts = vec![self.start, self.end];
         let rotated = rotation_matrix(angle, &self.centroid().unwrap(), &pts);
         Line::new(rotated[0], rotated[1])
@@ -103,9 +133,9 @@ impl<T> Rotate<T> for Line<T>
 }
 
 impl<T> RotatePoint<T> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
-    fn rotate_around_point(&self, angle: T, point: &Point<T>) -> Self {
+    fn rotate_radians_around_point(&self, angle: T, point: &Point<T>) -> Self {
         let pts = vec![self.start, self.end];
         let rotated = rotation_matrix(angle, point, &pts);
         Line::new(rotated[0], rotated[1])
@@ -113,28 +143,28 @@ impl<T> RotatePoint<T> for Line<T>
 }
 
 impl<T> Rotate<T> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
-    /// Rotate the LineString about its centroid by the given number of degrees
-    fn rotate(&self, angle: T) -> Self {
+    /// Rotate the LineString about its centroid by the given angle, in radians
+    fn rotate_radians(&self, angle: T) -> Self {
         LineString(rotation_matrix(angle, &self.centroid().unwrap(), &self.0))
     }
 }
 
 impl<T> RotatePoint<T> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
-    /// Rotate the LineString about a point by the given number of degrees
-    fn rotate_around_point(&self, angle: T, point: &Point<T>) -> Self {
+    /// Rotate the LineString about a point by the given angle, in radians
+    fn rotate_radians_around_point(&self, angle: T, point: &Point<T>) -> Self {
         LineString(rotation_matrix(angle, point, &self.0))
     }
 }
 
 impl<T> Rotate<T> for Polygon<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
-    /// Rotate the Polygon about its centroid by the given number of degrees
-    fn rotate(&self, angle: T) -> Self {
+    /// Rotate the Polygon about its centroid by the given angle, in radians
+    fn rotate_radians(&self, angle: T) -> Self {
         // if a polygon has holes, use the centroid of its outer shell as the rotation origin
         let centroid = match self.interiors.is_empty() {
             false => self.exterior.centroid().unwrap(),
@@ -149,79 +179,79 @@ impl<T> Rotate<T> for Polygon<T>
 }
 
 impl<T> RotatePoint<T> for Polygon<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
-    /// Rotate the Polygon about a given point by the given number of degrees
-    fn rotate_around_point(&self, angle: T, point: &Point<T>) -> Self {
+    /// Rotate the Polygon about a given point by the given angle, in radians
+    fn rotate_radians_around_point(&self, angle: T, point: &Point<T>) -> Self {
         Polygon::new(LineString(rotation_matrix(angle, point, &self.exterior.0)),
                      self.interiors
                          .iter()
-                         .map(|ring| ring.rotate_around_point(angle, point))
+                         .map(|ring| ring.rotate_radians_around_point(angle, point))
                          .collect())
     }
 }
 
 impl<T> RotatePoint<T> for MultiPolygon<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
-    /// Rotate the contained Polygons about a given point by the given number of degrees
-    fn rotate_around_point(&self, angle: T, point: &Point<T>) -> Self {
+    /// Rotate the contained Polygons about a given point by the given angle, in radians
+    fn rotate_radians_around_point(&self, angle: T, point: &Point<T>) -> Self {
         MultiPolygon(self.0
                          .iter()
-                         .map(|poly| poly.rotate_around_point(angle, point))
+                         .map(|poly| poly.rotate_radians_around_point(angle, point))
                          .collect())
     }
 }
 
 impl<T> Rotate<T> for MultiPolygon<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
-    /// Rotate the contained Polygons about their centroids by the given number of degrees
-    fn rotate(&self, angle: T) -> Self {
-        MultiPolygon(self.0.iter().map(|poly| poly.rotate(angle)).collect())
+    /// Rotate the contained Polygons about their centroids by the given angle, in radians
+    fn rotate_radians(&self, angle: T) -> Self {
+        MultiPolygon(self.0.iter().map(|poly| poly.rotate_radians(angle)).collect())
     }
 }
 
 impl<T> RotatePoint<T> for MultiLineString<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
-    /// Rotate the contained LineStrings about a given point by the given number of degrees
-    fn rotate_around_point(&self, angle: T, point: &Point<T>) -> Self {
+    /// Rotate the contained LineStrings about a given point by the given angle, in radians
+    fn rotate_radians_around_point(&self, angle: T, point: &Point<T>) -> Self {
         MultiLineString(self.0
                          .iter()
-                         .map(|ls| ls.rotate_around_point(angle, point))
+                         .map(|ls| ls.rotate_radians_around_point(angle, point))
                          .collect())
     }
 }
 
 impl<T> Rotate<T> for MultiLineString<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
-    /// Rotate the contained LineStrings about their centroids by the given number of degrees
-    fn rotate(&self, angle: T) -> Self {
-        MultiLineString(self.0.iter().map(|ls| ls.rotate(angle)).collect())
+    /// Rotate the contained LineStrings about their centroids by the given angle, in radians
+    fn rotate_radians(&self, angle: T) -> Self {
+        MultiLineString(self.0.iter().map(|ls| ls.rotate_radians(angle)).collect())
     }
 }
 
 impl<T> RotatePoint<T> for MultiPoint<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
-    /// Rotate the contained Points about a given point by the given number of degrees
+    /// Rotate the contained Points about a given point by the given angle, in radians
     /// This operation leaves the point coordinates unchanged
-    fn rotate_around_point(&self, angle: T, point: &Point<T>) -> Self {
+    fn rotate_radians_around_point(&self, angle: T, point: &Point<T>) -> Self {
         MultiPoint(self.0
                          .iter()
-                         .map(|p| p.rotate_around_point(angle, point))
+                         .map(|p| p.rotate_radians_around_point(angle, point))
                          .collect())
     }
 }
 
 impl<T> Rotate<T> for MultiPoint<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
-    /// Rotate the contained Points about their centroids by the given number of degrees
-    fn rotate(&self, angle: T) -> Self {
-        MultiPoint(self.0.iter().map(|p| p.rotate(angle)).collect())
+    /// Rotate the contained Points about their centroids by the given angle, in radians
+    fn rotate_radians(&self, angle: T) -> Self {
+        MultiPoint(self.0.iter().map(|p| p.rotate_radians(angle)).collect())
     }
 }
 
@@ -344,4 +374,26 @@ mod test {
         let line1 = Line::new(Point::new(0., 0.), Point::new(-2., 0.00000000000000012246467991473532));
         assert_eq!(line0.rotate_around_point(90., &Point::new(0., 0.)), line1);
     }
+    #[test]
+    fn test_rotate_radians() {
+        use std::f64::consts::PI;
+        let square = Polygon::new(LineString(vec![Point::new(0., 0.), Point::new(2., 0.), Point::new(2., 2.),
+                                                  Point::new(0., 2.), Point::new(0., 0.)]),
+                                  vec![]);
+        let origin = Point::new(-1., 3.);
+        let pairs = vec![(square.rotate(90.), square.rotate_radians(PI / 2.)),
+                         (square.rotate(-30.), square.rotate_radians(-PI / 6.)),
+                         (square.rotate_around_point(90., &origin),
+                          square.rotate_radians_around_point(PI / 2., &origin))];
+        for (degrees, radians) in pairs {
+            for (p, q) in degrees.exterior.0.iter().zip(radians.exterior.0.iter()) {
+                assert_relative_eq!(p.x(), q.x(), epsilon = 1e-12);
+                assert_relative_eq!(p.y(), q.y(), epsilon = 1e-12);
+            }
+        }
+        // a quarter turn of a square about its center maps its corners onto each other
+        let rotated = square.rotate_radians(PI / 2.);
+        assert_relative_eq!(rotated.exterior.0[0].x(), 2., epsilon = 1e-12);
+        assert_relative_eq!(rotated.exterior.0[0].y(), 0., epsilon = 1e-12);
+    }
 }
@@ -0,0 +1,160 @@
+use types::{CoordFloat, Point, LineString, Polygon};
+use algorithm::area::Area;
+use algorithm::contains::Contains;
+use algorithm::is_simple::IsSimple;
+use algorithm::orient::Winding;
+
+// the distinct vertices of a ring, counter-clockwise, without the closing point
+fn ccw_vertices<T>(ring: &LineString<T>) -> Vec<Point<T>>
+    where T: CoordFloat
+{
+    let mut ring = ring.clone();
+    ring.make_ccw_winding();
+    let mut vertices = ring.0;
+    vertices.dedup();
+    while vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    vertices
+}
+
+// the unit normal pointing to the left of the edge from a to b
+fn left_normal<T>(a: &Point<T>, b: &Point<T>) -> Point<T>
+    where T: CoordFloat
+{
+    let d = *b - *a;
+    let length = d.x().hypot(d.y());
+    Point::new(-d.y() / length, d.x() / length)
+}
+
+// move every edge of a counter-clockwise ring `distance` to its left, mitering the corners.
+// Returns None if any edge is reversed or shrinks to nothing, i.e. the ring collapses.
+fn offset_ring<T>(vertices: &[Point<T>], distance: T) -> Option<LineString<T>>
+    where T: CoordFloat
+{
+    let n = vertices.len();
+    if n < 3 {
+        return None;
+    }
+    let mut offset = Vec::with_capacity(n + 1);
+    for i in 0..n {
+        let (prev, curr, next) = (vertices[(i + n - 1) % n], vertices[i], vertices[(i + 1) % n]);
+        let (n1, n2) = (left_normal(&prev, &curr), left_normal(&curr, &next));
+        // the corner moves along the bisector of the two normals, far enough that both
+        // adjacent edges move by `distance`
+        let bisector = n1 + n2;
+        let cos_half = bisector.dot(&n1);
+        let corner = if cos_half <= T::epsilon() {
+            // the edge doubles back on itself; leave it to the reversal check below
+            curr + Point::new(n1.x() * distance, n1.y() * distance)
+        } else {
+            let scale = distance / cos_half;
+            curr + Point::new(bisector.x() * scale, bisector.y() * scale)
+        };
+        offset.push(corner);
+    }
+    for i in 0..n {
+        let j = (i + 1) % n;
+        let before = vertices[j] - vertices[i];
+        let after = offset[j] - offset[i];
+        if before.dot(&after) <= T::zero() {
+            return None;
+        }
+    }
+    let first = offset[0];
+    offset.push(first);
+    let ring = LineString(offset);
+    if ring.is_simple() { Some(ring) } else { None }
+}
+
+pub trait Shrink<T> {
+    /// Returns a polygon shrunk inwards by `distance`, or `None` if it would collapse.
+    ///
+    /// Every edge of the exterior ring moves `distance` inwards, and every edge of the interior
+    /// rings moves `distance` outwards, with corners mitered. This is exact for convex polygons.
+    /// `None` is returned if an edge would be reversed or vanish, if a ring would cross itself,
+    /// or if a grown interior ring would no longer fit inside the exterior ring.
+    ///
+    /// ```
+    /// use geo::Bbox;
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::shrink::Shrink;
+    ///
+    /// let square = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. }.to_polygon();
+    ///
+    /// assert_eq!(square.shrink(1.).unwrap().area(), 64.);
+    /// assert!(square.shrink(6.).is_none());
+    /// ```
+    fn shrink(&self, distance: T) -> Option<Polygon<T>> where T: CoordFloat;
+}
+
+impl<T> Shrink<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn shrink(&self, distance: T) -> Option<Polygon<T>> {
+        let exterior = offset_ring(&ccw_vertices(&self.exterior), distance)?;
+        let outer = Polygon::new(exterior.clone(), vec![]);
+        let mut interiors = Vec::with_capacity(self.interiors.len());
+        for ring in &self.interiors {
+            let mut grown = offset_ring(&ccw_vertices(ring), -distance)?;
+            if !grown.0.iter().all(|p| outer.contains(p)) {
+                return None;
+            }
+            grown.make_cw_winding();
+            interiors.push(grown);
+        }
+        // the interiors are wound clockwise, so subtract their magnitudes explicitly
+        let area = interiors
+            .iter()
+            .fold(outer.area(), |total, ring| total - Polygon::new(ring.clone(), vec![]).area().abs());
+        if area <= T::zero() {
+            return None;
+        }
+        Some(Polygon::new(exterior, interiors))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, Bbox};
+    use algorithm::area::Area;
+    use super::*;
+
+    #[test]
+    fn square_test() {
+        let square = Bbox { xmin: 0f64, xmax: 10., ymin: 0., ymax: 10. }.to_polygon();
+        let inset = square.shrink(3.).unwrap();
+        assert_relative_eq!(inset.area(), 16.);
+        assert_eq!(inset.exterior.0.len(), 5);
+        for p in &inset.exterior.0 {
+            assert_relative_eq!(p.x().min(10. - p.x()), 3.);
+            assert_relative_eq!(p.y().min(10. - p.y()), 3.);
+        }
+        assert!(square.shrink(6.).is_none());
+        // shrinking to a single point is a collapse too
+        assert!(square.shrink(5.).is_none());
+    }
+    #[test]
+    fn clockwise_triangle_test() {
+        // a right triangle with legs of 12, wound clockwise
+        let p = |x, y| Point::new(x, y);
+        let triangle = Polygon::new(LineString(vec![p(0., 0.), p(0., 12.), p(12., 0.), p(0., 0.)]), vec![]);
+        let inset = triangle.shrink(1.).unwrap();
+        // the inset of a triangle is similar to it, with the same incenter
+        let inradius = 12. / (2. + 2f64.sqrt());
+        let scale = (inradius - 1.) / inradius;
+        assert_relative_eq!(inset.area(), 72. * scale * scale, epsilon = 1e-9);
+        assert!(triangle.shrink(inradius + 0.01).is_none());
+    }
+    #[test]
+    fn hole_test() {
+        let exterior = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. }.to_line_string();
+        let hole = Bbox { xmin: 4., xmax: 6., ymin: 4., ymax: 6. }.to_line_string();
+        let poly = Polygon::new(exterior, vec![hole]);
+        let inset = poly.shrink(1.).unwrap();
+        // the exterior becomes 8x8, the hole 4x4
+        assert_relative_eq!(Polygon::new(inset.exterior.clone(), vec![]).area(), 64.);
+        assert_relative_eq!(Polygon::new(inset.interiors[0].clone(), vec![]).area(), -16.);
+        assert!(poly.shrink(2.).is_none());
+    }
+}
@@ -0,0 +1,67 @@
+use types::{CoordFloat, Point, Polygon};
+use algorithm::contains::Contains;
+use algorithm::distance::Distance;
+
+pub trait SignedDistance<T> {
+    /// Returns the distance from a point to the boundary of a geometry, negative if the
+    /// point is inside it and positive if it is outside.
+    ///
+    /// Points inside a `Polygon`'s interior rings are outside the filled region, so
+    /// their distance is positive. Points on the boundary have a distance of `0.0`.
+    ///
+    /// ```
+    /// use geo::{Point, Bbox};
+    /// use geo::algorithm::signed_distance::SignedDistance;
+    ///
+    /// let square = Bbox { xmin: 0., xmax: 4., ymin: 0., ymax: 4. }.to_polygon();
+    ///
+    /// assert_eq!(square.signed_distance(&Point::new(1., 2.)), -1.);
+    /// assert_eq!(square.signed_distance(&Point::new(7., 2.)), 3.);
+    /// ```
+    fn signed_distance(&self, p: &Point<T>) -> T where T: CoordFloat;
+}
+
+impl<T> SignedDistance<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn signed_distance(&self, p: &Point<T>) -> T {
+        let distance = self.interiors
+            .iter()
+            .fold(p.distance(&self.exterior), |d, ring| d.min(p.distance(ring)));
+        if self.contains(p) { -distance } else { distance }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, Polygon, Bbox};
+    use super::*;
+
+    fn holed() -> Polygon<f64> {
+        let exterior = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. }.to_line_string();
+        let hole = Bbox { xmin: 4., xmax: 6., ymin: 4., ymax: 6. }.to_line_string();
+        Polygon::new(exterior, vec![hole])
+    }
+
+    #[test]
+    fn inside_test() {
+        assert_relative_eq!(holed().signed_distance(&Point::new(1., 5.)), -1.);
+        // nearer the hole than the exterior
+        assert_relative_eq!(holed().signed_distance(&Point::new(3., 5.)), -1.);
+    }
+    #[test]
+    fn boundary_test() {
+        assert_relative_eq!(holed().signed_distance(&Point::new(0., 5.)), 0.);
+        assert_relative_eq!(holed().signed_distance(&Point::new(4., 5.)), 0.);
+    }
+    #[test]
+    fn outside_test() {
+        assert_relative_eq!(holed().signed_distance(&Point::new(-2., 5.)), 2.);
+        assert_relative_eq!(holed().signed_distance(&Point::new(13., 14.)), 5.);
+    }
+    #[test]
+    fn in_hole_test() {
+        assert_relative_eq!(holed().signed_distance(&Point::new(5., 5.)), 1.);
+        assert_relative_eq!(holed().signed_distance(&Point::new(5.5, 5.)), 0.5);
+    }
+}
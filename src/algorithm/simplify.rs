@@ -1,10 +1,10 @@
-use num_traits::Float;
-use types::{Point, LineString};
+use std::collections::HashSet;
+use types::{CoordFloat, Point, LineString, MultiLineString, Polygon, HashablePoint};
 use algorithm::distance::Distance;
 
 // perpendicular distance from a point to a line
 fn point_line_distance<T>(point: &Point<T>, start: &Point<T>, end: &Point<T>) -> T
-    where T: Float
+    where T: CoordFloat
 {
     if start == end {
         point.distance(start)
@@ -18,33 +18,124 @@ fn point_line_distance<T>(point: &Point<T>, start: &Point<T>, end: &Point<T>) ->
 }
 
 // Ramer–Douglas-Peucker line simplification algorithm
-fn rdp<T>(points: &[Point<T>], epsilon: &T) -> Vec<Point<T>>
-    where T: Float
+// Returns the indices of the points that are retained
+// The ranges still to be split are kept on an explicit stack rather than by recursion,
+// so that long linestrings can't overflow the call stack
+fn rdp_indices<T>(points: &[Point<T>], epsilon: &T) -> Vec<usize>
+    where T: CoordFloat
 {
     if points.is_empty() {
+        return vec![];
+    }
+    let last = points.len() - 1;
+    let mut kept = vec![false; points.len()];
+    let mut ranges = vec![(0, last)];
+    while let Some((start, end)) = ranges.pop() {
+        let mut dmax = T::zero();
+        let mut index = start;
+        for (i, point) in points.iter().enumerate().take(end).skip(start + 1) {
+            let distance = point_line_distance(point, &points[start], &points[end]);
+            if distance > dmax {
+                index = i;
+                dmax = distance;
+            }
+        }
+        if dmax > *epsilon {
+            kept[index] = true;
+            ranges.push((start, index));
+            ranges.push((index, end));
+        }
+    }
+    ::std::iter::once(0)
+        .chain((1..last).filter(|&i| kept[i]))
+        .chain(::std::iter::once(last))
+        .collect()
+}
+
+// Ramer–Douglas-Peucker, refining the segment with the furthest point first,
+// and carrying on past epsilon until at least min_points are retained
+fn rdp_min_points<T>(points: &[Point<T>], epsilon: &T, min_points: usize) -> Vec<Point<T>>
+    where T: CoordFloat
+{
+    if points.len() <= min_points.max(2) {
         return points.to_vec();
     }
-    let mut dmax = T::zero();
-    let mut index: usize = 0;
-    let mut distance: T;
-
-    for (i, _) in points.iter().enumerate().take(points.len() - 1).skip(1) {
-        distance = point_line_distance(&points[i],
-                                       &points[0],
-                                       &*points.last().unwrap());
-        if distance > dmax {
-            index = i;
-            dmax = distance;
+    // the furthest point from the segment (start, end), and its distance
+    let furthest = |start: usize, end: usize| {
+        (start + 1..end)
+            .map(|i| (i, point_line_distance(&points[i], &points[start], &points[end])))
+            .fold(None, |max: Option<(usize, T)>, (i, d)| match max {
+                Some((_, dmax)) if dmax >= d => max,
+                _ => Some((i, d)),
+            })
+    };
+    let mut kept = vec![0, points.len() - 1];
+    // segments between consecutive kept points which still have points between them
+    let mut candidates: Vec<(usize, usize, usize, T)> = vec![];
+    if let Some((i, d)) = furthest(0, points.len() - 1) {
+        candidates.push((0, points.len() - 1, i, d));
+    }
+    loop {
+        let best = candidates
+            .iter()
+            .enumerate()
+            .fold(None, |best: Option<(usize, T)>, (idx, &(_, _, _, d))| match best {
+                Some((_, dbest)) if dbest >= d => best,
+                _ => Some((idx, d)),
+            });
+        let idx = match best {
+            Some((idx, d)) if d > *epsilon || kept.len() < min_points => idx,
+            _ => break,
+        };
+        let (start, end, split, _) = candidates.swap_remove(idx);
+        kept.push(split);
+        for &(a, b) in &[(start, split), (split, end)] {
+            if let Some((i, d)) = furthest(a, b) {
+                candidates.push((a, b, i, d));
+            }
         }
     }
-    if dmax > *epsilon {
-        let mut intermediate = rdp(&points[..index + 1], &*epsilon);
-        intermediate.pop();
-        intermediate.extend_from_slice(&rdp(&points[index..], &*epsilon));
-        intermediate
-    } else {
-        vec![*points.first().unwrap(), *points.last().unwrap()]
+    kept.sort();
+    kept.iter().map(|&i| points[i]).collect()
+}
+
+// The tolerance below which each point is retained by Ramer–Douglas-Peucker
+// The furthest point of a range doesn't depend on epsilon, so a point is retained exactly
+// when epsilon is less than the distance that split it and the distances that split every
+// range enclosing it. The endpoints, which are always retained, and points never split out
+// are given zero
+fn rdp_thresholds<T>(points: &[Point<T>]) -> Vec<T>
+    where T: CoordFloat
+{
+    let mut thresholds = vec![T::zero(); points.len()];
+    if points.len() < 3 {
+        return thresholds;
     }
+    let mut ranges = vec![(0, points.len() - 1, T::infinity())];
+    while let Some((start, end, bound)) = ranges.pop() {
+        let mut dmax = T::zero();
+        let mut index = start;
+        for (i, point) in points.iter().enumerate().take(end).skip(start + 1) {
+            let distance = point_line_distance(point, &points[start], &points[end]);
+            if distance > dmax {
+                index = i;
+                dmax = distance;
+            }
+        }
+        if dmax > T::zero() {
+            let threshold = dmax.min(bound);
+            thresholds[index] = threshold;
+            ranges.push((start, index, threshold));
+            ranges.push((index, end, threshold));
+        }
+    }
+    thresholds
+}
+
+fn rdp<T>(points: &[Point<T>], epsilon: &T) -> Vec<Point<T>>
+    where T: CoordFloat
+{
+    rdp_indices(points, epsilon).iter().map(|&i| points[i]).collect()
 }
 
 pub trait Simplify<T, Epsilon = T> {
@@ -70,21 +161,179 @@ pub trait Simplify<T, Epsilon = T> {
     /// let simplified = linestring.simplify(&1.0);
     /// assert_eq!(simplified, ls_compare)
     /// ```
-    fn simplify(&self, epsilon: &T) -> Self where T: Float;
+    fn simplify(&self, epsilon: &T) -> Self where T: CoordFloat;
+
+    /// Returns the indices of the points retained by `simplify`, in ascending order.
+    ///
+    /// This is useful for keeping data associated with each point (timestamps, speeds)
+    /// aligned with the simplified geometry.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::simplify::{Simplify};
+    ///
+    /// let mut vec = Vec::new();
+    /// vec.push(Point::new(0.0, 0.0));
+    /// vec.push(Point::new(5.0, 4.0));
+    /// vec.push(Point::new(11.0, 5.5));
+    /// vec.push(Point::new(17.3, 3.2));
+    /// vec.push(Point::new(27.8, 0.1));
+    /// let linestring = LineString(vec);
+    /// let indices = linestring.simplify_idx(&1.0);
+    /// assert_eq!(indices, vec![0, 1, 2, 4]);
+    /// ```
+    fn simplify_idx(&self, epsilon: &T) -> Vec<usize> where T: CoordFloat;
+
+    /// Returns the simplified representation of a LineString, like `simplify`, but never
+    /// keeping fewer than `min_points` points (or all of them, if there are fewer).
+    ///
+    /// Once the tolerance is met, points carry on being added in the order the
+    /// Ramer–Douglas–Peucker algorithm would add them, furthest first, until there are
+    /// `min_points`. This stops small shapes collapsing at coarse tolerances.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::simplify::{Simplify};
+    ///
+    /// let linestring = LineString(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.5),
+    ///                                  Point::new(2.0, 0.0), Point::new(3.0, 0.2)]);
+    /// assert_eq!(linestring.simplify(&10.0).0.len(), 2);
+    /// let simplified = linestring.simplify_min_points(&10.0, 3);
+    /// assert_eq!(simplified.0, vec![Point::new(0.0, 0.0), Point::new(1.0, 0.5), Point::new(3.0, 0.2)]);
+    /// ```
+    fn simplify_min_points(&self, epsilon: &T, min_points: usize) -> Self where T: CoordFloat;
+
+    /// Returns each of `epsilons` paired with the number of points `simplify` would retain
+    /// with it, to help choose a tolerance.
+    ///
+    /// The points are only examined once, however many tolerances are given.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::simplify::{Simplify};
+    ///
+    /// let mut vec = Vec::new();
+    /// vec.push(Point::new(0.0, 0.0));
+    /// vec.push(Point::new(5.0, 4.0));
+    /// vec.push(Point::new(11.0, 5.5));
+    /// vec.push(Point::new(17.3, 3.2));
+    /// vec.push(Point::new(27.8, 0.1));
+    /// let linestring = LineString(vec);
+    /// let report = linestring.simplify_report(&[0.1, 1.0, 10.0]);
+    /// assert_eq!(report, vec![(0.1, 5), (1.0, 4), (10.0, 2)]);
+    /// ```
+    fn simplify_report(&self, epsilons: &[T]) -> Vec<(T, usize)> where T: CoordFloat;
 }
 
 impl<T> Simplify<T> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn simplify(&self, epsilon: &T) -> LineString<T> {
         LineString(rdp(&self.0, epsilon))
     }
+
+    fn simplify_idx(&self, epsilon: &T) -> Vec<usize> {
+        rdp_indices(&self.0, epsilon)
+    }
+
+    fn simplify_min_points(&self, epsilon: &T, min_points: usize) -> LineString<T> {
+        LineString(rdp_min_points(&self.0, epsilon, min_points))
+    }
+
+    fn simplify_report(&self, epsilons: &[T]) -> Vec<(T, usize)> {
+        let ends = match self.0.len() {
+            0 => 0,
+            _ => 2,
+        };
+        let thresholds = rdp_thresholds(&self.0);
+        epsilons.iter()
+                .map(|&epsilon| (epsilon, ends + thresholds.iter().filter(|&&t| t > epsilon).count()))
+                .collect()
+    }
+}
+
+impl<T> Polygon<T>
+    where T: CoordFloat
+{
+    /// Returns the simplified representation of a Polygon, using `exterior_eps` for the
+    /// exterior ring and `interior_eps` for the interior rings.
+    ///
+    /// This allows keeping the detail of small holes while simplifying a large exterior.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    ///
+    /// let p = |x, y| Point::new(x, y);
+    /// let exterior = LineString(vec![p(0., 0.), p(5., 0.5), p(10., 0.), p(10., 10.), p(0., 10.), p(0., 0.)]);
+    /// let interior = LineString(vec![p(4., 4.), p(4.5, 4.2), p(5., 4.), p(5., 5.), p(4., 5.), p(4., 4.)]);
+    /// let poly = Polygon::new(exterior, vec![interior.clone()]);
+    ///
+    /// let simplified = poly.simplify_rings(&1.0, &0.1);
+    /// assert_eq!(simplified.exterior.0.len(), 5);
+    /// assert_eq!(simplified.interiors[0], interior);
+    /// ```
+    pub fn simplify_rings(&self, exterior_eps: &T, interior_eps: &T) -> Polygon<T> {
+        Polygon::new(self.exterior.simplify(exterior_eps),
+                     self.interiors.iter().map(|ring| ring.simplify(interior_eps)).collect())
+    }
+}
+
+impl<T> MultiLineString<T>
+    where T: CoordFloat
+{
+    /// Returns the simplified representation of a network of LineStrings, such as roads,
+    /// without moving the nodes where they meet.
+    ///
+    /// Every point at which a LineString starts or ends is a node, and is kept wherever it
+    /// appears in any of the LineStrings, including part way along one. The pieces between
+    /// nodes are simplified with the Ramer–Douglas–Peucker algorithm, so lines joined at
+    /// a node stay joined, even at a large `epsilon`.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, MultiLineString};
+    ///
+    /// let p = |x, y| Point::new(x, y);
+    /// // a side road joining the middle of a main road
+    /// let main = LineString(vec![p(0., 0.), p(5., 0.1), p(10., 0.)]);
+    /// let side = LineString(vec![p(5., 0.1), p(5.2, 5.), p(5., 10.)]);
+    /// let network = MultiLineString(vec![main, side]);
+    ///
+    /// let simplified = network.simplify_network(&1.0);
+    /// assert_eq!(simplified.0[0].0, vec![p(0., 0.), p(5., 0.1), p(10., 0.)]);
+    /// assert_eq!(simplified.0[1].0, vec![p(5., 0.1), p(5., 10.)]);
+    /// ```
+    pub fn simplify_network(&self, epsilon: &T) -> MultiLineString<T> {
+        let nodes: HashSet<HashablePoint<T>> = self.0
+            .iter()
+            .flat_map(|ls| ls.0.first().into_iter().chain(ls.0.last()))
+            .map(|&p| HashablePoint(p))
+            .collect();
+        let simplified = self.0
+            .iter()
+            .map(|ls| {
+                let mut points: Vec<Point<T>> = Vec::with_capacity(ls.0.len());
+                let mut start = 0;
+                for (i, p) in ls.0.iter().enumerate().skip(1) {
+                    if i == ls.0.len() - 1 || nodes.contains(&HashablePoint(*p)) {
+                        points.pop();
+                        points.extend(rdp(&ls.0[start..i + 1], epsilon));
+                        start = i;
+                    }
+                }
+                if points.is_empty() {
+                    points.extend_from_slice(&ls.0);
+                }
+                LineString(points)
+            })
+            .collect();
+        MultiLineString(simplified)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use types::{Point};
-    use super::{point_line_distance, rdp};
+    use types::{Point, LineString, MultiLineString, Polygon};
+    use super::{point_line_distance, rdp, rdp_indices, rdp_min_points, Simplify};
 
     #[test]
     fn perpdistance_test() {
@@ -128,4 +377,181 @@ mod test {
         let simplified = rdp(&vec, &1.0);
         assert_eq!(simplified, compare);
     }
+    #[test]
+    fn simplify_idx_test() {
+        let track = LineString(vec![Point::new(0.0, 0.0),
+                                    Point::new(1.0, 0.1),
+                                    Point::new(2.0, -0.1),
+                                    Point::new(3.0, 5.0),
+                                    Point::new(4.0, 6.0),
+                                    Point::new(7.0, 9.0),
+                                    Point::new(8.0, 9.0),
+                                    Point::new(9.0, 9.0),
+                                    Point::new(10.0, 1.0)]);
+        let indices = track.simplify_idx(&1.0);
+        assert_eq!(indices.first(), Some(&0));
+        assert_eq!(indices.last(), Some(&(track.0.len() - 1)));
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+        let simplified: Vec<Point<f64>> = indices.iter().map(|&i| track.0[i]).collect();
+        assert_eq!(LineString(simplified), track.simplify(&1.0));
+    }
+    #[test]
+    fn simplify_idx_empty_test() {
+        let track = LineString::<f64>(vec![]);
+        assert!(track.simplify_idx(&1.0).is_empty());
+    }
+    #[test]
+    fn simplify_rings_test() {
+        let p = |x, y| Point::new(x, y);
+        // a wobbly square with a wobbly, much smaller hole
+        let exterior = LineString(vec![p(0., 0.), p(5., 0.3), p(10., 0.), p(10.3, 5.), p(10., 10.),
+                                       p(5., 9.7), p(0., 10.), p(0., 0.)]);
+        let interior = LineString(vec![p(4., 4.), p(4.5, 4.1), p(5., 4.), p(5.1, 4.5), p(5., 5.),
+                                       p(4.5, 4.9), p(4., 5.), p(4., 4.)]);
+        let poly = Polygon::new(exterior, vec![interior.clone()]);
+        let simplified = poly.simplify_rings(&0.5, &0.05);
+        assert_eq!(simplified.exterior.0, vec![p(0., 0.), p(10., 0.), p(10., 10.), p(0., 10.), p(0., 0.)]);
+        assert_eq!(simplified.interiors[0], interior);
+        assert!(simplified.interiors[0].0.len() > simplified.exterior.0.len());
+        // with a single tolerance, the hole loses its detail too
+        assert_eq!(poly.simplify_rings(&0.5, &0.5).interiors[0].0.len(), 5);
+    }
+    #[test]
+    fn simplify_min_points_test() {
+        // a small, wobbly circle
+        let ring = LineString((0..=16)
+            .map(|i| {
+                let angle = i as f64 * ::std::f64::consts::PI / 8.;
+                let r = if i % 2 == 0 { 1.0 } else { 1.05 };
+                Point::new(r * angle.cos(), r * angle.sin())
+            })
+            .collect());
+        assert_eq!(ring.simplify(&10.0).0.len(), 2);
+        let simplified = ring.simplify_min_points(&10.0, 5);
+        assert_eq!(simplified.0.len(), 5);
+        assert_eq!(simplified.0.first(), ring.0.first());
+        assert_eq!(simplified.0.last(), ring.0.last());
+        // a small epsilon keeps more than min_points, exactly as simplify does
+        assert_eq!(ring.simplify_min_points(&0.01, 5), ring.simplify(&0.01));
+        // fewer points than min_points are all kept
+        assert_eq!(ring.simplify_min_points(&10.0, 100), ring);
+    }
+    #[test]
+    fn rdp_min_points_matches_rdp_test() {
+        let points = vec![Point::new(0.0, 0.0),
+                          Point::new(5.0, 4.0),
+                          Point::new(11.0, 5.5),
+                          Point::new(17.3, 3.2),
+                          Point::new(27.8, 0.1)];
+        for &epsilon in &[0.1, 1.0, 2.0, 10.0] {
+            assert_eq!(rdp_min_points(&points, &epsilon, 0), rdp(&points, &epsilon));
+        }
+    }
+    #[test]
+    fn simplify_network_test() {
+        let p = |x, y| Point::new(x, y);
+        // two wiggly lines meeting end to end at (10, 1)
+        let a = LineString(vec![p(0., 0.), p(3., 0.4), p(6., -0.3), p(10., 1.)]);
+        let b = LineString(vec![p(10., 1.), p(12., 4.), p(13., 7.), p(20., 8.)]);
+        // a third line, ending part way along the second
+        let c = LineString(vec![p(30., 0.), p(25., 2.), p(13., 7.)]);
+        let network = MultiLineString(vec![a, b, c]);
+        for &epsilon in &[0.1, 5., 1000.] {
+            let simplified = network.simplify_network(&epsilon);
+            assert_eq!(simplified.0.len(), 3);
+            assert_eq!(simplified.0[0].0.last(), simplified.0[1].0.first());
+            assert!(simplified.0[1].0.contains(&p(13., 7.)));
+            assert_eq!(simplified.0[2].0.last(), Some(&p(13., 7.)));
+        }
+        let simplified = network.simplify_network(&1000.);
+        assert_eq!(simplified.0[0].0, vec![p(0., 0.), p(10., 1.)]);
+        assert_eq!(simplified.0[1].0, vec![p(10., 1.), p(13., 7.), p(20., 8.)]);
+        assert_eq!(simplified.0[2].0, vec![p(30., 0.), p(13., 7.)]);
+        // without pinning, the node part way along the second line would be lost
+        assert_eq!(network.0[1].simplify(&1000.).0, vec![p(10., 1.), p(20., 8.)]);
+    }
+    #[test]
+    fn simplify_network_degenerate_test() {
+        let network = MultiLineString(vec![LineString(vec![]), LineString(vec![Point::new(1., 1.)])]);
+        assert_eq!(network.simplify_network(&1.), network);
+    }
+    // the recursive formulation of Ramer–Douglas-Peucker, as a reference
+    fn rdp_indices_recursive(points: &[Point<f64>], epsilon: f64) -> Vec<usize> {
+        if points.is_empty() {
+            return vec![];
+        }
+        let (mut dmax, mut index) = (0., 0);
+        for i in 1..points.len().saturating_sub(1) {
+            let distance = point_line_distance(&points[i], &points[0], &points[points.len() - 1]);
+            if distance > dmax {
+                index = i;
+                dmax = distance;
+            }
+        }
+        if dmax > epsilon {
+            let mut intermediate = rdp_indices_recursive(&points[..index + 1], epsilon);
+            intermediate.pop();
+            intermediate.extend(rdp_indices_recursive(&points[index..], epsilon).iter().map(|i| i + index));
+            intermediate
+        } else {
+            vec![0, points.len() - 1]
+        }
+    }
+    #[test]
+    fn rdp_matches_recursive_test() {
+        // a deterministic, noisy walk
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 8) as f64 / (1 << 24) as f64 - 0.5
+        };
+        for n in 0..40 {
+            let points: Vec<Point<f64>> = (0..n).map(|i| Point::new(i as f64 + next(), next() * 4.)).collect();
+            for &epsilon in &[0., 0.1, 0.5, 1.5] {
+                assert_eq!(rdp_indices(&points, &epsilon), rdp_indices_recursive(&points, epsilon));
+            }
+        }
+    }
+    #[test]
+    fn rdp_long_linestring_test() {
+        // a zig-zag, where each range splits at a point beside one of its ends, so the
+        // ranges nest as deeply as there are points; recursion overflows a test thread's
+        // stack at a few thousand
+        let n = 10_000;
+        let points: Vec<Point<f64>> = (0..n).map(|i| Point::new(i as f64, (i % 2) as f64)).collect();
+        let ls = LineString(points);
+        assert_eq!(ls.simplify_idx(&0.5), (0..n).collect::<Vec<usize>>());
+        assert_eq!(ls.simplify(&2.).0, vec![ls.0[0], ls.0[n - 1]]);
+    }
+    #[test]
+    fn simplify_report_test() {
+        let mut seed = 7u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 8) as f64 / (1 << 24) as f64 - 0.5
+        };
+        let ls = LineString((0..500).map(|i| Point::new(i as f64 / 10., next() * 4.)).collect());
+        let epsilons = [0., 0.05, 0.1, 0.25, 0.5, 1., 1.5, 2., 5.];
+        let report = ls.simplify_report(&epsilons);
+        assert_eq!(report.len(), epsilons.len());
+        for (&(epsilon, count), &expected) in report.iter().zip(&epsilons) {
+            assert_eq!(epsilon, expected);
+            assert_eq!(count, ls.simplify(&epsilon).0.len());
+        }
+        for pair in report.windows(2) {
+            assert!(pair[1].1 <= pair[0].1);
+        }
+        assert_eq!(report[0].1, 500);
+        assert_eq!(report[epsilons.len() - 1].1, 2);
+    }
+    #[test]
+    fn simplify_report_degenerate_test() {
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(empty.simplify_report(&[1.]), vec![(1., 0)]);
+        let single = LineString(vec![Point::new(1., 1.)]);
+        assert_eq!(single.simplify_report(&[1.]), vec![(1., single.simplify(&1.).0.len())]);
+        let straight = LineString(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(2., 0.)]);
+        assert_eq!(straight.simplify_report(&[0., 1.]), vec![(0., 2), (1., 2)]);
+        assert!(straight.simplify_report(&[]).is_empty());
+    }
 }
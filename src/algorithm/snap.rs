@@ -0,0 +1,190 @@
+use types::{CoordFloat, Point, Line, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon};
+
+// is size a usable grid spacing? Others would divide by zero or give NaN coordinates.
+fn is_grid_size<T>(size: T) -> bool
+    where T: CoordFloat
+{
+    size > T::zero() && size.is_finite()
+}
+
+// snap a point to the nearest grid intersection
+fn snap_point<T>(point: &Point<T>, size: T) -> Point<T>
+    where T: CoordFloat
+{
+    if !is_grid_size(size) {
+        return *point;
+    }
+    Point::new((point.x() / size).round() * size,
+               (point.y() / size).round() * size)
+}
+
+// snap a slice of points, optionally removing consecutive duplicates
+fn snap_points<T>(points: &[Point<T>], size: T, dedup: bool) -> Vec<Point<T>>
+    where T: CoordFloat
+{
+    let mut snapped: Vec<Point<T>> = points.iter().map(|p| snap_point(p, size)).collect();
+    if dedup && is_grid_size(size) {
+        snapped.dedup();
+    }
+    snapped
+}
+
+pub trait SnapToGrid<T> {
+    /// Snaps every coordinate of a geometry to the nearest multiple of `size`.
+    ///
+    /// Snapping may make consecutive points coincide; these are kept. Use
+    /// `snap_to_grid_dedup` to remove them.
+    ///
+    /// A `size` which is zero, negative, infinite or NaN doesn't define a grid, and the
+    /// geometry is returned unchanged.
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::snap::SnapToGrid;
+    ///
+    /// let p = Point::new(1.03, 1.97);
+    /// assert_eq!(p.snap_to_grid(0.5), Point::new(1.0, 2.0));
+    /// ```
+    fn snap_to_grid(&self, size: T) -> Self where T: CoordFloat;
+
+    /// Snaps every coordinate of a geometry to the nearest multiple of `size`, removing
+    /// consecutive points that become identical.
+    ///
+    /// As with `snap_to_grid`, a `size` which doesn't define a grid returns the geometry
+    /// unchanged, duplicates included.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    /// use geo::algorithm::snap::SnapToGrid;
+    ///
+    /// let ls = LineString(vec![Point::new(0.1, 0.1), Point::new(-0.2, 0.05), Point::new(1.1, 0.9)]);
+    /// let snapped = ls.snap_to_grid_dedup(1.0);
+    /// assert_eq!(snapped, LineString(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)]));
+    /// ```
+    fn snap_to_grid_dedup(&self, size: T) -> Self
+        where T: CoordFloat,
+              Self: Sized
+    {
+        self.snap_to_grid(size)
+    }
+}
+
+impl<T> SnapToGrid<T> for Point<T>
+    where T: CoordFloat
+{
+    fn snap_to_grid(&self, size: T) -> Self {
+        snap_point(self, size)
+    }
+}
+
+impl<T> SnapToGrid<T> for Line<T>
+    where T: CoordFloat
+{
+    fn snap_to_grid(&self, size: T) -> Self {
+        Line::new(snap_point(&self.start, size), snap_point(&self.end, size))
+    }
+}
+
+impl<T> SnapToGrid<T> for MultiPoint<T>
+    where T: CoordFloat
+{
+    fn snap_to_grid(&self, size: T) -> Self {
+        MultiPoint(snap_points(&self.0, size, false))
+    }
+
+    fn snap_to_grid_dedup(&self, size: T) -> Self {
+        MultiPoint(snap_points(&self.0, size, true))
+    }
+}
+
+impl<T> SnapToGrid<T> for LineString<T>
+    where T: CoordFloat
+{
+    fn snap_to_grid(&self, size: T) -> Self {
+        LineString(snap_points(&self.0, size, false))
+    }
+
+    fn snap_to_grid_dedup(&self, size: T) -> Self {
+        LineString(snap_points(&self.0, size, true))
+    }
+}
+
+impl<T> SnapToGrid<T> for MultiLineString<T>
+    where T: CoordFloat
+{
+    fn snap_to_grid(&self, size: T) -> Self {
+        MultiLineString(self.0.iter().map(|ls| ls.snap_to_grid(size)).collect())
+    }
+
+    fn snap_to_grid_dedup(&self, size: T) -> Self {
+        MultiLineString(self.0.iter().map(|ls| ls.snap_to_grid_dedup(size)).collect())
+    }
+}
+
+impl<T> SnapToGrid<T> for Polygon<T>
+    where T: CoordFloat
+{
+    fn snap_to_grid(&self, size: T) -> Self {
+        Polygon::new(self.exterior.snap_to_grid(size),
+                     self.interiors.iter().map(|ring| ring.snap_to_grid(size)).collect())
+    }
+
+    fn snap_to_grid_dedup(&self, size: T) -> Self {
+        Polygon::new(self.exterior.snap_to_grid_dedup(size),
+                     self.interiors.iter().map(|ring| ring.snap_to_grid_dedup(size)).collect())
+    }
+}
+
+impl<T> SnapToGrid<T> for MultiPolygon<T>
+    where T: CoordFloat
+{
+    fn snap_to_grid(&self, size: T) -> Self {
+        MultiPolygon(self.0.iter().map(|poly| poly.snap_to_grid(size)).collect())
+    }
+
+    fn snap_to_grid_dedup(&self, size: T) -> Self {
+        MultiPolygon(self.0.iter().map(|poly| poly.snap_to_grid_dedup(size)).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, Line, LineString, Polygon};
+    use super::*;
+
+    #[test]
+    fn snap_point_test() {
+        assert_eq!(Point::new(1.03, 1.97).snap_to_grid(0.5), Point::new(1.0, 2.0));
+        assert_eq!(Point::new(-1.3, 7.6).snap_to_grid(2.0), Point::new(-2.0, 8.0));
+    }
+    #[test]
+    fn snap_line_test() {
+        let line = Line::new(Point::new(0.2, 0.4), Point::new(2.7, 3.1));
+        assert_eq!(line.snap_to_grid(1.0), Line::new(Point::new(0.0, 0.0), Point::new(3.0, 3.0)));
+    }
+    #[test]
+    fn snap_linestring_keeps_duplicates_test() {
+        let ls = LineString(vec![Point::new(0.1, 0.1), Point::new(0.2, -0.1), Point::new(1.1, 0.9)]);
+        let snapped = ls.snap_to_grid(1.0);
+        assert_eq!(snapped.0, vec![Point::new(0.0, 0.0), Point::new(0.0, 0.0), Point::new(1.0, 1.0)]);
+    }
+    #[test]
+    fn snap_polygon_dedup_test() {
+        let exterior = LineString(vec![Point::new(0.0, 0.0),
+                                       Point::new(0.1, 0.0),
+                                       Point::new(2.0, 0.1),
+                                       Point::new(1.9, 2.1),
+                                       Point::new(0.0, 0.0)]);
+        let snapped = Polygon::new(exterior, vec![]).snap_to_grid_dedup(1.0);
+        assert_eq!(snapped.exterior.0,
+                   vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(2.0, 2.0), Point::new(0.0, 0.0)]);
+    }    #[test]
+    fn invalid_size_test() {
+        let ls = LineString(vec![Point::new(0.1, 0.1), Point::new(0.1, 0.1), Point::new(1.1, 0.9)]);
+        for &size in &[0., -1., f64::NAN, f64::INFINITY] {
+            assert_eq!(Point::new(1.03, 1.97).snap_to_grid(size), Point::new(1.03, 1.97));
+            assert_eq!(ls.snap_to_grid(size), ls);
+            assert_eq!(ls.snap_to_grid_dedup(size), ls);
+        }
+    }
+}
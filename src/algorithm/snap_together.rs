@@ -0,0 +1,97 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use types::{CoordFloat, Coordinate, Geometry};
+use algorithm::map_coords::MapCoordsInplace;
+
+// the representative coordinates in each cell of the grid
+type Cells<T> = HashMap<(i64, i64), Vec<Coordinate<T>>>;
+
+/// Snaps coordinates lying within `tolerance` of each other, across all of the geometries,
+/// to a single representative coordinate.
+///
+/// Coordinates are visited in order, geometry by geometry. Each one is replaced by the first
+/// representative found within `tolerance` of it, or else becomes a representative itself.
+/// Nearby representatives are found through a grid of cells `tolerance` wide, so this takes
+/// time linear in the number of coordinates. Coordinates which aren't finite, and all
+/// coordinates when `tolerance` isn't positive, are left unchanged.
+///
+/// ```
+/// use geo::{Point, Geometry};
+/// use geo::algorithm::snap_together::snap;
+///
+/// let mut geoms = vec![Geometry::Point(Point::new(1., 1.)), Geometry::Point(Point::new(1.05, 0.98))];
+/// snap(&mut geoms, 0.1);
+///
+/// assert_eq!(geoms[1], Geometry::Point(Point::new(1., 1.)));
+/// ```
+pub fn snap<T>(geoms: &mut [Geometry<T>], tolerance: T)
+    where T: CoordFloat
+{
+    if tolerance.is_nan() || tolerance <= T::zero() {
+        return;
+    }
+    let cells: RefCell<Cells<T>> = RefCell::new(HashMap::new());
+    let snap_coord = |c: &Coordinate<T>| {
+        let cell = match ((c.x / tolerance).floor().to_i64(), (c.y / tolerance).floor().to_i64()) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return *c,
+        };
+        let mut cells = cells.borrow_mut();
+        for dx in -1..2 {
+            for dy in -1..2 {
+                let nearby = cells.get(&(cell.0 + dx, cell.1 + dy)).into_iter().flat_map(|reps| reps.iter());
+                for rep in nearby {
+                    if (rep.x - c.x).hypot(rep.y - c.y) <= tolerance {
+                        return *rep;
+                    }
+                }
+            }
+        }
+        cells.entry(cell).or_default().push(*c);
+        *c
+    };
+    for geom in geoms.iter_mut() {
+        geom.map_coords_inplace(snap_coord);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, Geometry};
+    use super::*;
+
+    #[test]
+    fn shared_edge_test() {
+        let p = |x, y| Point::new(x, y);
+        let left = Polygon::new(LineString(vec![p(0., 0.), p(1., 0.), p(1., 1.), p(0., 1.), p(0., 0.)]),
+                                vec![]);
+        // sharing the edge from (1, 0) to (1, 1), but slightly off
+        let right = Polygon::new(LineString(vec![p(1. + 1e-9, -1e-9), p(2., 0.), p(2., 1.),
+                                                 p(1. - 1e-9, 1. + 1e-9), p(1. + 1e-9, -1e-9)]),
+                                 vec![]);
+        let mut geoms = vec![Geometry::Polygon(left.clone()), Geometry::Polygon(right)];
+        snap(&mut geoms, 1e-6);
+        assert_eq!(geoms[0], Geometry::Polygon(left));
+        let expected = Polygon::new(LineString(vec![p(1., 0.), p(2., 0.), p(2., 1.), p(1., 1.), p(1., 0.)]),
+                                    vec![]);
+        assert_eq!(geoms[1], Geometry::Polygon(expected));
+    }
+    #[test]
+    fn across_cells_test() {
+        // points either side of a cell boundary still snap together, but distant ones don't
+        let mut geoms = vec![Geometry::LineString(LineString(vec![Point::new(0.99, 0.), Point::new(1.01, 0.),
+                                                                  Point::new(1.5, 0.)]))];
+        snap(&mut geoms, 0.1);
+        let expected = LineString(vec![Point::new(0.99, 0.), Point::new(0.99, 0.), Point::new(1.5, 0.)]);
+        assert_eq!(geoms[0], Geometry::LineString(expected));
+    }
+    #[test]
+    fn no_tolerance_test() {
+        let original = vec![Geometry::Point(Point::new(0., 0.)), Geometry::Point(Point::new(0., 1e-12))];
+        let mut geoms = original.clone();
+        snap(&mut geoms, 0.);
+        assert_eq!(geoms, original);
+        snap(&mut geoms, -1.);
+        assert_eq!(geoms, original);
+    }
+}
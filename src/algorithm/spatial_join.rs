@@ -0,0 +1,71 @@
+use types::{CoordFloat, Bbox, MultiPoint, MultiPolygon};
+use algorithm::boundingbox::BoundingBox;
+use algorithm::contains::Contains;
+
+/// Finds, for each point, the index of the first polygon that contains it.
+///
+/// Returns one entry per point, in order: `Some(index)` into `polygons`, or `None` if no
+/// polygon contains the point. The bounding box of each polygon is computed once and used as
+/// a coarse filter, so the exact containment test only runs against nearby polygons.
+///
+/// ```
+/// use geo::{Point, MultiPoint, MultiPolygon, Bbox};
+/// use geo::algorithm::spatial_join::points_in_polygons;
+///
+/// let left = Bbox { xmin: 0., xmax: 1., ymin: 0., ymax: 1. }.to_polygon();
+/// let right = Bbox { xmin: 2., xmax: 3., ymin: 0., ymax: 1. }.to_polygon();
+/// let polygons = MultiPolygon(vec![left, right]);
+/// let points = MultiPoint(vec![Point::new(2.5, 0.5), Point::new(1.5, 0.5), Point::new(0.5, 0.5)]);
+///
+/// assert_eq!(points_in_polygons(&points, &polygons), vec![Some(1), None, Some(0)]);
+/// ```
+pub fn points_in_polygons<T: CoordFloat>(points: &MultiPoint<T>, polygons: &MultiPolygon<T>) -> Vec<Option<usize>> {
+    let bboxes: Vec<Option<Bbox<T>>> = polygons.0.iter().map(|poly| poly.bbox()).collect();
+    points.0
+        .iter()
+        .map(|point| {
+            polygons.0
+                .iter()
+                .zip(&bboxes)
+                .position(|(poly, bbox)| match *bbox {
+                    Some(ref bbox) => bbox.contains(point) && poly.contains(point),
+                    None => false,
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPoint, MultiPolygon, Bbox};
+    use super::*;
+
+    #[test]
+    fn three_polygons_test() {
+        let p = |x, y| Point::new(x, y);
+        let square = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 2. }.to_polygon();
+        let triangle = Polygon::new(LineString(vec![p(3., 0.), p(6., 0.), p(3., 3.), p(3., 0.)]), vec![]);
+        let holed = Polygon::new(Bbox { xmin: 0., xmax: 4., ymin: 5., ymax: 9. }.to_line_string(),
+                                 vec![Bbox { xmin: 1., xmax: 3., ymin: 6., ymax: 8. }.to_line_string()]);
+        let polygons = MultiPolygon(vec![square, triangle, holed]);
+        let points = MultiPoint(vec![p(1., 1.),
+                                     p(3.5, 0.5),
+                                     // inside the triangle's bbox, but not the triangle
+                                     p(5.5, 2.5),
+                                     p(0.5, 8.5),
+                                     // in the hole
+                                     p(2., 7.),
+                                     p(-1., -1.),
+                                     p(10., 10.),
+                                     p(1.5, 0.2)]);
+        assert_eq!(points_in_polygons(&points, &polygons),
+                   vec![Some(0), Some(1), None, Some(2), None, None, None, Some(0)]);
+    }
+    #[test]
+    fn empty_inputs_test() {
+        let polygons = MultiPolygon(vec![Bbox { xmin: 0., xmax: 1., ymin: 0., ymax: 1. }.to_polygon()]);
+        assert!(points_in_polygons(&MultiPoint(vec![]), &polygons).is_empty());
+        let points = MultiPoint(vec![Point::new(0.5, 0.5)]);
+        assert_eq!(points_in_polygons(&points, &MultiPolygon(vec![])), vec![None]);
+    }
+}
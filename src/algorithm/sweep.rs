@@ -0,0 +1,341 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use types::{CoordFloat, Point, Line, total_cmp};
+use algorithm::intersects::Intersects;
+
+// a point where the sweep line stops, ordered left to right and then bottom to top
+#[derive(Clone, Copy, Debug)]
+struct EventPoint<T>(Point<T>) where T: CoordFloat;
+
+impl<T> Ord for EventPoint<T>
+    where T: CoordFloat
+{
+    fn cmp(&self, other: &EventPoint<T>) -> Ordering {
+        (self.0).0.cmp_lexicographic(&(other.0).0)
+    }
+}
+
+impl<T> PartialOrd for EventPoint<T>
+    where T: CoordFloat
+{
+    fn partial_cmp(&self, other: &EventPoint<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> PartialEq for EventPoint<T>
+    where T: CoordFloat
+{
+    fn eq(&self, other: &EventPoint<T>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for EventPoint<T> where T: CoordFloat {}
+
+// the segments which start, end, or have been found to cross, at an event point
+struct Event {
+    starts: Vec<usize>,
+    ends: Vec<usize>,
+    crosses: Vec<usize>,
+}
+
+impl Event {
+    fn new() -> Event {
+        Event { starts: vec![], ends: vec![], crosses: vec![] }
+    }
+}
+
+// The height of a segment where it meets the sweep line, with the sweep stopped at
+// `sweep`. A vertical segment lies along the sweep line, and is taken to be at the
+// height of the sweep point for as long as it spans it.
+fn y_at<T>(line: &Line<T>, sweep: &Point<T>) -> T
+    where T: CoordFloat
+{
+    let (start, end) = (line.start, line.end);
+    if start.x() == end.x() {
+        return sweep.y().max(start.y()).min(end.y());
+    }
+    let t = (sweep.x() - start.x()) / (end.x() - start.x());
+    start.y() + t * (end.y() - start.y())
+}
+
+// the z-component of the cross product of two vectors
+fn cross<T>(a: &Point<T>, b: &Point<T>) -> T
+    where T: CoordFloat
+{
+    a.x() * b.y() - a.y() * b.x()
+}
+
+// A point shared by two segments already known to intersect. Collinear overlapping
+// segments share a whole stretch, and one of its endpoints is returned.
+fn intersection_point<T>(a: &Line<T>, b: &Line<T>) -> Point<T>
+    where T: CoordFloat
+{
+    let da = a.end - a.start;
+    let db = b.end - b.start;
+    let denom = cross(&da, &db);
+    if denom == T::zero() {
+        return [a.start, a.end, b.start, b.end]
+            .iter()
+            .cloned()
+            .find(|p| p.intersects(a) && p.intersects(b))
+            .unwrap_or(a.start);
+    }
+    let s = cross(&(b.start - a.start), &db) / denom;
+    a.start + Point::new(da.x() * s, da.y() * s)
+}
+
+/// Finds every pair of intersecting segments, with a point where they meet, using a
+/// Bentley–Ottmann sweep.
+///
+/// A vertical sweep line moves from left to right, stopping at the endpoints of the
+/// segments and at the crossings found so far. The segments it cuts are kept in the order
+/// they cut it, bottom to top, and only segments which are neighbours in that order, or
+/// which meet at a point where the sweep line stops, are tested against each other. This
+/// takes `O((n + k) log n)` comparisons for `n` segments with `k` intersections, rather
+/// than the `O(n²)` of testing every pair. The order is kept in a `Vec`, so each stop
+/// also shifts up to `n` indices along it, which is cheap next to the comparisons.
+///
+/// Segments touching at an endpoint, or overlapping along a stretch, intersect, just as
+/// they do for `Intersects`; a segment which passes within `DEFAULT_EPSILON` of a point
+/// where the sweep line stops is taken to pass through it. Segments with a NaN coordinate
+/// intersect nothing.
+///
+/// Each pair of indices is returned once, as `(i, j)` with `i < j`, sorted.
+///
+/// ```
+/// use geo::{Point, Line};
+/// use geo::algorithm::sweep::find_intersections;
+///
+/// let segments = vec![Line::new(Point::new(0., 0.), Point::new(2., 2.)),
+///                     Line::new(Point::new(0., 2.), Point::new(2., 0.)),
+///                     Line::new(Point::new(5., 0.), Point::new(5., 2.))];
+///
+/// let found = find_intersections(&segments);
+/// assert_eq!(found.len(), 1);
+/// assert_eq!((found[0].0, found[0].1), (0, 1));
+/// assert_eq!(found[0].2, Point::new(1., 1.));
+/// ```
+pub fn find_intersections<T>(segments: &[Line<T>]) -> Vec<(usize, usize, Point<T>)>
+    where T: CoordFloat
+{
+    // each segment runs from the end the sweep line reaches first
+    let lines: Vec<Line<T>> = segments
+        .iter()
+        .map(|line| if line.end.0.cmp_lexicographic(&line.start.0) == Ordering::Less {
+            Line::new(line.end, line.start)
+        } else {
+            line.clone()
+        })
+        .collect();
+    let mut events: BTreeMap<EventPoint<T>, Event> = BTreeMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        let coords = [line.start.x(), line.start.y(), line.end.x(), line.end.y()];
+        if coords.iter().any(|c| c.is_nan()) {
+            continue;
+        }
+        events.entry(EventPoint(line.start)).or_insert_with(Event::new).starts.push(i);
+        events.entry(EventPoint(line.end)).or_insert_with(Event::new).ends.push(i);
+    }
+
+    // the segments cut by the sweep line, bottom to top
+    let mut status: Vec<usize> = vec![];
+    let mut found: Vec<(usize, usize)> = vec![];
+    while let Some(&point) = events.keys().next() {
+        let event = events.remove(&point).unwrap();
+        let sweep = point.0;
+
+        // the segments already cut by the sweep line which pass through the event point
+        // lie together in the status
+        let through = |i: usize| {
+            event.ends.contains(&i) || event.crosses.contains(&i) || lines[i].intersects(&sweep)
+        };
+        let below = status.partition_point(|&i| y_at(&lines[i], &sweep) < sweep.y());
+        let mut lo = below;
+        while lo > 0 && through(status[lo - 1]) {
+            lo -= 1;
+        }
+        let mut hi = below;
+        while hi < status.len() && through(status[hi]) {
+            hi += 1;
+        }
+        let mut at_point: Vec<usize> = status.drain(lo..hi).collect();
+        // a segment ending here, which rounding has kept out of that range
+        for &i in &event.ends {
+            if at_point.contains(&i) {
+                continue;
+            }
+            if let Some(k) = status.iter().position(|&j| j == i) {
+                status.remove(k);
+                if k < lo {
+                    lo -= 1;
+                }
+                at_point.push(i);
+            }
+        }
+        at_point.extend(event.starts.iter().cloned());
+
+        // every pair meeting here is among the segments through the event point
+        for (k, &i) in at_point.iter().enumerate() {
+            for &j in &at_point[k + 1..] {
+                if lines[i].intersects(&lines[j]) {
+                    found.push(if i < j { (i, j) } else { (j, i) });
+                }
+            }
+        }
+
+        // those which carry on past the event point cut the sweep line in the order of
+        // their slopes
+        let mut continuing: Vec<usize> = at_point.into_iter().filter(|i| !event.ends.contains(i)).collect();
+        let angle = |i: usize| {
+            let direction = lines[i].end - lines[i].start;
+            direction.y().atan2(direction.x())
+        };
+        continuing.sort_by(|&i, &j| total_cmp(angle(i), angle(j)).then(i.cmp(&j)));
+        let after = lo + continuing.len();
+        status.splice(lo..lo, continuing);
+
+        // segments which have just become neighbours may cross further on
+        let mut neighbours = vec![];
+        if lo == after {
+            if lo > 0 && lo < status.len() {
+                neighbours.push((status[lo - 1], status[lo]));
+            }
+        } else {
+            if lo > 0 {
+                neighbours.push((status[lo - 1], status[lo]));
+            }
+            if after < status.len() {
+                neighbours.push((status[after - 1], status[after]));
+            }
+        }
+        for (i, j) in neighbours {
+            if !lines[i].intersects(&lines[j]) {
+                continue;
+            }
+            found.push(if i < j { (i, j) } else { (j, i) });
+            let crossing = EventPoint(intersection_point(&lines[i], &lines[j]));
+            if crossing > point {
+                let crosses = &mut events.entry(crossing).or_insert_with(Event::new).crosses;
+                crosses.push(i);
+                crosses.push(j);
+            }
+        }
+    }
+    found.sort();
+    found.dedup();
+    found.into_iter().map(|(i, j)| (i, j, intersection_point(&segments[i], &segments[j]))).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, Line};
+    use algorithm::distance::Distance;
+    use algorithm::intersects::Intersects;
+    use super::*;
+
+    // checks the sweep against testing every pair of segments
+    fn assert_matches_brute_force(segments: &[Line<f64>]) {
+        let found = find_intersections(segments);
+        let mut brute_force = Vec::new();
+        for i in 0..segments.len() {
+            for j in (i + 1)..segments.len() {
+                if segments[i].intersects(&segments[j]) {
+                    brute_force.push((i, j));
+                }
+            }
+        }
+        let pairs: Vec<(usize, usize)> = found.iter().map(|&(i, j, _)| (i, j)).collect();
+        assert_eq!(pairs, brute_force);
+        for &(i, j, p) in &found {
+            assert_relative_eq!(p.distance(&segments[i]), 0., epsilon = 1e-9);
+            assert_relative_eq!(p.distance(&segments[j]), 0., epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn grid_test() {
+        // five horizontal and five vertical segments, plus two diagonals across the grid
+        let mut segments = Vec::new();
+        for k in 0..5 {
+            let c = k as f64 * 2.;
+            segments.push(Line::new(Point::new(-1., c), Point::new(9., c)));
+            segments.push(Line::new(Point::new(c, -1.), Point::new(c, 9.)));
+        }
+        segments.push(Line::new(Point::new(-1., -1.), Point::new(9., 9.)));
+        segments.push(Line::new(Point::new(-1., 9.5), Point::new(9.5, -1.)));
+        assert_matches_brute_force(&segments);
+    }
+    #[test]
+    fn random_test() {
+        // a deterministic scatter of segments, of mixed lengths
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 8) as f64 / (1 << 24) as f64 * 100.
+        };
+        for &(n, scale) in &[(50, 1.), (200, 0.2), (300, 0.05)] {
+            let segments: Vec<Line<f64>> = (0..n)
+                .map(|_| {
+                    let (x, y) = (next(), next());
+                    Line::new(Point::new(x, y), Point::new(x + (next() - 50.) * scale, y + (next() - 50.) * scale))
+                })
+                .collect();
+            assert_matches_brute_force(&segments);
+        }
+    }
+    #[test]
+    fn shared_points_test() {
+        let p = |x, y| Point::new(x, y);
+        let mut segments = vec![];
+        // a star of segments through one point, in both directions
+        for &(x, y) in &[(1., 0.), (1., 1.), (0., 1.), (-1., 1.), (2., -1.), (0., -3.)] {
+            segments.push(Line::new(p(5. + x, 5. + y), p(5. - x, 5. - y)));
+        }
+        // a zig-zag, whose segments share their endpoints
+        for k in 0..6 {
+            let x = k as f64;
+            segments.push(Line::new(p(x, (k % 2) as f64), p(x + 1., ((k + 1) % 2) as f64)));
+        }
+        // segments ending on others, a point-like segment, and overlapping vertical ones
+        segments.push(Line::new(p(3., 0.5), p(3., 4.)));
+        segments.push(Line::new(p(5., 5.), p(5., 5.)));
+        segments.push(Line::new(p(5., 2.), p(5., 8.)));
+        segments.push(Line::new(p(5., 7.), p(5., 10.)));
+        segments.push(Line::new(p(0., 5.), p(4., 5.)));
+        assert_matches_brute_force(&segments);
+    }
+    #[test]
+    fn stacked_horizontals_test() {
+        // many segments across the same x-range, only a few of which cross
+        let mut segments: Vec<Line<f64>> = (0..400)
+            .map(|k| Line::new(Point::new(0., k as f64), Point::new(100., k as f64)))
+            .collect();
+        segments.push(Line::new(Point::new(50., -1.), Point::new(50., 2.5)));
+        segments.push(Line::new(Point::new(10., 397.5), Point::new(20., 400.)));
+        assert_matches_brute_force(&segments);
+        assert_eq!(find_intersections(&segments).len(), 5);
+    }
+    #[test]
+    fn collinear_test() {
+        let segments = vec![Line::new(Point::new(0., 0.), Point::new(4., 0.)),
+                            Line::new(Point::new(2., 0.), Point::new(6., 0.)),
+                            Line::new(Point::new(7., 0.), Point::new(8., 0.))];
+        let found = find_intersections(&segments);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0], (0, 1, Point::new(4., 0.)));
+    }
+    #[test]
+    fn nan_test() {
+        let segments = vec![Line::new(Point::new(0., 0.), Point::new(2., 2.)),
+                            Line::new(Point::new(f64::NAN, 2.), Point::new(2., 0.)),
+                            Line::new(Point::new(0., 2.), Point::new(2., 0.))];
+        let found = find_intersections(&segments);
+        assert_eq!(found, vec![(0, 2, Point::new(1., 1.))]);
+    }
+    #[test]
+    fn empty_test() {
+        assert!(find_intersections::<f64>(&[]).is_empty());
+    }
+}
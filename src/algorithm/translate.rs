@@ -1,9 +1,8 @@
-use num_traits::{Float, FromPrimitive};
-use types::{Point, Polygon, LineString, Line, MultiPoint, MultiPolygon, MultiLineString};
+use types::{CoordFloat, Point, Polygon, LineString, Line, MultiPoint, MultiPolygon, MultiLineString};
 
 // translate a slice of points by the given offsets
 fn translation_matrix<T>(x_offset: T, y_offset: T, points: &[Point<T>]) -> Vec<Point<T>>
-    where T: Float
+    where T: CoordFloat
 {
     points
         .iter()
@@ -36,11 +35,11 @@ pub trait Translate<T> {
     /// let correct_ls = LineString(correct);
     /// assert_eq!(translated, correct_ls);
     /// ```
-    fn translate(&self, xoff: T, yoff: T) -> Self where T: Float;
+    fn translate(&self, xoff: T, yoff: T) -> Self where T: CoordFloat;
 }
 
 impl<T> Translate<T> for Point<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Translate the Point by the given offsets
     fn translate(&self, xoff: T, yoff: T) -> Self {
@@ -49,7 +48,7 @@ impl<T> Translate<T> for Point<T>
 }
 
 impl<T> Translate<T> for LineString<T>
-    where T: Float
+    where T: CoordFloat
 {
     /// Translate the LineString by the given offsets
     fn translate(&self, xoff: T, yoff: T) -> Self {
@@ -58,7 +57,7 @@ impl<T> Translate<T> for LineString<T>
 }
 
 impl<T> Translate<T> for Polygon<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
     /// Translate the Polygon by the given offsets
     fn translate(&self, xoff: T, yoff: T) -> Self {
@@ -71,7 +70,7 @@ impl<T> Translate<T> for Polygon<T>
 }
 
 impl<T> Translate<T> for Line<T>
-    where T: Float
+    where T: CoordFloat
 {
     fn translate(&self, xoff: T, yoff: T) -> Self {
         let translated = translation_matrix(xoff, yoff, &vec![self.start, self.end]);
@@ -80,7 +79,7 @@ impl<T> Translate<T> for Line<T>
 }
 
 impl<T> Translate<T> for MultiPolygon<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
     fn translate(&self, xoff: T, yoff: T) -> Self {
         MultiPolygon(self.0.iter().map(|poly| poly.translate(xoff, yoff)).collect())
@@ -88,7 +87,7 @@ impl<T> Translate<T> for MultiPolygon<T>
 }
 
 impl<T> Translate<T> for MultiLineString<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
     fn translate(&self, xoff: T, yoff: T) -> Self {
         MultiLineString(self.0.iter().map(|ls| ls.translate(xoff, yoff)).collect())
@@ -96,7 +95,7 @@ impl<T> Translate<T> for MultiLineString<T>
 }
 
 impl<T> Translate<T> for MultiPoint<T>
-    where T: Float + FromPrimitive
+    where T: CoordFloat
 {
     fn translate(&self, xoff: T, yoff: T) -> Self {
         MultiPoint(self.0.iter().map(|p| p.translate(xoff, yoff)).collect())
@@ -0,0 +1,75 @@
+use types::{CoordFloat, Point};
+
+/// The orientation of an ordered triple of points.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Orientation {
+    /// The points turn clockwise (to the right)
+    Clockwise,
+    /// The points turn counter-clockwise (to the left)
+    CounterClockwise,
+    /// The points lie on a single line, within floating-point error
+    Collinear,
+}
+
+/// Returns the orientation of the points `a`, `b` and `c`, i.e. on which side
+/// of the directed line from `a` to `b` the point `c` lies.
+///
+/// The sign of the cross product `(b - a) x (c - a)` is only trusted if its
+/// magnitude exceeds a few units of rounding error relative to the terms it is
+/// computed from. This is a more conservative version of the error bound used by
+/// the first stage of [Shewchuk's adaptive predicate](https://www.cs.cmu.edu/~quake/robust.html).
+/// Anything smaller is reported as `Collinear`, rather than an arbitrary turn.
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::util::{orient2d, Orientation};
+///
+/// let a = Point::new(0., 0.);
+/// let b = Point::new(1., 0.);
+/// assert_eq!(orient2d(&a, &b, &Point::new(0.5, 1.)), Orientation::CounterClockwise);
+/// assert_eq!(orient2d(&a, &b, &Point::new(0.5, -1.)), Orientation::Clockwise);
+/// assert_eq!(orient2d(&a, &b, &Point::new(2., 0.)), Orientation::Collinear);
+/// ```
+pub fn orient2d<T: CoordFloat>(a: &Point<T>, b: &Point<T>, c: &Point<T>) -> Orientation {
+    let det_left = (b.x() - a.x()) * (c.y() - a.y());
+    let det_right = (b.y() - a.y()) * (c.x() - a.x());
+    let det = det_left - det_right;
+    let four = T::one() + T::one() + T::one() + T::one();
+    let err_bound = four * T::epsilon() * (det_left.abs() + det_right.abs());
+    if det > err_bound {
+        Orientation::CounterClockwise
+    } else if det < -err_bound {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::Point;
+    use super::*;
+
+    #[test]
+    fn nearly_collinear_test() {
+        let a = Point::new(0.0_f64, 0.0);
+        let b = Point::new(1.0, 1.0);
+        for &offset in &[1e-15, -1e-15, 0.0] {
+            let c = Point::new(2.0, 2.0 + offset);
+            assert_eq!(orient2d(&a, &b, &c), Orientation::Collinear);
+            assert_eq!(orient2d(&b, &a, &c), Orientation::Collinear);
+        }
+    }
+    #[test]
+    fn orientation_test() {
+        let a = Point::new(0.0_f64, 0.0);
+        let b = Point::new(1.0, 1.0);
+        assert_eq!(orient2d(&a, &b, &Point::new(2.0, 2.0 + 1e-9)), Orientation::CounterClockwise);
+        assert_eq!(orient2d(&a, &b, &Point::new(2.0, 2.0 - 1e-9)), Orientation::Clockwise);
+    }
+    #[test]
+    fn coincident_points_test() {
+        let a = Point::new(3.0_f32, -1.0);
+        assert_eq!(orient2d(&a, &a, &a), Orientation::Collinear);
+    }
+}
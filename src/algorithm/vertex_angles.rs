@@ -0,0 +1,65 @@
+use types::{CoordFloat, LineString};
+
+/// Returns the signed turning angle, in radians, at each interior vertex of a `LineString`.
+///
+/// The angle is the change in direction between the incoming and outgoing segments,
+/// in the range `[-π, π]`: positive for left (counter-clockwise) turns, negative for right
+/// turns, and zero where the line continues straight on. A `LineString` with fewer than
+/// three points has no interior vertices, and yields no angles.
+///
+/// ```
+/// use std::f64::consts::FRAC_PI_2;
+/// use geo::{Point, LineString};
+/// use geo::algorithm::vertex_angles::turning_angles;
+///
+/// let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(1., 1.)]);
+/// assert_eq!(turning_angles(&ls), vec![FRAC_PI_2]);
+/// ```
+pub fn turning_angles<T: CoordFloat>(ls: &LineString<T>) -> Vec<T> {
+    ls.0
+        .windows(3)
+        .map(|ps| {
+            let (dx1, dy1) = (ps[1].x() - ps[0].x(), ps[1].y() - ps[0].y());
+            let (dx2, dy2) = (ps[2].x() - ps[1].x(), ps[2].y() - ps[1].y());
+            let cross = dx1 * dy2 - dy1 * dx2;
+            let dot = dx1 * dx2 + dy1 * dy2;
+            cross.atan2(dot)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+    use types::{Point, LineString};
+    use super::*;
+
+    #[test]
+    fn right_angle_test() {
+        let left = LineString(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(1., 1.)]);
+        assert_eq!(turning_angles(&left), vec![FRAC_PI_2]);
+        let right = LineString(vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(1., -1.)]);
+        assert_eq!(turning_angles(&right), vec![-FRAC_PI_2]);
+    }
+    #[test]
+    fn straight_line_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 1.), Point::new(3., 3.)]);
+        assert_eq!(turning_angles(&ls), vec![0.]);
+    }
+    #[test]
+    fn multiple_vertices_test() {
+        let ls = LineString(vec![Point::new(0., 0.),
+                                 Point::new(1., 0.),
+                                 Point::new(2., 1.),
+                                 Point::new(2., 2.)]);
+        let angles = turning_angles(&ls);
+        assert_eq!(angles.len(), 2);
+        assert_relative_eq!(angles[0], FRAC_PI_4);
+        assert_relative_eq!(angles[1], FRAC_PI_4);
+    }
+    #[test]
+    fn too_short_test() {
+        let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 0.)]);
+        assert!(turning_angles(&ls).is_empty());
+    }
+}
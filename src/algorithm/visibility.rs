@@ -0,0 +1,65 @@
+use types::{CoordFloat, Point, Line, MultiPolygon};
+use algorithm::intersects::Intersects;
+
+/// Checks if the straight line between two points is clear of every polygon in a set.
+///
+/// The line is blocked if it touches the boundary of any obstacle, including the edges of
+/// its holes, or if either point lies in an obstacle's filled region. A line lying wholly
+/// within a hole is clear.
+///
+/// ```
+/// use geo::{Point, Bbox, MultiPolygon};
+/// use geo::algorithm::visibility::has_line_of_sight;
+///
+/// let wall = Bbox { xmin: 4., xmax: 6., ymin: -1., ymax: 1. }.to_polygon();
+/// let obstacles = MultiPolygon(vec![wall]);
+///
+/// assert!(!has_line_of_sight(&Point::new(0., 0.), &Point::new(10., 0.), &obstacles));
+/// assert!(has_line_of_sight(&Point::new(0., 5.), &Point::new(10., 5.), &obstacles));
+/// ```
+pub fn has_line_of_sight<T>(a: &Point<T>, b: &Point<T>, obstacles: &MultiPolygon<T>) -> bool
+    where T: CoordFloat
+{
+    let line = Line::new(*a, *b);
+    !obstacles.0.iter().any(|obstacle| line.intersects(obstacle))
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPolygon, Bbox};
+    use super::*;
+
+    #[test]
+    fn blocked_and_clear_test() {
+        let (a, b) = (Point::new(0., 0.), Point::new(10., 10.));
+        // a square straddling the diagonal
+        let between = Bbox { xmin: 4., xmax: 6., ymin: 4., ymax: 6. }.to_polygon();
+        // a square well to one side of it
+        let beside = Bbox { xmin: 6., xmax: 8., ymin: 0., ymax: 2. }.to_polygon();
+        assert!(!has_line_of_sight(&a, &b, &MultiPolygon(vec![between.clone(), beside.clone()])));
+        assert!(has_line_of_sight(&a, &b, &MultiPolygon(vec![beside.clone()])));
+        assert!(has_line_of_sight(&b, &a, &MultiPolygon(vec![beside])));
+        assert!(has_line_of_sight(&a, &b, &MultiPolygon(vec![])));
+    }
+    #[test]
+    fn touching_and_inside_test() {
+        let obstacle = Bbox { xmin: 2., xmax: 4., ymin: 2., ymax: 4. }.to_polygon();
+        let obstacles = MultiPolygon(vec![obstacle]);
+        // grazing a corner
+        assert!(!has_line_of_sight(&Point::new(0., 0.), &Point::new(2., 2.), &obstacles));
+        // starting inside the obstacle, without crossing its boundary
+        assert!(!has_line_of_sight(&Point::new(2.5, 2.5), &Point::new(3.5, 3.5), &obstacles));
+    }
+    #[test]
+    fn hole_test() {
+        let ring = |min: f64, max: f64| {
+            LineString(vec![Point::new(min, min), Point::new(max, min), Point::new(max, max),
+                            Point::new(min, max), Point::new(min, min)])
+        };
+        let courtyard = MultiPolygon(vec![Polygon::new(ring(0., 10.), vec![ring(2., 8.)])]);
+        // across the open courtyard
+        assert!(has_line_of_sight(&Point::new(3., 3.), &Point::new(7., 6.), &courtyard));
+        // out through the walls
+        assert!(!has_line_of_sight(&Point::new(5., 5.), &Point::new(15., 5.), &courtyard));
+    }
+}
@@ -0,0 +1,15 @@
+/// The default tolerance for deciding whether two positions coincide, used by
+/// `Intersects` and `Contains` between points and lines, by `OnBoundary`, and by
+/// `LineString::reverse_if_needed`.
+///
+/// It is an absolute distance, in the units of the coordinates. The predicates each have
+/// a `_with_epsilon` variant taking an explicit tolerance, for data at scales where this
+/// one is too coarse or too fine.
+pub const DEFAULT_EPSILON: f64 = 1e-9;
+
+/// The radius of the sphere used to model the Earth, in meters, by the haversine and
+/// great-circle algorithms.
+///
+/// This is the Earth's mean radius, as commonly rounded. It is smaller than the WGS84
+/// equatorial radius of 6378137 meters.
+pub const MEAN_EARTH_RADIUS: f64 = 6371000.0;
@@ -0,0 +1,87 @@
+//! `From` conversions between this crate's geometries and those of the `geo-types` crate.
+//! Requires the `geo-types` feature.
+
+use geo_types;
+use num_traits::Float;
+use types::{Point, LineString, Polygon};
+
+impl<T> From<Point<T>> for geo_types::Point<T>
+    where T: Float + geo_types::CoordNum
+{
+    fn from(point: Point<T>) -> Self {
+        geo_types::Point::new(point.x(), point.y())
+    }
+}
+
+impl<T> From<geo_types::Point<T>> for Point<T>
+    where T: Float + geo_types::CoordNum
+{
+    fn from(point: geo_types::Point<T>) -> Self {
+        Point::new(point.x(), point.y())
+    }
+}
+
+impl<T> From<LineString<T>> for geo_types::LineString<T>
+    where T: Float + geo_types::CoordNum
+{
+    fn from(line_string: LineString<T>) -> Self {
+        line_string.0
+            .into_iter()
+            .map(|p| geo_types::Coord { x: p.x(), y: p.y() })
+            .collect()
+    }
+}
+
+impl<T> From<geo_types::LineString<T>> for LineString<T>
+    where T: Float + geo_types::CoordNum
+{
+    fn from(line_string: geo_types::LineString<T>) -> Self {
+        LineString(line_string.0.into_iter().map(|c| Point::new(c.x, c.y)).collect())
+    }
+}
+
+impl<T> From<Polygon<T>> for geo_types::Polygon<T>
+    where T: Float + geo_types::CoordNum
+{
+    fn from(polygon: Polygon<T>) -> Self {
+        geo_types::Polygon::new(polygon.exterior.into(),
+                                polygon.interiors.into_iter().map(|ring| ring.into()).collect())
+    }
+}
+
+impl<T> From<geo_types::Polygon<T>> for Polygon<T>
+    where T: Float + geo_types::CoordNum
+{
+    fn from(polygon: geo_types::Polygon<T>) -> Self {
+        let (exterior, interiors) = polygon.into_inner();
+        Polygon::new(exterior.into(),
+                     interiors.into_iter().map(|ring| ring.into()).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use geo_types;
+    use types::{Point, LineString, Polygon};
+
+    #[test]
+    fn point_round_trip_test() {
+        let p = Point::new(1.5, -2.25);
+        let converted: geo_types::Point<f64> = p.into();
+        assert_eq!(converted, geo_types::Point::new(1.5, -2.25));
+        assert_eq!(Point::from(converted), p);
+    }
+    #[test]
+    fn polygon_with_hole_round_trip_test() {
+        let p = |x, y| Point::new(x, y);
+        let exterior = LineString(vec![p(0., 0.), p(10., 0.), p(10., 10.), p(0., 10.), p(0., 0.)]);
+        let hole = LineString(vec![p(2., 2.), p(2., 4.), p(4., 4.), p(4., 2.), p(2., 2.)]);
+        let poly = Polygon::new(exterior, vec![hole]);
+
+        let converted: geo_types::Polygon<f64> = poly.clone().into();
+        assert_eq!(converted.exterior().0.len(), 5);
+        assert_eq!(converted.interiors().len(), 1);
+        assert_eq!(converted.interiors()[0].0[1], geo_types::Coord { x: 2., y: 4. });
+        assert_eq!(Polygon::from(converted), poly);
+    }
+}
@@ -2,6 +2,10 @@
 extern crate serde_derive;
 extern crate serde;
 extern crate num_traits;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "geo-types")]
+extern crate geo_types;
 
 pub use traits::ToGeo;
 pub use types::*;
@@ -9,8 +13,14 @@ pub use algorithm::*;
 
 mod traits;
 mod types;
+#[cfg(feature = "geo-types")]
+mod geo_types_compat;
+/// The default predicate tolerance and the Earth radius used by the spherical algorithms
+pub mod constants;
 /// This module includes all the functions of geometric calculations
 pub mod algorithm;
+/// Reading and writing geometries as Well-Known Binary
+pub mod wkb;
 
 #[cfg(test)]
 #[macro_use]
@@ -1,12 +1,48 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
 use std::ops::Add;
 use std::ops::AddAssign;
 use std::ops::Neg;
 use std::ops::Sub;
 
-use num_traits::{Float, ToPrimitive};
+use num_traits::{Float, FromPrimitive, Num, NumCast, ToPrimitive};
+
+use constants::DEFAULT_EPSILON;
 
 pub static COORD_PRECISION: f32 = 1e-1; // 0.1m
 
+/// The numeric requirements of a coordinate component.
+///
+/// This is implemented for every type meeting the bounds, including the primitive integer
+/// and floating point types, so it never needs to be implemented by hand.
+pub trait CoordNum: Num + Copy + NumCast + PartialOrd {}
+
+impl<T: Num + Copy + NumCast + PartialOrd> CoordNum for T {}
+
+/// The numeric requirements of the coordinate components used by the algorithms, which
+/// need floating point operations such as `sqrt`, and conversions from primitive values.
+///
+/// This is implemented for every type meeting the bounds, such as `f32` and `f64`, so user
+/// code can be generic over `T: CoordFloat` without depending on `num_traits` directly.
+///
+/// ```
+/// use geo::{CoordFloat, Point, LineString, Polygon};
+/// use geo::algorithm::area::Area;
+///
+/// fn unit_square<T: CoordFloat>() -> Polygon<T> {
+///     let (zero, one) = (T::zero(), T::one());
+///     let ring = vec![Point::new(zero, zero), Point::new(one, zero), Point::new(one, one),
+///                     Point::new(zero, one), Point::new(zero, zero)];
+///     Polygon::new(LineString(ring), vec![])
+/// }
+///
+/// assert_eq!(unit_square::<f32>().area(), 1.);
+/// ```
+pub trait CoordFloat: CoordNum + Float + FromPrimitive {}
+
+impl<T: CoordNum + Float + FromPrimitive> CoordFloat for T {}
+
 #[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct Coordinate<T>
     where T: Float
@@ -15,6 +51,63 @@ pub struct Coordinate<T>
     pub y: T,
 }
 
+// compare two floats, ordering NaN after every other value
+pub(crate) fn total_cmp<T>(a: T, b: T) -> Ordering
+    where T: Float
+{
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+impl<T> Coordinate<T>
+    where T: Float
+{
+    /// Compares two coordinates by `x`, then by `y`.
+    ///
+    /// Unlike `partial_cmp` this is a total order, so it can be used to sort coordinates
+    /// deterministically: NaN compares equal to NaN and greater than any other value,
+    /// and `-0.0` compares equal to `0.0`.
+    ///
+    /// ```
+    /// use std::f64;
+    /// use geo::Coordinate;
+    ///
+    /// let mut coords = vec![Coordinate { x: 1., y: 0. },
+    ///                       Coordinate { x: f64::NAN, y: 0. },
+    ///                       Coordinate { x: 0., y: 5. }];
+    /// coords.sort_by(|a, b| a.cmp_lexicographic(b));
+    ///
+    /// assert_eq!(coords[0], Coordinate { x: 0., y: 5. });
+    /// assert_eq!(coords[1], Coordinate { x: 1., y: 0. });
+    /// assert!(coords[2].x.is_nan());
+    /// ```
+    pub fn cmp_lexicographic(&self, other: &Coordinate<T>) -> Ordering {
+        total_cmp(self.x, other.x).then_with(|| total_cmp(self.y, other.y))
+    }
+
+    /// Linearly interpolates between two coordinates, returning `self` at `t = 0.0` and
+    /// `other` at `t = 1.0`. Values of `t` outside that range extrapolate along the line.
+    ///
+    /// ```
+    /// use geo::Coordinate;
+    ///
+    /// let a = Coordinate { x: 0., y: 10. };
+    /// let b = Coordinate { x: 4., y: 20. };
+    ///
+    /// assert_eq!(a.lerp(&b, 0.25), Coordinate { x: 1., y: 12.5 });
+    /// ```
+    pub fn lerp(&self, other: &Coordinate<T>, t: T) -> Coordinate<T> {
+        Coordinate {
+            x: self.x + (other.x - self.x) * t,
+            y: self.y + (other.y - self.y) * t,
+        }
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct Bbox<T>
     where T: Float
@@ -54,6 +147,41 @@ pub struct ExtremePoint<T>
     pub xmin: Point<T>,
 }
 
+/// Which ring of a `Polygon` a point belongs to.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum RingRole {
+    /// The exterior ring
+    Exterior,
+    /// The interior ring at the given index
+    Interior(usize),
+}
+
+/// The result of a closest point query against a geometry.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Closest<T>
+    where T: Float
+{
+    /// The closest point lies in the interior of one of the geometry's segments.
+    Intersection(Point<T>),
+    /// The closest point is one of the geometry's vertices.
+    SinglePoint(Point<T>),
+    /// There is no single closest point, e.g. because the geometry is empty.
+    Indeterminate,
+}
+
+/// The result of projecting a point onto a linear geometry.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Projection<T>
+    where T: Float
+{
+    /// The point on the geometry nearest to the projected point
+    pub point: Point<T>,
+    /// The distance along the geometry from its start to `point`
+    pub along_distance: T,
+    /// The distance from the projected point to `point`
+    pub offset_distance: T,
+}
+
 #[derive(PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct Point<T> (pub Coordinate<T>) where T: Float;
 
@@ -76,6 +204,23 @@ impl<T> Point<T>
         Point(Coordinate { x: x, y: y })
     }
 
+    /// Creates a new point, or returns `None` if either coordinate is NaN or infinite.
+    ///
+    /// ```
+    /// use std::f64;
+    /// use geo::Point;
+    ///
+    /// assert_eq!(Point::new_checked(1.234, 2.345), Some(Point::new(1.234, 2.345)));
+    /// assert_eq!(Point::new_checked(f64::NAN, 2.345), None);
+    /// ```
+    pub fn new_checked(x: T, y: T) -> Option<Point<T>> {
+        if x.is_finite() && y.is_finite() {
+            Some(Point::new(x, y))
+        } else {
+            None
+        }
+    }
+
     /// Returns the x/horizontal component of the point.
     ///
     /// ```
@@ -200,6 +345,75 @@ impl<T> Point<T>
     pub fn dot(&self, point: &Point<T>) -> T {
         self.x() * point.x() + self.y() * point.y()
     }
+
+    /// Returns a point with both components rounded down to the nearest integer.
+    ///
+    /// ```
+    /// use geo::Point;
+    ///
+    /// let p = Point::new(1.7, -2.3).floor();
+    ///
+    /// assert_eq!(p, Point::new(1.0, -3.0));
+    /// ```
+    pub fn floor(&self) -> Point<T> {
+        Point::new(self.x().floor(), self.y().floor())
+    }
+
+    /// Returns a point with both components rounded up to the nearest integer.
+    ///
+    /// ```
+    /// use geo::Point;
+    ///
+    /// let p = Point::new(1.7, -2.3).ceil();
+    ///
+    /// assert_eq!(p, Point::new(2.0, -2.0));
+    /// ```
+    pub fn ceil(&self) -> Point<T> {
+        Point::new(self.x().ceil(), self.y().ceil())
+    }
+
+    /// Returns a point with both components rounded to the nearest integer,
+    /// rounding half-way cases away from `0.0`.
+    ///
+    /// ```
+    /// use geo::Point;
+    ///
+    /// let p = Point::new(1.5, -2.3).round();
+    ///
+    /// assert_eq!(p, Point::new(2.0, -2.0));
+    /// ```
+    pub fn round(&self) -> Point<T> {
+        Point::new(self.x().round(), self.y().round())
+    }
+
+    /// Returns the components of the point as integers, truncating towards zero.
+    ///
+    /// Panics if either component is NaN or doesn't fit in an `i64`.
+    ///
+    /// ```
+    /// use geo::Point;
+    ///
+    /// let p = Point::new(1.7, -2.3);
+    ///
+    /// assert_eq!(p.to_i64_pair(), (1, -2));
+    /// ```
+    pub fn to_i64_pair(&self) -> (i64, i64) {
+        (self.x().to_i64().unwrap(), self.y().to_i64().unwrap())
+    }
+
+    /// Linearly interpolates between two points, returning `self` at `t = 0.0` and
+    /// `other` at `t = 1.0`. Values of `t` outside that range extrapolate along the line.
+    ///
+    /// ```
+    /// use geo::Point;
+    ///
+    /// let p = Point::new(0., 10.).lerp(&Point::new(4., 20.), 0.5);
+    ///
+    /// assert_eq!(p, Point::new(2., 15.));
+    /// ```
+    pub fn lerp(&self, other: &Point<T>, t: T) -> Point<T> {
+        Point(self.0.lerp(&other.0, t))
+    }
 }
 
 impl<T> Neg for Point<T>
@@ -262,6 +476,134 @@ impl<T> Sub for Point<T>
     }
 }
 
+/// A `Point` that can be hashed, for deduplicating points with a `HashSet` or `HashMap`.
+///
+/// Two `HashablePoint`s are equal when their coordinates compare equal by
+/// `Coordinate::cmp_lexicographic`, so `-0.0` equals `0.0` and NaN equals NaN, and
+/// equal points always hash the same.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use geo::{Point, HashablePoint};
+///
+/// let points = vec![Point::new(1., 2.), Point::new(-0., 3.), Point::new(1., 2.), Point::new(0., 3.)];
+/// let unique: HashSet<HashablePoint<f64>> = points.into_iter().map(HashablePoint).collect();
+///
+/// assert_eq!(unique.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct HashablePoint<T>(pub Point<T>) where T: Float;
+
+impl<T: Float> From<Point<T>> for HashablePoint<T> { fn from(x: Point<T>) -> HashablePoint<T> { HashablePoint(x) } }
+
+// the bit pattern of a float, with every zero and every NaN mapped to a single pattern
+fn hash_float<T, H>(value: T, state: &mut H)
+    where T: Float,
+          H: Hasher
+{
+    if value.is_nan() {
+        T::nan().integer_decode().hash(state);
+    } else if value == T::zero() {
+        T::zero().integer_decode().hash(state);
+    } else {
+        value.integer_decode().hash(state);
+    }
+}
+
+impl<T> PartialEq for HashablePoint<T>
+    where T: Float
+{
+    fn eq(&self, other: &HashablePoint<T>) -> bool {
+        (self.0).0.cmp_lexicographic(&(other.0).0) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for HashablePoint<T> where T: Float {}
+
+impl<T> Hash for HashablePoint<T>
+    where T: Float
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_float(self.0.x(), state);
+        hash_float(self.0.y(), state);
+    }
+}
+
+impl<T> Bbox<T>
+    where T: Float
+{
+    /// Returns the boundary of the bounding box as a closed, counter-clockwise
+    /// `LineString`, starting and ending at the `(xmin, ymin)` corner.
+    ///
+    /// ```
+    /// use geo::{Bbox, Point};
+    ///
+    /// let bbox = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 1. };
+    /// let ls = bbox.to_line_string();
+    ///
+    /// assert_eq!(ls.0.len(), 5);
+    /// assert_eq!(ls.0[0], Point::new(0., 0.));
+    /// assert_eq!(ls.0[2], Point::new(2., 1.));
+    /// assert_eq!(ls.0.first(), ls.0.last());
+    /// ```
+    pub fn to_line_string(&self) -> LineString<T> {
+        LineString(vec![Point::new(self.xmin, self.ymin),
+                        Point::new(self.xmax, self.ymin),
+                        Point::new(self.xmax, self.ymax),
+                        Point::new(self.xmin, self.ymax),
+                        Point::new(self.xmin, self.ymin)])
+    }
+
+    /// Returns the bounding box as a `Polygon` with no interior rings.
+    ///
+    /// ```
+    /// use geo::Bbox;
+    ///
+    /// let bbox = Bbox { xmin: 0., xmax: 2., ymin: 0., ymax: 1. };
+    /// let poly = bbox.to_polygon();
+    ///
+    /// assert_eq!(poly.exterior, bbox.to_line_string());
+    /// assert!(poly.interiors.is_empty());
+    /// ```
+    pub fn to_polygon(&self) -> Polygon<T> {
+        Polygon::new(self.to_line_string(), vec![])
+    }
+
+    /// Returns the bounding box grown by `margin` on every side.
+    ///
+    /// A negative margin shrinks the box, and can leave the mins greater than the maxes.
+    ///
+    /// ```
+    /// use geo::Bbox;
+    ///
+    /// let bbox = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. };
+    ///
+    /// assert_eq!(bbox.expand(1.), Bbox { xmin: -1., xmax: 11., ymin: -1., ymax: 11. });
+    /// ```
+    pub fn expand(&self, margin: T) -> Bbox<T> {
+        self.expand_xy(margin, margin)
+    }
+
+    /// Returns the bounding box grown by `mx` on its left and right, and by `my` on its
+    /// top and bottom.
+    ///
+    /// ```
+    /// use geo::Bbox;
+    ///
+    /// let bbox = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. };
+    ///
+    /// assert_eq!(bbox.expand_xy(2., -1.), Bbox { xmin: -2., xmax: 12., ymin: 1., ymax: 9. });
+    /// ```
+    pub fn expand_xy(&self, mx: T, my: T) -> Bbox<T> {
+        Bbox {
+            xmin: self.xmin - mx,
+            xmax: self.xmax + mx,
+            ymin: self.ymin - my,
+            ymax: self.ymax + my,
+        }
+    }
+}
+
 impl<T> Add for Bbox<T>
     where T: Float + ToPrimitive
 {
@@ -322,6 +664,28 @@ pub struct MultiPoint<T>(pub Vec<Point<T>>) where T: Float;
 
 impl<T: Float> From<Point<T>> for MultiPoint<T> { fn from(x: Point<T>) -> MultiPoint<T> { MultiPoint(vec![x]) } }
 
+impl<T> IntoIterator for MultiPoint<T>
+    where T: Float
+{
+    type Item = Point<T>;
+    type IntoIter = ::std::vec::IntoIter<Point<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MultiPoint<T>
+    where T: Float
+{
+    type Item = &'a Point<T>;
+    type IntoIter = ::std::slice::Iter<'a, Point<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct Line<T>
     where T: Float
@@ -351,11 +715,180 @@ impl<T> Line<T>
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct LineString<T>(pub Vec<Point<T>>) where T: Float;
 
+impl<T> LineString<T>
+    where T: Float
+{
+    /// Creates a new, empty `LineString` with room for `capacity` points
+    /// before reallocating.
+    ///
+    /// ```
+    /// use geo::LineString;
+    ///
+    /// let ls = LineString::<f64>::with_capacity(10);
+    ///
+    /// assert!(ls.0.is_empty());
+    /// assert!(ls.0.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> LineString<T> {
+        LineString(Vec::with_capacity(capacity))
+    }
+
+    /// Appends a point to the end of the `LineString`.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let mut ls = LineString::with_capacity(2);
+    /// ls.push(Point::new(0., 0.));
+    /// ls.push(Point::new(1., 2.));
+    ///
+    /// assert_eq!(ls.0, vec![Point::new(0., 0.), Point::new(1., 2.)]);
+    /// ```
+    pub fn push(&mut self, point: Point<T>) {
+        self.0.push(point);
+    }
+
+    /// Appends the points of `other` to the end of the `LineString`. If `other` starts
+    /// where the `LineString` ends, the shared point is only kept once.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let mut ls = LineString(vec![Point::new(0., 0.), Point::new(1., 0.)]);
+    /// ls.append(&LineString(vec![Point::new(1., 0.), Point::new(1., 1.)]));
+    ///
+    /// assert_eq!(ls.0, vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(1., 1.)]);
+    /// ```
+    pub fn append(&mut self, other: &LineString<T>) {
+        let skip = match (self.0.last(), other.0.first()) {
+            (Some(last), Some(first)) if last == first => 1,
+            _ => 0,
+        };
+        self.0.extend_from_slice(&other.0[skip..]);
+    }
+
+    /// Returns an iterator over the segments of the `LineString`, as `Line`s.
+    ///
+    /// ```
+    /// use geo::{Point, Line, LineString};
+    ///
+    /// let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 2.), Point::new(3., 2.)]);
+    /// let lines: Vec<Line<f64>> = ls.lines().collect();
+    ///
+    /// assert_eq!(lines, vec![Line::new(Point::new(0., 0.), Point::new(1., 2.)),
+    ///                        Line::new(Point::new(1., 2.), Point::new(3., 2.))]);
+    /// ```
+    pub fn lines<'a>(&'a self) -> impl Iterator<Item = Line<T>> + 'a {
+        self.0.windows(2).map(|ps| Line::new(ps[0], ps[1]))
+    }
+
+    /// Returns an iterator over mutable references to the points of the `LineString`, for
+    /// editing them in place.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let mut ls = LineString(vec![Point::new(0., 0.), Point::new(1., 2.)]);
+    /// for p in ls.coords_mut() {
+    ///     let x = p.x();
+    ///     p.set_x(x + 10.);
+    /// }
+    ///
+    /// assert_eq!(ls.0, vec![Point::new(10., 0.), Point::new(11., 2.)]);
+    /// ```
+    pub fn coords_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut Point<T>> + 'a {
+        self.0.iter_mut()
+    }
+
+    /// Returns the points of the `LineString` as a slice.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 2.)]);
+    ///
+    /// assert_eq!(ls.as_slice(), &[Point::new(0., 0.), Point::new(1., 2.)]);
+    /// ```
+    pub fn as_slice(&self) -> &[Point<T>] {
+        &self.0
+    }
+
+    /// Consumes the `LineString`, returning its points without copying them.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let ls = LineString(vec![Point::new(0., 0.), Point::new(1., 2.)]);
+    ///
+    /// assert_eq!(ls.into_inner(), vec![Point::new(0., 0.), Point::new(1., 2.)]);
+    /// ```
+    pub fn into_inner(self) -> Vec<Point<T>> {
+        self.0
+    }
+
+    /// Reverses the `LineString` in place if it ends at `should_start_at`, so that it
+    /// starts there instead. Points are compared to within `DEFAULT_EPSILON` in each
+    /// coordinate. A `LineString` which already starts there is left unchanged.
+    ///
+    /// ```
+    /// use geo::{Point, LineString};
+    ///
+    /// let mut ls = LineString(vec![Point::new(0., 0.), Point::new(1., 2.)]);
+    /// ls.reverse_if_needed(&Point::new(1., 2.));
+    ///
+    /// assert_eq!(ls.0, vec![Point::new(1., 2.), Point::new(0., 0.)]);
+    /// ```
+    pub fn reverse_if_needed(&mut self, should_start_at: &Point<T>) {
+        let epsilon = T::from(DEFAULT_EPSILON).unwrap();
+        let near = |p: &Point<T>| {
+            (p.x() - should_start_at.x()).abs() <= epsilon &&
+            (p.y() - should_start_at.y()).abs() <= epsilon
+        };
+        let needs_reversing = match (self.0.first(), self.0.last()) {
+            (Some(first), Some(last)) => !near(first) && near(last),
+            _ => false,
+        };
+        if needs_reversing {
+            self.0.reverse();
+        }
+    }
+}
+
+impl<T> Extend<Point<T>> for LineString<T>
+    where T: Float
+{
+    fn extend<I: IntoIterator<Item = Point<T>>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct MultiLineString<T>(pub Vec<LineString<T>>) where T: Float;
 
 impl<T: Float> From<LineString<T>> for MultiLineString<T> { fn from(x: LineString<T>) -> MultiLineString<T> { MultiLineString(vec![x]) } }
 
+impl<T> IntoIterator for MultiLineString<T>
+    where T: Float
+{
+    type Item = LineString<T>;
+    type IntoIter = ::std::vec::IntoIter<LineString<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MultiLineString<T>
+    where T: Float
+{
+    type Item = &'a LineString<T>;
+    type IntoIter = ::std::slice::Iter<'a, LineString<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct Polygon<T>
     where T: Float
@@ -383,6 +916,107 @@ impl<T> Polygon<T>
     pub fn new(exterior: LineString<T>, interiors: Vec<LineString<T>>) -> Polygon<T> {
         Polygon { exterior: exterior, interiors: interiors }
     }
+
+    /// Returns the number of interior rings (holes) of the polygon.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    ///
+    /// let exterior = LineString(vec![Point::new(0., 0.), Point::new(1., 1.),
+    ///                                Point::new(1., 0.), Point::new(0., 0.)]);
+    /// let p = Polygon::new(exterior, vec![]);
+    /// assert_eq!(p.num_interior_rings(), 0);
+    /// ```
+    pub fn num_interior_rings(&self) -> usize {
+        self.interiors.len()
+    }
+
+    /// Returns the interior ring at index `i`, or `None` if there is no such ring.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    ///
+    /// let exterior = LineString(vec![Point::new(0., 0.), Point::new(1., 1.),
+    ///                                Point::new(1., 0.), Point::new(0., 0.)]);
+    /// let interior = LineString(vec![Point::new(0.1, 0.1), Point::new(0.9, 0.9),
+    ///                                Point::new(0.9, 0.1), Point::new(0.1, 0.1)]);
+    /// let p = Polygon::new(exterior, vec![interior.clone()]);
+    /// assert_eq!(p.interior_ring(0), Some(&interior));
+    /// assert_eq!(p.interior_ring(1), None);
+    /// ```
+    pub fn interior_ring(&self, i: usize) -> Option<&LineString<T>> {
+        self.interiors.get(i)
+    }
+
+    /// Returns an iterator over the segments of every ring of the polygon, as `Line`s.
+    /// The exterior ring comes first, followed by each interior ring in turn.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon};
+    ///
+    /// let exterior = LineString(vec![Point::new(0., 0.), Point::new(1., 1.),
+    ///                                Point::new(1., 0.), Point::new(0., 0.)]);
+    /// let p = Polygon::new(exterior, vec![]);
+    /// assert_eq!(p.lines().count(), 3);
+    /// ```
+    pub fn lines<'a>(&'a self) -> impl Iterator<Item = Line<T>> + 'a {
+        self.exterior.lines().chain(self.interiors.iter().flat_map(|ring| ring.lines()))
+    }
+
+    /// Returns an iterator over the points of every ring of the polygon, with the ring each
+    /// belongs to and its index within that ring. The exterior ring comes first, followed by
+    /// each interior ring in turn.
+    ///
+    /// ```
+    /// use geo::{Point, LineString, Polygon, RingRole};
+    ///
+    /// let exterior = LineString(vec![Point::new(0., 0.), Point::new(4., 0.),
+    ///                                Point::new(4., 4.), Point::new(0., 0.)]);
+    /// let interior = LineString(vec![Point::new(2., 1.), Point::new(3., 1.),
+    ///                                Point::new(3., 2.), Point::new(2., 1.)]);
+    /// let p = Polygon::new(exterior, vec![interior]);
+    ///
+    /// let (role, i, point) = p.vertices_with_location().nth(5).unwrap();
+    /// assert_eq!((role, i, *point), (RingRole::Interior(0), 1, Point::new(3., 1.)));
+    /// ```
+    pub fn vertices_with_location<'a>(&'a self) -> impl Iterator<Item = (RingRole, usize, &'a Point<T>)> + 'a {
+        let exterior = self.exterior.0.iter().enumerate().map(|(i, p)| (RingRole::Exterior, i, p));
+        let interiors = self.interiors.iter().enumerate().flat_map(|(ring, ls)| {
+            ls.0.iter().enumerate().map(move |(i, p)| (RingRole::Interior(ring), i, p))
+        });
+        exterior.chain(interiors)
+    }
+}
+
+impl<T> FromIterator<Point<T>> for Polygon<T>
+    where T: Float
+{
+    /// Collects points into the exterior ring of a polygon with no interior rings.
+    /// The ring is closed if the last point doesn't equal the first.
+    ///
+    /// ```
+    /// use geo::{Point, Polygon};
+    /// use geo::algorithm::area::Area;
+    ///
+    /// let poly: Polygon<f64> = vec![Point::new(0., 0.), Point::new(2., 0.),
+    ///                               Point::new(2., 3.), Point::new(0., 3.)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(poly.exterior.0.len(), 5);
+    /// assert_eq!(poly.exterior.0.first(), poly.exterior.0.last());
+    /// assert!(poly.interiors.is_empty());
+    /// assert_eq!(poly.area(), 6.);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = Point<T>>>(iter: I) -> Self {
+        let mut points: Vec<Point<T>> = iter.into_iter().collect();
+        if let (Some(&first), Some(&last)) = (points.first(), points.last()) {
+            if first != last {
+                points.push(first);
+            }
+        }
+        Polygon::new(LineString(points), vec![])
+    }
 }
 
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
@@ -390,6 +1024,28 @@ pub struct MultiPolygon<T>(pub Vec<Polygon<T>>) where T: Float;
 
 impl<T: Float> From<Polygon<T>> for MultiPolygon<T> { fn from(x: Polygon<T>) -> MultiPolygon<T> { MultiPolygon(vec![x]) } }
 
+impl<T> IntoIterator for MultiPolygon<T>
+    where T: Float
+{
+    type Item = Polygon<T>;
+    type IntoIter = ::std::vec::IntoIter<Polygon<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MultiPolygon<T>
+    where T: Float
+{
+    type Item = &'a Polygon<T>;
+    type IntoIter = ::std::slice::Iter<'a, Polygon<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 #[derive(PartialEq, Clone, Debug)]
 pub struct GeometryCollection<T>(pub Vec<Geometry<T>>) where T: Float;
 
@@ -419,6 +1075,7 @@ impl<T: Float> From<GeometryCollection<T>> for Geometry<T> { fn from(x: Geometry
 #[cfg(test)]
 mod test {
     use ::types::*;
+    use ::algorithm::area::Area;
 
     #[test]
     fn type_test() {
@@ -446,4 +1103,267 @@ mod test {
         assert_eq!(p.exterior, exterior);
         assert_eq!(p.interiors, interiors);
     }
+
+    #[test]
+    fn bbox_to_polygon_test() {
+        let bbox = Bbox { xmin: -1., xmax: 3., ymin: 2., ymax: 5. };
+        let ls = bbox.to_line_string();
+        assert_eq!(ls.0, vec![Point::new(-1., 2.), Point::new(3., 2.), Point::new(3., 5.),
+                              Point::new(-1., 5.), Point::new(-1., 2.)]);
+
+        let poly = bbox.to_polygon();
+        assert_eq!(poly.exterior.0.len(), 5);
+        assert_eq!(poly.exterior.0.first(), poly.exterior.0.last());
+        assert!(poly.interiors.is_empty());
+    }
+
+    #[test]
+    fn polygon_from_iter_test() {
+        let closed = vec![Point::new(0., 0.), Point::new(1., 0.), Point::new(0., 1.), Point::new(0., 0.)];
+        let poly: Polygon<f64> = closed.iter().cloned().collect();
+        assert_eq!(poly.exterior.0, closed);
+
+        let poly: Polygon<f64> = Vec::new().into_iter().collect();
+        assert!(poly.exterior.0.is_empty());
+    }
+
+    #[test]
+    fn multipolygon_iter_test() {
+        let square = |x: f64| Bbox { xmin: x, xmax: x + 1., ymin: 0., ymax: 1. }.to_polygon();
+        let mp = MultiPolygon(vec![square(0.), square(2.), square(4.)]);
+        let mut total = 0.;
+        for poly in &mp {
+            total += poly.area();
+        }
+        assert_eq!(total, 3.);
+        let owned: Vec<Polygon<f64>> = mp.into_iter().collect();
+        assert_eq!(owned.len(), 3);
+    }
+
+    #[test]
+    fn multipoint_multilinestring_iter_test() {
+        let mp = MultiPoint(vec![Point::new(1., 2.), Point::new(3., 4.)]);
+        assert_eq!((&mp).into_iter().map(|p| p.x()).sum::<f64>(), 4.);
+        let mls = MultiLineString(vec![LineString(mp.0.clone()), LineString(vec![])]);
+        assert_eq!(mls.into_iter().map(|ls| ls.0.len()).collect::<Vec<_>>(), vec![2, 0]);
+    }
+
+    #[test]
+    fn linestring_with_capacity_test() {
+        let mut ls = LineString::with_capacity(1000);
+        let capacity = ls.0.capacity();
+        assert!(capacity >= 1000);
+        for i in 0..1000 {
+            ls.push(Point::new(i as f64, 0.));
+        }
+        assert_eq!(ls.0.len(), 1000);
+        assert_eq!(ls.0.capacity(), capacity);
+    }
+
+    #[test]
+    fn polygon_interior_ring_test() {
+        let exterior = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. }.to_line_string();
+        let hole0 = Bbox { xmin: 1., xmax: 2., ymin: 1., ymax: 2. }.to_line_string();
+        let hole1 = Bbox { xmin: 5., xmax: 6., ymin: 5., ymax: 6. }.to_line_string();
+        let p = Polygon::new(exterior, vec![hole0, hole1.clone()]);
+
+        assert_eq!(p.num_interior_rings(), 2);
+        assert_eq!(p.interior_ring(1), Some(&hole1));
+        assert_eq!(p.interior_ring(5), None);
+    }
+
+    #[test]
+    fn point_new_checked_test() {
+        use std::f64;
+
+        assert_eq!(Point::new_checked(-1.5, 0.), Some(Point::new(-1.5, 0.)));
+        assert_eq!(Point::new_checked(f64::NAN, 0.0), None);
+        assert_eq!(Point::new_checked(0.0, f64::NAN), None);
+        assert_eq!(Point::new_checked(f64::INFINITY, 0.0), None);
+        assert_eq!(Point::new_checked(0.0, f64::NEG_INFINITY), None);
+    }
+
+    #[test]
+    fn point_integer_grid_test() {
+        let p = Point::new(1.7, 2.3);
+        assert_eq!(p.floor(), Point::new(1.0, 2.0));
+        assert_eq!(p.ceil(), Point::new(2.0, 3.0));
+        assert_eq!(p.round(), Point::new(2.0, 2.0));
+        assert_eq!(p.to_i64_pair(), (1, 2));
+        assert_eq!(Point::new(-1.7, -0.5).to_i64_pair(), (-1, 0));
+        assert_eq!(Point::new(-1.7, -0.5).floor().to_i64_pair(), (-2, -1));
+    }
+
+    #[test]
+    fn polygon_lines_test() {
+        let exterior = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. }.to_line_string();
+        let hole = Bbox { xmin: 2., xmax: 4., ymin: 2., ymax: 4. }.to_line_string();
+        let p = Polygon::new(exterior, vec![hole]);
+
+        let lines: Vec<Line<f64>> = p.lines().collect();
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines[0], Line::new(Point::new(0., 0.), Point::new(10., 0.)));
+        assert_eq!(lines[4], Line::new(Point::new(2., 2.), Point::new(4., 2.)));
+        assert!(lines.iter().all(|line| line.start != line.end));
+    }
+
+    #[test]
+    fn coord_float_generic_test() {
+        fn rect_area<T: CoordFloat>(width: T, height: T) -> T {
+            let zero = T::zero();
+            Bbox { xmin: zero, xmax: width, ymin: zero, ymax: height }.to_polygon().area()
+        }
+        assert_eq!(rect_area(2.0_f64, 3.0), 6.0);
+        assert_eq!(rect_area(0.5_f32, 4.0), 2.0);
+    }
+
+    #[test]
+    fn linestring_reverse_if_needed_test() {
+        let p = |x, y| Point::new(x, y);
+        let mut ends_there = LineString(vec![p(0., 0.), p(1., 1.), p(2., 0.)]);
+        ends_there.reverse_if_needed(&p(2., 1e-12));
+        assert_eq!(ends_there.0, vec![p(2., 0.), p(1., 1.), p(0., 0.)]);
+
+        let mut starts_there = LineString(vec![p(0., 0.), p(1., 1.), p(2., 0.)]);
+        starts_there.reverse_if_needed(&p(0., 0.));
+        assert_eq!(starts_there.0, vec![p(0., 0.), p(1., 1.), p(2., 0.)]);
+
+        // neither end matches
+        let mut elsewhere = LineString(vec![p(0., 0.), p(1., 1.), p(2., 0.)]);
+        elsewhere.reverse_if_needed(&p(1., 1.));
+        assert_eq!(elsewhere.0, vec![p(0., 0.), p(1., 1.), p(2., 0.)]);
+    }
+
+    #[test]
+    fn lerp_test() {
+        let (a, b) = (Coordinate { x: 1., y: -2. }, Coordinate { x: 5., y: 6. });
+        assert_eq!(a.lerp(&b, 0.), a);
+        assert_eq!(a.lerp(&b, 1.), b);
+        assert_eq!(a.lerp(&b, 0.5), Coordinate { x: 3., y: 2. });
+        assert_eq!(a.lerp(&b, 2.), Coordinate { x: 9., y: 14. });
+
+        let (p, q) = (Point(a), Point(b));
+        assert_eq!(p.lerp(&q, 0.), p);
+        assert_eq!(p.lerp(&q, 1.), q);
+        assert_eq!(p.lerp(&q, 0.5), Point::new(3., 2.));
+    }
+
+    #[test]
+    fn coordinate_cmp_lexicographic_test() {
+        use std::f64;
+
+        let c = |x, y| Coordinate { x, y };
+        let coords = vec![c(2., 1.), c(f64::NAN, 0.), c(1., f64::NAN), c(1., 3.), c(2., -1.),
+                          c(1., 3.), c(-5., 10.)];
+        let mut sorted = coords.clone();
+        sorted.sort_by(|a, b| a.cmp_lexicographic(b));
+        let mut reversed: Vec<_> = coords.iter().rev().cloned().collect();
+        reversed.sort_by(|a, b| a.cmp_lexicographic(b));
+
+        let expected = [(-5., 10.), (1., 3.), (1., 3.)];
+        for (coord, &(x, y)) in sorted.iter().zip(expected.iter()) {
+            assert_eq!(*coord, c(x, y));
+        }
+        assert_eq!(sorted[3].x, 1.);
+        assert!(sorted[3].y.is_nan());
+        assert_eq!(&sorted[4..6], &[c(2., -1.), c(2., 1.)]);
+        assert!(sorted[6].x.is_nan());
+        // the input order doesn't matter
+        for (a, b) in sorted.iter().zip(reversed.iter()) {
+            assert_eq!(a.cmp_lexicographic(b), Ordering::Equal);
+        }
+    }
+
+    #[test]
+    fn hashable_point_test() {
+        use std::collections::HashSet;
+        use std::f64;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(HashablePoint(Point::new(1.5, -2.))));
+        assert!(!set.insert(HashablePoint(Point::new(1.5, -2.))));
+        assert_eq!(set.len(), 1);
+
+        // signed zeros and NaNs are each a single value
+        assert!(set.insert(HashablePoint(Point::new(0., f64::NAN))));
+        assert!(!set.insert(HashablePoint(Point::new(-0., f64::NAN))));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&HashablePoint(Point::new(-0., -f64::NAN))));
+    }
+
+    #[test]
+    fn bbox_expand_test() {
+        let bbox = Bbox { xmin: 0., xmax: 10., ymin: 0., ymax: 10. };
+        assert_eq!(bbox.expand(1.), Bbox { xmin: -1., xmax: 11., ymin: -1., ymax: 11. });
+        assert_eq!(bbox.expand(-2.), Bbox { xmin: 2., xmax: 8., ymin: 2., ymax: 8. });
+        assert_eq!(bbox.expand_xy(0.5, 3.), Bbox { xmin: -0.5, xmax: 10.5, ymin: -3., ymax: 13. });
+    }
+
+    #[test]
+    fn linestring_coords_mut_test() {
+        let mut ls = LineString(vec![Point::new(0., 1.), Point::new(2., 3.), Point::new(4., 5.)]);
+        for p in ls.coords_mut() {
+            let y = p.y();
+            p.set_y(-2. * y);
+        }
+        assert_eq!(ls, LineString(vec![Point::new(0., -2.), Point::new(2., -6.), Point::new(4., -10.)]));
+
+        // editing a single vertex
+        if let Some(p) = ls.coords_mut().nth(1) {
+            p.set_x(7.);
+        }
+        assert_eq!(ls.0[1], Point::new(7., -6.));
+    }
+
+    #[test]
+    fn linestring_into_inner_test() {
+        let points = vec![Point::new(0., 1.), Point::new(2., 3.), Point::new(4., 5.)];
+        let ls = LineString(points.clone());
+        assert_eq!(ls.as_slice(), &points[..]);
+        assert_eq!(ls.as_slice().len(), 3);
+        assert_eq!(ls.into_inner(), points);
+        assert!(LineString::<f64>(vec![]).into_inner().is_empty());
+    }
+
+    #[test]
+    fn linestring_append_test() {
+        let p = |x, y| Point::new(x, y);
+        let mut ls = LineString(vec![p(0., 0.), p(1., 0.), p(2., 0.)]);
+        ls.append(&LineString(vec![p(2., 0.), p(3., 0.)]));
+        assert_eq!(ls.0, vec![p(0., 0.), p(1., 0.), p(2., 0.), p(3., 0.)]);
+
+        // without a shared endpoint, every point is kept
+        ls.append(&LineString(vec![p(5., 0.)]));
+        assert_eq!(ls.0.len(), 5);
+        ls.append(&LineString(vec![]));
+        assert_eq!(ls.0.len(), 5);
+        let mut empty = LineString(vec![]);
+        empty.append(&ls);
+        assert_eq!(empty, ls);
+    }
+
+    #[test]
+    fn linestring_extend_test() {
+        let mut ls = LineString(vec![Point::new(0., 0.)]);
+        ls.extend(vec![Point::new(0., 0.), Point::new(1., 1.)]);
+        assert_eq!(ls.0, vec![Point::new(0., 0.), Point::new(0., 0.), Point::new(1., 1.)]);
+    }
+
+    #[test]
+    fn polygon_vertices_with_location_test() {
+        let p = |x, y| Point::new(x, y);
+        let exterior = LineString(vec![p(0., 0.), p(10., 0.), p(10., 10.), p(0., 10.), p(0., 0.)]);
+        let hole = LineString(vec![p(2., 2.), p(2., 4.), p(4., 4.), p(2., 2.)]);
+        let poly = Polygon::new(exterior.clone(), vec![hole.clone()]);
+        let vertices: Vec<(RingRole, usize, Point<f64>)> = poly.vertices_with_location()
+            .map(|(role, i, point)| (role, i, *point))
+            .collect();
+        assert_eq!(vertices.len(), 9);
+        for (i, point) in exterior.0.iter().enumerate() {
+            assert_eq!(vertices[i], (RingRole::Exterior, i, *point));
+        }
+        for (i, point) in hole.0.iter().enumerate() {
+            assert_eq!(vertices[5 + i], (RingRole::Interior(0), i, *point));
+        }
+    }
 }
@@ -0,0 +1,408 @@
+use std::error::Error;
+use std::fmt;
+use types::{CoordFloat, Point, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon,
+            GeometryCollection, Geometry};
+
+/// The byte order of the numbers in Well-Known Binary.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Endianness {
+    /// Most significant byte first, flagged by `0`
+    BigEndian,
+    /// Least significant byte first, flagged by `1`
+    LittleEndian,
+}
+
+/// The ways decoding Well-Known Binary can fail.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum WkbError {
+    /// The input ended in the middle of a geometry
+    UnexpectedEnd,
+    /// A byte-order flag was neither `0` nor `1`
+    InvalidByteOrder(u8),
+    /// A geometry type code isn't one of the supported 2D types
+    UnknownGeometryType(u32),
+    /// A coordinate can't be represented by the target type
+    InvalidCoordinate,
+    /// There were bytes left over after the geometry
+    TrailingBytes,
+    /// Geometry collections were nested more than `MAX_NESTING` deep
+    TooDeep,
+}
+
+impl fmt::Display for WkbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            WkbError::UnexpectedEnd => write!(f, "unexpected end of WKB input"),
+            WkbError::InvalidByteOrder(flag) => write!(f, "invalid WKB byte order flag {}", flag),
+            WkbError::UnknownGeometryType(code) => write!(f, "unknown WKB geometry type {}", code),
+            WkbError::InvalidCoordinate => write!(f, "WKB coordinate out of range"),
+            WkbError::TrailingBytes => write!(f, "trailing bytes after WKB geometry"),
+            WkbError::TooDeep => write!(f, "WKB geometry collections nested too deeply"),
+        }
+    }
+}
+
+impl Error for WkbError {
+    fn description(&self) -> &str {
+        "invalid WKB"
+    }
+}
+
+// the OGC geometry type codes
+const POINT: u32 = 1;
+const LINESTRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTIPOINT: u32 = 4;
+const MULTILINESTRING: u32 = 5;
+const MULTIPOLYGON: u32 = 6;
+const GEOMETRYCOLLECTION: u32 = 7;
+
+/// The deepest that geometry collections may be nested in decoded Well-Known Binary.
+///
+/// Decoding recurses into each nested collection, so without a limit a small, malicious
+/// input could overflow the stack.
+pub const MAX_NESTING: usize = 32;
+
+struct Writer {
+    bytes: Vec<u8>,
+    endianness: Endianness,
+}
+
+impl Writer {
+    fn header(&mut self, code: u32) {
+        let flag = match self.endianness {
+            Endianness::BigEndian => 0,
+            Endianness::LittleEndian => 1,
+        };
+        self.bytes.push(flag);
+        self.u32(code);
+    }
+    fn u32(&mut self, value: u32) {
+        match self.endianness {
+            Endianness::BigEndian => self.bytes.extend_from_slice(&value.to_be_bytes()),
+            Endianness::LittleEndian => self.bytes.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+    fn f64(&mut self, value: f64) {
+        match self.endianness {
+            Endianness::BigEndian => self.bytes.extend_from_slice(&value.to_be_bytes()),
+            Endianness::LittleEndian => self.bytes.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+    fn coords<T: CoordFloat>(&mut self, point: &Point<T>) {
+        self.f64(point.x().to_f64().unwrap_or(f64::NAN));
+        self.f64(point.y().to_f64().unwrap_or(f64::NAN));
+    }
+    fn ring<T: CoordFloat>(&mut self, ring: &LineString<T>) {
+        self.u32(ring.0.len() as u32);
+        for p in &ring.0 {
+            self.coords(p);
+        }
+    }
+    fn polygon_body<T: CoordFloat>(&mut self, polygon: &Polygon<T>) {
+        self.u32(1 + polygon.interiors.len() as u32);
+        self.ring(&polygon.exterior);
+        for ring in &polygon.interiors {
+            self.ring(ring);
+        }
+    }
+    fn geometry<T: CoordFloat>(&mut self, geom: &Geometry<T>) {
+        match *geom {
+            Geometry::Point(ref p) => {
+                self.header(POINT);
+                self.coords(p);
+            }
+            Geometry::LineString(ref ls) => {
+                self.header(LINESTRING);
+                self.ring(ls);
+            }
+            Geometry::Polygon(ref poly) => {
+                self.header(POLYGON);
+                self.polygon_body(poly);
+            }
+            Geometry::MultiPoint(ref mp) => {
+                self.header(MULTIPOINT);
+                self.u32(mp.0.len() as u32);
+                for p in &mp.0 {
+                    self.header(POINT);
+                    self.coords(p);
+                }
+            }
+            Geometry::MultiLineString(ref mls) => {
+                self.header(MULTILINESTRING);
+                self.u32(mls.0.len() as u32);
+                for ls in &mls.0 {
+                    self.header(LINESTRING);
+                    self.ring(ls);
+                }
+            }
+            Geometry::MultiPolygon(ref mpoly) => {
+                self.header(MULTIPOLYGON);
+                self.u32(mpoly.0.len() as u32);
+                for poly in &mpoly.0 {
+                    self.header(POLYGON);
+                    self.polygon_body(poly);
+                }
+            }
+            Geometry::GeometryCollection(ref gc) => {
+                self.header(GEOMETRYCOLLECTION);
+                self.u32(gc.0.len() as u32);
+                for g in &gc.0 {
+                    self.geometry(g);
+                }
+            }
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    endianness: Endianness,
+    // the number of geometry collections being read
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], WkbError> {
+        if self.bytes.len() < n {
+            return Err(WkbError::UnexpectedEnd);
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Ok(head)
+    }
+    // reads a byte-order flag, which applies until the end of the geometry it starts
+    fn header(&mut self) -> Result<u32, WkbError> {
+        self.endianness = match self.take(1)?[0] {
+            0 => Endianness::BigEndian,
+            1 => Endianness::LittleEndian,
+            flag => return Err(WkbError::InvalidByteOrder(flag)),
+        };
+        self.u32()
+    }
+    fn u32(&mut self) -> Result<u32, WkbError> {
+        let mut buf = [0; 4];
+        buf.copy_from_slice(self.take(4)?);
+        Ok(match self.endianness {
+            Endianness::BigEndian => u32::from_be_bytes(buf),
+            Endianness::LittleEndian => u32::from_le_bytes(buf),
+        })
+    }
+    fn f64(&mut self) -> Result<f64, WkbError> {
+        let mut buf = [0; 8];
+        buf.copy_from_slice(self.take(8)?);
+        Ok(match self.endianness {
+            Endianness::BigEndian => f64::from_be_bytes(buf),
+            Endianness::LittleEndian => f64::from_le_bytes(buf),
+        })
+    }
+    // reads a count, checking it against the remaining input so a corrupt count can't
+    // trigger a huge allocation
+    fn count(&mut self, min_size: usize) -> Result<usize, WkbError> {
+        let n = self.u32()? as usize;
+        if n.saturating_mul(min_size) > self.bytes.len() {
+            return Err(WkbError::UnexpectedEnd);
+        }
+        Ok(n)
+    }
+    fn coords<T: CoordFloat>(&mut self) -> Result<Point<T>, WkbError> {
+        let x = T::from(self.f64()?).ok_or(WkbError::InvalidCoordinate)?;
+        let y = T::from(self.f64()?).ok_or(WkbError::InvalidCoordinate)?;
+        Ok(Point::new(x, y))
+    }
+    fn ring<T: CoordFloat>(&mut self) -> Result<LineString<T>, WkbError> {
+        let n = self.count(16)?;
+        (0..n).map(|_| self.coords()).collect::<Result<_, _>>().map(LineString)
+    }
+    fn polygon_body<T: CoordFloat>(&mut self) -> Result<Polygon<T>, WkbError> {
+        let n = self.count(4)?;
+        if n == 0 {
+            return Ok(Polygon::new(LineString(vec![]), vec![]));
+        }
+        let exterior = self.ring()?;
+        let interiors = (1..n).map(|_| self.ring()).collect::<Result<_, _>>()?;
+        Ok(Polygon::new(exterior, interiors))
+    }
+    // reads a member of a multi-geometry, which must have the given type
+    fn member(&mut self, code: u32) -> Result<(), WkbError> {
+        match self.header()? {
+            c if c == code => Ok(()),
+            c => Err(WkbError::UnknownGeometryType(c)),
+        }
+    }
+    fn geometry<T: CoordFloat>(&mut self) -> Result<Geometry<T>, WkbError> {
+        Ok(match self.header()? {
+            POINT => Geometry::Point(self.coords()?),
+            LINESTRING => Geometry::LineString(self.ring()?),
+            POLYGON => Geometry::Polygon(self.polygon_body()?),
+            MULTIPOINT => {
+                let n = self.count(21)?;
+                let points = (0..n)
+                    .map(|_| self.member(POINT).and_then(|_| self.coords()))
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            MULTILINESTRING => {
+                let n = self.count(9)?;
+                let lines = (0..n)
+                    .map(|_| self.member(LINESTRING).and_then(|_| self.ring()))
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            MULTIPOLYGON => {
+                let n = self.count(9)?;
+                let polygons = (0..n)
+                    .map(|_| self.member(POLYGON).and_then(|_| self.polygon_body()))
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            GEOMETRYCOLLECTION => {
+                if self.depth == MAX_NESTING {
+                    return Err(WkbError::TooDeep);
+                }
+                let n = self.count(5)?;
+                self.depth += 1;
+                let geoms = (0..n).map(|_| self.geometry()).collect::<Result<_, _>>();
+                self.depth -= 1;
+                let geoms = geoms?;
+                Geometry::GeometryCollection(GeometryCollection(geoms))
+            }
+            code => return Err(WkbError::UnknownGeometryType(code)),
+        })
+    }
+}
+
+/// Encodes a geometry as 2D Well-Known Binary, with the given byte order.
+///
+/// Coordinates are written as `f64`.
+///
+/// ```
+/// use geo::{Point, Geometry};
+/// use geo::wkb::{to_wkb, Endianness};
+///
+/// let bytes = to_wkb(&Geometry::Point(Point::new(1., 2.)), Endianness::LittleEndian);
+/// assert_eq!(bytes.len(), 21);
+/// assert_eq!(&bytes[..5], &[1, 1, 0, 0, 0]);
+/// ```
+pub fn to_wkb<T>(geom: &Geometry<T>, endianness: Endianness) -> Vec<u8>
+    where T: CoordFloat
+{
+    let mut writer = Writer { bytes: Vec::new(), endianness };
+    writer.geometry(geom);
+    writer.bytes
+}
+
+/// Decodes a geometry from 2D Well-Known Binary, in either byte order.
+///
+/// Each geometry, including the members of multi-geometries, carries its own byte-order flag.
+/// An error is returned if the input is truncated or has bytes left over, or if it uses a
+/// geometry type other than the seven 2D OGC types, or if geometry collections are nested
+/// more than `MAX_NESTING` deep.
+///
+/// ```
+/// use geo::{Point, Geometry};
+/// use geo::wkb::{to_wkb, from_wkb, Endianness};
+///
+/// let point = Geometry::Point(Point::new(1., 2.));
+/// let bytes = to_wkb(&point, Endianness::BigEndian);
+/// assert_eq!(from_wkb::<f64>(&bytes), Ok(point));
+/// assert!(from_wkb::<f64>(&bytes[..10]).is_err());
+/// ```
+pub fn from_wkb<T>(bytes: &[u8]) -> Result<Geometry<T>, WkbError>
+    where T: CoordFloat
+{
+    let mut reader = Reader { bytes, endianness: Endianness::LittleEndian, depth: 0 };
+    let geom = reader.geometry()?;
+    if !reader.bytes.is_empty() {
+        return Err(WkbError::TrailingBytes);
+    }
+    Ok(geom)
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, MultiPoint, MultiPolygon, Geometry, GeometryCollection};
+    use super::*;
+
+    fn ring(min: f64, max: f64) -> LineString<f64> {
+        LineString(vec![Point::new(min, min),
+                        Point::new(max, min),
+                        Point::new(max, max),
+                        Point::new(min, max),
+                        Point::new(min, min)])
+    }
+
+    #[test]
+    fn holed_polygon_round_trip_test() {
+        let poly = Geometry::Polygon(Polygon::new(ring(0., 10.), vec![ring(2., 4.), ring(6., 8.5)]));
+        for &endianness in &[Endianness::BigEndian, Endianness::LittleEndian] {
+            let bytes = to_wkb(&poly, endianness);
+            // header, ring count, and three rings of five points
+            assert_eq!(bytes.len(), 1 + 4 + 4 + 3 * (4 + 5 * 16));
+            assert_eq!(from_wkb(&bytes), Ok(poly.clone()));
+        }
+    }
+    #[test]
+    fn byte_order_test() {
+        let point = Geometry::Point(Point::new(1., 0.));
+        let big = to_wkb(&point, Endianness::BigEndian);
+        let little = to_wkb(&point, Endianness::LittleEndian);
+        assert_eq!(&big[..5], &[0, 0, 0, 0, 1]);
+        assert_eq!(&little[..5], &[1, 1, 0, 0, 0]);
+        // 1.0 is 0x3FF0000000000000
+        assert_eq!(&big[5..7], &[0x3F, 0xF0]);
+        assert_eq!(&little[11..13], &[0xF0, 0x3F]);
+    }
+    #[test]
+    fn multi_round_trip_test() {
+        let geoms = vec![Geometry::MultiPoint(MultiPoint(vec![Point::new(1., 2.), Point::new(3., 4.)])),
+                         Geometry::MultiPolygon(MultiPolygon(vec![Polygon::new(ring(0., 1.), vec![]),
+                                                                  Polygon::new(ring(2., 5.), vec![ring(3., 4.)])])),
+                         Geometry::LineString(ring(0., 3.))];
+        let collection = Geometry::GeometryCollection(GeometryCollection(geoms.clone()));
+        for geom in geoms.iter().chain(Some(&collection)) {
+            for &endianness in &[Endianness::BigEndian, Endianness::LittleEndian] {
+                assert_eq!(from_wkb(&to_wkb(geom, endianness)), Ok(geom.clone()));
+            }
+        }
+    }
+    #[test]
+    fn mixed_byte_order_test() {
+        // a big-endian multipoint holding a little-endian point
+        let mut bytes = vec![0, 0, 0, 0, 4, 0, 0, 0, 1];
+        bytes.extend(to_wkb(&Geometry::Point(Point::new(5., 6.)), Endianness::LittleEndian));
+        assert_eq!(from_wkb(&bytes), Ok(Geometry::MultiPoint(MultiPoint(vec![Point::new(5., 6.)]))));
+    }
+    #[test]
+    fn invalid_test() {
+        let bytes = to_wkb(&Geometry::Point(Point::new(1., 2.)), Endianness::LittleEndian);
+        assert_eq!(from_wkb::<f64>(&bytes[..20]), Err(WkbError::UnexpectedEnd));
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(from_wkb::<f64>(&trailing), Err(WkbError::TrailingBytes));
+        let mut flag = bytes.clone();
+        flag[0] = 2;
+        assert_eq!(from_wkb::<f64>(&flag), Err(WkbError::InvalidByteOrder(2)));
+        let mut code = bytes.clone();
+        code[1] = 17;
+        assert_eq!(from_wkb::<f64>(&code), Err(WkbError::UnknownGeometryType(17)));
+        // a linestring claiming far more points than there are bytes
+        let huge = vec![1, 2, 0, 0, 0, 255, 255, 255, 255];
+        assert_eq!(from_wkb::<f64>(&huge), Err(WkbError::UnexpectedEnd));
+    }
+    #[test]
+    fn nesting_limit_test() {
+        // collections each holding just the next, around an empty one
+        let nested = |depth: usize| {
+            let mut bytes = vec![];
+            for _ in 0..depth {
+                bytes.extend_from_slice(&[1, 7, 0, 0, 0, 1, 0, 0, 0]);
+            }
+            bytes.extend_from_slice(&[1, 7, 0, 0, 0, 0, 0, 0, 0]);
+            bytes
+        };
+        assert!(from_wkb::<f64>(&nested(MAX_NESTING - 1)).is_ok());
+        assert_eq!(from_wkb::<f64>(&nested(MAX_NESTING)), Err(WkbError::TooDeep));
+        assert_eq!(from_wkb::<f64>(&nested(500_000)), Err(WkbError::TooDeep));
+    }
+}